@@ -1,6 +1,7 @@
+use crate::idle::IdleContext;
 use crate::window::create_surface;
 use crate::window::ViziaWindow;
-use baseview::{Window, WindowHandle, WindowScalePolicy};
+use baseview::{Window, WindowHandle, WindowInfo, WindowScalePolicy};
 use gl_rs as gl;
 use gl_rs::types::GLint;
 use raw_window_handle::HasRawWindowHandle;
@@ -14,6 +15,24 @@ use vizia_core::prelude::*;
 #[derive(Debug)]
 pub enum ApplicationError {}
 
+/// The reason a redraw was requested during a frame, recorded when the `redraw-causes` feature is enabled.
+#[cfg(feature = "redraw-causes")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RedrawCause {
+    /// One or more events were handled.
+    Events,
+    /// The style of one or more views changed.
+    StyleChange,
+    /// One or more animations ticked.
+    Animation,
+    /// The layout of one or more views changed.
+    Layout,
+    /// The window was resized.
+    Resize,
+    /// The window gained focus.
+    Focus,
+}
+
 ///Creating a new application creates a root `Window` and a `Context`. Views declared within the closure passed to `Application::new()` are added to the context and rendered into the root window.
 ///
 /// # Example
@@ -34,7 +53,9 @@ where
     app: F,
     window_description: WindowDescription,
     window_scale_policy: WindowScalePolicy,
-    on_idle: Option<Box<dyn Fn(&mut Context) + Send>>,
+    on_idle: Option<Box<dyn Fn(&mut IdleContext) + Send>>,
+    on_frame: Option<Box<dyn Fn(&mut Context, Duration) + Send>>,
+    on_platform_event: Option<Box<dyn Fn(&mut Context, &baseview::Event) -> bool + Send>>,
     ignore_default_theme: bool,
 }
 
@@ -49,6 +70,8 @@ where
             window_description: WindowDescription::new(),
             window_scale_policy: WindowScalePolicy::SystemScaleFactor,
             on_idle: None,
+            on_frame: None,
+            on_platform_event: None,
             ignore_default_theme: false,
         }
     }
@@ -98,12 +121,38 @@ where
             self.window_scale_policy,
             self.app,
             self.on_idle,
+            self.on_frame,
+            self.on_platform_event,
             self.ignore_default_theme,
         );
 
         Ok(())
     }
 
+    /// Open a new window on a separate thread, without blocking the current thread.
+    ///
+    /// Returns a handle to the thread running the window, which can be joined to wait until the window is
+    /// destroyed. This is intended for standalone builds, such as integration tests which drive a real window
+    /// alongside other work. Not available on macOS, where windows must be created on the main thread.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_baseview::Application;
+    /// let handle = Application::new(|cx|{
+    ///     // Content goes here
+    /// })
+    /// .run_nonblocking();
+    ///
+    /// // Other work happens here while the window is open.
+    ///
+    /// handle.join().unwrap().unwrap();
+    /// ```
+    #[cfg(not(target_os = "macos"))]
+    pub fn run_nonblocking(self) -> std::thread::JoinHandle<Result<(), ApplicationError>> {
+        std::thread::spawn(move || self.run())
+    }
+
     /// Open a new child window.
     ///
     /// This function does **not** block the current thread. This is only to be
@@ -118,6 +167,8 @@ where
             self.window_scale_policy,
             self.app,
             self.on_idle,
+            self.on_frame,
+            self.on_platform_event,
             self.ignore_default_theme,
         )
     }
@@ -141,6 +192,87 @@ where
     /// .run();
     /// ```
     pub fn on_idle<I: 'static + Fn(&mut Context) + Send>(mut self, callback: I) -> Self {
+        self.on_idle = Some(Box::new(move |cx: &mut IdleContext| (callback)(cx.context())));
+
+        self
+    }
+
+    /// Takes a closure which will be called once for every frame which is drawn, with the time elapsed since the previous frame.
+    ///
+    /// Unlike [`on_idle()`](Self::on_idle), the callback only runs when a frame has actually been produced, so it
+    /// ticks at the rate the window redraws. This makes it a place to advance custom animations or simulations.
+    /// Changes made in the callback which cause a redraw will produce another frame, and so another call to the callback.
+    /// The elapsed time is zero for the first frame.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_baseview::Application;
+    /// Application::new(|cx|{
+    ///     // Build application here
+    /// })
+    /// .on_frame(|cx, dt|{
+    ///     // Code here runs after every frame, with the time since the previous frame
+    /// })
+    /// .run();
+    /// ```
+    pub fn on_frame<I: 'static + Fn(&mut Context, Duration) + Send>(mut self, callback: I) -> Self {
+        self.on_frame = Some(Box::new(callback));
+
+        self
+    }
+
+    /// Takes a closure which will be called with every raw event received from baseview, before it is translated by vizia.
+    ///
+    /// The callback returns whether vizia should also process the event. Returning `false` stops vizia from handling the
+    /// event and marks it as captured. This is an escape hatch for platform features which vizia does not yet model.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_baseview::Application;
+    /// Application::new(|cx|{
+    ///     // Build application here
+    /// })
+    /// .on_platform_event(|_cx, event|{
+    ///     // Return false to stop vizia from handling the event
+    ///     !matches!(event, baseview::Event::Mouse(baseview::MouseEvent::CursorEntered))
+    /// })
+    /// .run();
+    /// ```
+    pub fn on_platform_event<E>(mut self, callback: E) -> Self
+    where
+        E: 'static + Fn(&mut Context, &baseview::Event) -> bool + Send,
+    {
+        self.on_platform_event = Some(Box::new(callback));
+
+        self
+    }
+
+    /// Takes a closure which will be called at the end of every loop of the application, with access to the backend window.
+    ///
+    /// This behaves the same as [`on_idle()`](Self::on_idle), but the callback receives an [`IdleContext`], which can
+    /// read the current size of the window and request that it be resized, for example when negotiating the size of a
+    /// plugin window with the host. Replaces any callback previously set with [`on_idle()`](Self::on_idle).
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_baseview::Application;
+    /// Application::new(|cx|{
+    ///     // Build application here
+    /// })
+    /// .on_idle_with_window(|cx|{
+    ///     if cx.logical_size().width < 400 {
+    ///         cx.resize((400, 300));
+    ///     }
+    /// })
+    /// .run();
+    /// ```
+    pub fn on_idle_with_window<I: 'static + Fn(&mut IdleContext) + Send>(
+        mut self,
+        callback: I,
+    ) -> Self {
         self.on_idle = Some(Box::new(callback));
 
         self
@@ -152,6 +284,8 @@ pub(crate) struct ApplicationRunner {
     event_manager: EventManager,
     pub gr_context: skia_safe::gpu::DirectContext,
     should_redraw: bool,
+    /// Whether the user requested that the window be closed while handling the latest events.
+    close_requested: bool,
 
     /// If this is set to `true`, then `window_scale_factor` will be updated during
     /// [`baseview::WindowEvent::Resized`] events in accordance to the system's reported DPI. This
@@ -164,15 +298,18 @@ pub(crate) struct ApplicationRunner {
     /// mouse coordinates to physical window coordinates. For any other use within VIZIA itself this
     /// always needs to be multiplied by `user_scale_factor`.
     window_scale_factor: f64,
-    // /// The scale factor applied on top of the `window_scale` to convert the window's logical size
-    // /// to a physical size. If this is different from `*cx.user_scale_factor` after handling the
-    // /// events then the window will be resized.
-    // current_user_scale_factor: f64,
-    // /// The window's current logical size, before `user_scale_factor` has been applied. Needed to
-    // /// resize the window when changing the scale factor.
-    // current_window_size: WindowSize,
+    /// The scale factor applied on top of the `window_scale` to convert the window's logical size
+    /// to a physical size. If this is different from the user scale factor of the window
+    /// description after handling the events then the window will be resized.
+    current_user_scale_factor: f64,
+    /// The time at which the previous frame was drawn.
+    last_frame_time: Option<Instant>,
     pub surface: skia_safe::Surface,
     pub dirty_surface: skia_safe::Surface,
+
+    /// The causes of the redraw requested for the current frame.
+    #[cfg(feature = "redraw-causes")]
+    redraw_causes: Vec<RedrawCause>,
 }
 
 impl ApplicationRunner {
@@ -186,122 +323,165 @@ impl ApplicationRunner {
     ) -> Self {
         ApplicationRunner {
             should_redraw: true,
+            close_requested: false,
             gr_context,
             event_manager: EventManager::new(),
             use_system_scaling,
             window_scale_factor,
-            //current_user_scale_factor: cx.user_scale_factor(),
-            //current_window_size: *cx.window_size(),
+            current_user_scale_factor: cx
+                .0
+                .windows
+                .get(&Entity::root())
+                .map_or(1.0, |window_state| window_state.window_description.user_scale_factor),
+            last_frame_time: None,
             cx,
             surface,
             dirty_surface,
+            #[cfg(feature = "redraw-causes")]
+            redraw_causes: Vec::new(),
+        }
+    }
+
+    /// Returns the total number of views waiting to be redrawn across all windows.
+    #[cfg(feature = "redraw-causes")]
+    fn redraw_list_len(&self) -> usize {
+        self.cx.0.windows.values().map(|window_state| window_state.redraw_list.len()).sum()
+    }
+
+    /// Records `cause` if the number of views waiting to be redrawn grew from `before`.
+    #[cfg(feature = "redraw-causes")]
+    fn record_redraw_cause(&mut self, before: usize, cause: RedrawCause) {
+        if self.redraw_list_len() > before && !self.redraw_causes.contains(&cause) {
+            self.redraw_causes.push(cause);
         }
     }
 
     /// Handle all reactivity within a frame. The window instance is used to resize the window when
     /// needed.
     pub fn on_frame_update(&mut self, window: &mut Window) {
+        // Timers and scheduled events are checked once per frame.
+        self.cx.process_timers();
+        self.cx.emit_scheduled_events();
+
         while let Some(event) = queue_get() {
             self.cx.send_event(event);
         }
 
+        #[cfg(feature = "redraw-causes")]
+        let before = self.redraw_list_len();
+
         // Events
         while self.event_manager.flush_events(self.cx.context()) {}
 
-        // if *cx.window_size() != self.current_window_size
-        //     || cx.user_scale_factor() != self.current_user_scale_factor
-        // {
-        //     self.current_window_size = *cx.window_size();
-        //     self.current_user_scale_factor = cx.user_scale_factor();
-
-        //     // The user scale factor is not part of the HiDPI scaling, so baseview should treat it
-        //     // as part of our logical size
-        //     window.resize(baseview::Size {
-        //         width: self.current_window_size.width as f64 * self.current_user_scale_factor,
-        //         height: self.current_window_size.height as f64 * self.current_user_scale_factor,
-        //     });
-
-        //     // TODO: These calculations are now repeated in three places, should probably be moved
-        //     //       to a function
-        //     cx.set_scale_factor(self.window_scale_factor * self.current_user_scale_factor);
-        //     let new_physical_width =
-        //         self.current_window_size.width as f32 * cx.style().scale_factor();
-        //     let new_physical_height =
-        //         self.current_window_size.height as f32 * cx.style().scale_factor();
-
-        //     cx.set_window_size(new_physical_width, new_physical_height);
-
-        //     if let Some(surface) = cx.get_surface_mut(Entity::root()) {
-        //         if new_physical_width != 0.0 || new_physical_height != 0.0 {
-        //             let fb_info = {
-        //                 let mut fboid: GLint = 0;
-        //                 unsafe { gl::GetIntegerv(gl::FRAMEBUFFER_BINDING, &mut fboid) };
-
-        //                 FramebufferInfo {
-        //                     fboid: fboid.try_into().unwrap(),
-        //                     format: skia_safe::gpu::gl::Format::RGBA8.into(),
-        //                     ..Default::default()
-        //                 }
-        //             };
-
-        //             let backend_render_target = backend_render_targets::make_gl(
-        //                 (new_physical_width as i32, new_physical_height as i32),
-        //                 None,
-        //                 8,
-        //                 fb_info,
-        //             );
-
-        //             surface.0 = gpu::surfaces::wrap_backend_render_target(
-        //                 &mut self.gr_context,
-        //                 &backend_render_target,
-        //                 SurfaceOrigin::BottomLeft,
-        //                 ColorType::RGBA8888,
-        //                 None,
-        //                 None,
-        //             )
-        //             .expect("Could not create skia surface");
-
-        //             surface.1 = surface
-        //                 .0
-        //                 .new_surface_with_dimensions((
-        //                     new_physical_width.max(1.0) as i32,
-        //                     new_physical_height.max(1.0) as i32,
-        //                 ))
-        //                 .unwrap();
-        //         }
-        //     }
-
-        //     cx.needs_refresh();
-
-        //     // hmmm why are we flushing events again?
-        //     // self.event_manager.flush_events(cx.context());
-        // }
+        // Close the window if no view prevented it while handling the close request.
+        if std::mem::take(&mut self.close_requested)
+            && !self.cx.is_window_close_prevented(Entity::root())
+        {
+            window.close();
+        }
+
+        #[cfg(feature = "redraw-causes")]
+        self.record_redraw_cause(before, RedrawCause::Events);
+
+        // Resize the window when the user scale factor has been changed, keeping the logical size
+        // of the window the same.
+        let user_scale_factor = self.user_scale_factor();
+        if user_scale_factor != self.current_user_scale_factor {
+            let scale_factor = self.window_scale_factor * self.current_user_scale_factor;
+            let logical_size = WindowSize::new(
+                (self.surface.width() as f64 / scale_factor).round() as u32,
+                (self.surface.height() as f64 / scale_factor).round() as u32,
+            );
+
+            self.current_user_scale_factor = user_scale_factor;
+            self.resize_window(window, logical_size);
+        }
+
+        #[cfg(feature = "redraw-causes")]
+        let before = self.redraw_list_len();
 
         let context = window.gl_context().expect("Window was created without OpenGL support");
         unsafe { context.make_current() };
         self.cx.process_style_updates();
         unsafe { context.make_not_current() };
 
-        self.cx.process_animations();
+        #[cfg(feature = "redraw-causes")]
+        self.record_redraw_cause(before, RedrawCause::StyleChange);
+        #[cfg(feature = "redraw-causes")]
+        let before = self.redraw_list_len();
+
+        self.cx.process_animations(Instant::now());
+
+        #[cfg(feature = "redraw-causes")]
+        self.record_redraw_cause(before, RedrawCause::Animation);
+        #[cfg(feature = "redraw-causes")]
+        let before = self.redraw_list_len();
 
         self.cx.process_visual_updates();
 
+        #[cfg(feature = "redraw-causes")]
+        self.record_redraw_cause(before, RedrawCause::Layout);
+
         if self.cx.0.windows.iter().any(|(_, window_state)| !window_state.redraw_list.is_empty()) {
             self.should_redraw = true;
         }
     }
 
-    pub fn render(&mut self) {
-        if self.cx.draw(Entity::root(), &mut self.surface, &mut self.dirty_surface) {
+    /// Draws the window, returning whether a frame was produced.
+    pub fn render(&mut self) -> bool {
+        if self.cx.draw(Entity::root(), &mut self.surface, &mut self.dirty_surface).is_some() {
             self.gr_context.flush_and_submit();
             self.should_redraw = false;
+
+            if self.cx.has_frame_capture_requests(Entity::root()) {
+                if let Some(frame) = self.capture_frame() {
+                    self.cx.send_captured_frame(Entity::root(), frame);
+                }
+            }
+
+            #[cfg(feature = "redraw-causes")]
+            log::debug!("Redraw caused by {:?}", std::mem::take(&mut self.redraw_causes));
+
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Calls the frame callback with the time elapsed since the previous frame was drawn.
+    pub fn handle_frame(&mut self, on_frame: &Option<Box<dyn Fn(&mut Context, Duration) + Send>>) {
+        let now = Instant::now();
+        let dt = self.last_frame_time.map_or(Duration::ZERO, |time| now - time);
+        self.last_frame_time = Some(now);
+
+        if let Some(frame_callback) = on_frame {
+            self.cx.set_current(Entity::root());
+            (frame_callback)(self.cx.context(), dt);
         }
     }
 
+    // Reads back the pixels of the frame which was just drawn, before it is presented.
+    fn capture_frame(&mut self) -> Option<CapturedFrame> {
+        let info = skia_safe::ImageInfo::new(
+            self.surface.image_info().dimensions(),
+            skia_safe::ColorType::RGBA8888,
+            skia_safe::AlphaType::Unpremul,
+            None,
+        );
+        let mut pixels = vec![0; info.compute_min_byte_size()];
+
+        self.surface.read_pixels(&info, &mut pixels, info.min_row_bytes(), (0, 0)).then(|| {
+            CapturedFrame { width: info.width() as u32, height: info.height() as u32, pixels }
+        })
+    }
+
     pub fn handle_event(&mut self, event: baseview::Event, should_quit: &mut bool) {
-        if requests_exit(&event) {
+        if let baseview::Event::Window(baseview::WindowEvent::WillClose) = event {
             self.cx.send_event(Event::new(WindowEvent::WindowClose));
             *should_quit = true;
+        } else if requests_exit(&event) {
+            self.cx.request_window_close(Entity::root());
+            self.close_requested = true;
         }
 
         let mut update_modifiers = |modifiers: vizia_input::KeyboardModifiers| {
@@ -324,16 +504,8 @@ impl ApplicationRunner {
                 baseview::MouseEvent::CursorMoved { position, modifiers } => {
                     update_modifiers(modifiers);
 
-                    // NOTE: We multiply by `self.window_scale_factor` and not by
-                    //       `self.context.style.dpi_factor`. Since the additional scaling by
-                    //       internally do additional scaling by `self.context.user_scale_factor` is
-                    //       done internally to be able to separate actual HiDPI scaling from
-                    //       arbitrary uniform scaling baseview only knows about its own scale
-                    //       factor.
-                    let physical_posx = position.x * self.window_scale_factor;
-                    let physical_posy = position.y * self.window_scale_factor;
-                    let cursor_x = (physical_posx) as f32;
-                    let cursor_y = (physical_posy) as f32;
+                    let (cursor_x, cursor_y) =
+                        physical_cursor_position(position, self.window_scale_factor);
                     self.cx.emit_origin(WindowEvent::MouseMove(cursor_x, cursor_y));
                 }
                 baseview::MouseEvent::ButtonPressed { button, modifiers } => {
@@ -351,27 +523,16 @@ impl ApplicationRunner {
                 baseview::MouseEvent::WheelScrolled { delta, modifiers } => {
                     update_modifiers(modifiers);
 
-                    let (lines_x, lines_y) = match delta {
-                        baseview::ScrollDelta::Lines { x, y } => (x, y),
-                        baseview::ScrollDelta::Pixels { x, y } => (
-                            if x < 0.0 {
-                                -1.0
-                            } else if x > 1.0 {
-                                1.0
-                            } else {
-                                0.0
-                            },
-                            if y < 0.0 {
-                                -1.0
-                            } else if y > 1.0 {
-                                1.0
-                            } else {
-                                0.0
-                            },
-                        ),
+                    let event = match delta {
+                        baseview::ScrollDelta::Lines { x, y } => WindowEvent::MouseScroll(x, y),
+                        // Baseview reports pixel deltas in logical pixels.
+                        baseview::ScrollDelta::Pixels { x, y } => {
+                            let scale_factor = self.window_scale_factor as f32;
+                            WindowEvent::MouseScrollPixels(x * scale_factor, y * scale_factor)
+                        }
                     };
 
-                    self.cx.emit_origin(WindowEvent::MouseScroll(lines_x, lines_y));
+                    self.cx.emit_origin(event);
                 }
 
                 baseview::MouseEvent::CursorEntered => {
@@ -414,7 +575,11 @@ impl ApplicationRunner {
                             }
                         }
 
-                        self.cx.emit_origin(WindowEvent::KeyDown(event.code, Some(event.key)));
+                        self.cx.emit_origin(WindowEvent::KeyDown(
+                            event.code,
+                            Some(event.key),
+                            event.repeat,
+                        ));
                     }
 
                     MouseButtonState::Released => {
@@ -423,67 +588,21 @@ impl ApplicationRunner {
                 }
             }
             baseview::Event::Window(event) => match event {
-                baseview::WindowEvent::Focused => self.cx.needs_refresh(Entity::root()),
-                baseview::WindowEvent::Resized(window_info) => {
-                    let fb_info = {
-                        let mut fboid: GLint = 0;
-                        unsafe { gl::GetIntegerv(gl::FRAMEBUFFER_BINDING, &mut fboid) };
-
-                        FramebufferInfo {
-                            fboid: fboid.try_into().unwrap(),
-                            format: skia_safe::gpu::gl::Format::RGBA8.into(),
-                            ..Default::default()
-                        }
-                    };
-
-                    self.surface = create_surface(
-                        (
-                            window_info.physical_size().width as i32,
-                            window_info.physical_size().height as i32,
-                        ),
-                        fb_info,
-                        &mut self.gr_context,
-                    );
-
-                    self.dirty_surface = self
-                        .surface
-                        .new_surface_with_dimensions((
-                            window_info.physical_size().width as i32,
-                            window_info.physical_size().height as i32,
-                        ))
-                        .unwrap();
-
-                    // // We keep track of the current size before applying the user scale factor while
-                    // // baseview's logical size includes that factor so we need to compensate for it
-                    // self.current_window_size = *self.cx.window_size();
-                    // self.current_window_size.width = (window_info.logical_size().width
-                    //     / self.cx.user_scale_factor())
-                    // .round() as u32;
-                    // self.current_window_size.height = (window_info.logical_size().height
-                    //     / self.cx.user_scale_factor())
-                    // .round() as u32;
-                    // *self.cx.window_size() = self.current_window_size;
-
-                    // Only use new DPI settings when `WindowScalePolicy::SystemScaleFactor` was
-                    // used
-                    if self.use_system_scaling {
-                        self.window_scale_factor = window_info.scale();
-                    }
-
-                    //let user_scale_factor = self.cx.user_scale_factor();
-
-                    //self.cx.set_scale_factor(self.window_scale_factor * user_scale_factor);
-
-                    let physical_size =
-                        (window_info.physical_size().width, window_info.physical_size().height);
+                baseview::WindowEvent::Focused => {
+                    self.cx.set_window_focused(Entity::root(), true);
+                    self.cx.needs_refresh(Entity::root());
 
-                    self.cx.set_window_size(
-                        Entity::root(),
-                        physical_size.0 as f32,
-                        physical_size.1 as f32,
-                    );
+                    #[cfg(feature = "redraw-causes")]
+                    self.redraw_causes.push(RedrawCause::Focus);
+                }
+                baseview::WindowEvent::Resized(window_info) => {
+                    self.resize(window_info);
 
-                    self.cx.needs_refresh(Entity::root());
+                    #[cfg(feature = "redraw-causes")]
+                    self.redraw_causes.push(RedrawCause::Resize);
+                }
+                baseview::WindowEvent::Unfocused => {
+                    self.cx.set_window_focused(Entity::root(), false);
                 }
                 baseview::WindowEvent::WillClose => {
                     self.cx.send_event(Event::new(WindowEvent::WindowClose));
@@ -493,14 +612,147 @@ impl ApplicationRunner {
         }
     }
 
-    pub fn handle_idle(&mut self, on_idle: &Option<Box<dyn Fn(&mut Context) + Send>>) {
+    /// Recreates the window surfaces and updates the window size and scale factor in the context.
+    fn resize(&mut self, window_info: WindowInfo) {
+        let physical_size = window_info.physical_size();
+
+        self.recreate_surface(physical_size.width, physical_size.height);
+
+        // Only use new DPI settings when `WindowScalePolicy::SystemScaleFactor` was
+        // used
+        if self.use_system_scaling && self.window_scale_factor != window_info.scale() {
+            self.window_scale_factor = window_info.scale();
+            self.cx
+                .send_event(Event::new(WindowEvent::ScaleFactorChanged(self.window_scale_factor)));
+        }
+
+        self.apply_scale();
+
+        self.cx.set_window_size(
+            Entity::root(),
+            physical_size.width as f32,
+            physical_size.height as f32,
+        );
+
+        self.cx.needs_refresh(Entity::root());
+    }
+
+    /// Recreates the skia surface for the window framebuffer and the dirty surface used for
+    /// partial redraws. Sizes are clamped to at least one pixel so that a zero-sized window,
+    /// e.g. when minimized, doesn't fail to produce a surface.
+    fn recreate_surface(&mut self, physical_width: u32, physical_height: u32) {
+        let size = (physical_width.max(1) as i32, physical_height.max(1) as i32);
+
+        let fb_info = {
+            let mut fboid: GLint = 0;
+            unsafe { gl::GetIntegerv(gl::FRAMEBUFFER_BINDING, &mut fboid) };
+
+            FramebufferInfo {
+                fboid: fboid.try_into().unwrap(),
+                format: skia_safe::gpu::gl::Format::RGBA8.into(),
+                ..Default::default()
+            }
+        };
+
+        self.surface = create_surface(size, fb_info, &mut self.gr_context);
+
+        self.dirty_surface =
+            self.surface.new_surface_with_dimensions(size).expect("Could not create dirty surface");
+    }
+
+    /// Applies the combined window and user scale factor to the context.
+    ///
+    /// Layout is performed in physical pixels using the combined scale factor, so it
+    /// must be kept in sync with the window scale factor reported by the system.
+    fn apply_scale(&mut self) {
+        self.cx.set_scale_factor(self.window_scale_factor * self.user_scale_factor());
+    }
+
+    /// Returns the user scale factor of the window, which is applied on top of the window scale factor.
+    fn user_scale_factor(&self) -> f64 {
+        self.cx
+            .0
+            .windows
+            .get(&Entity::root())
+            .map_or(1.0, |window_state| window_state.window_description.user_scale_factor)
+    }
+
+    /// Passes a raw baseview event to the platform event callback, returning whether vizia should also process it.
+    pub fn handle_platform_event(
+        &mut self,
+        event: &baseview::Event,
+        on_platform_event: &Option<Box<dyn Fn(&mut Context, &baseview::Event) -> bool + Send>>,
+    ) -> bool {
+        if let Some(callback) = on_platform_event {
+            self.cx.set_current(Entity::root());
+            (callback)(self.cx.context(), event)
+        } else {
+            true
+        }
+    }
+
+    pub fn handle_idle(
+        &mut self,
+        window: &mut Window,
+        on_idle: &Option<Box<dyn Fn(&mut IdleContext) + Send>>,
+    ) {
         if let Some(idle_callback) = on_idle {
             self.cx.set_current(Entity::root());
-            (idle_callback)(self.cx.context());
+
+            let physical_size =
+                WindowSize::new(self.surface.width() as u32, self.surface.height() as u32);
+            let window_scale_factor = self.window_scale_factor;
+            let user_scale_factor = self.user_scale_factor();
+
+            let mut idle_cx = IdleContext::new(
+                self.cx.context(),
+                physical_size,
+                window_scale_factor,
+                user_scale_factor,
+            );
+            (idle_callback)(&mut idle_cx);
+
+            if let Some(size) = idle_cx.requested_size() {
+                self.resize_window(window, size);
+            }
+        }
+    }
+
+    /// Resizes the window to the given logical size, before the user scale factor has been applied.
+    fn resize_window(&mut self, window: &mut Window, size: WindowSize) {
+        // The user scale factor is not part of the HiDPI scaling, so baseview should treat it
+        // as part of our logical size.
+        let user_scale_factor = self.user_scale_factor();
+        let logical_size = baseview::Size::new(
+            size.width as f64 * user_scale_factor,
+            size.height as f64 * user_scale_factor,
+        );
+
+        window.resize(logical_size);
+
+        if let Some(window_state) = self.cx.0.windows.get_mut(&Entity::root()) {
+            window_state.window_description.inner_size = size;
         }
+
+        // Recreate the surfaces straight away rather than relying on the platform to send a
+        // resize event for a programmatic resize.
+        let context = window.gl_context().expect("Window was created without OpenGL support");
+        unsafe { context.make_current() };
+        self.resize(WindowInfo::from_logical_size(logical_size, self.window_scale_factor));
+        unsafe { context.make_not_current() };
     }
 }
 
+/// Converts a cursor position reported by baseview to the physical coordinates used for layout.
+///
+/// Baseview's logical window size already includes the user scale factor, as the window is created
+/// with a logical size of `inner_size * user_scale_factor`. Multiplying by the window scale factor
+/// alone therefore yields physical pixels, which match view bounds laid out with a scale factor of
+/// `window_scale_factor * user_scale_factor`.
+fn physical_cursor_position(position: baseview::Point, window_scale_factor: f64) -> (f32, f32) {
+    ((position.x * window_scale_factor) as f32, (position.y * window_scale_factor) as f32)
+}
+
 /// Returns true if the provided event should cause an [`Application`] to
 /// exit.
 pub fn requests_exit(event: &baseview::Event) -> bool {
@@ -531,3 +783,61 @@ fn translate_mouse_button(button: baseview::MouseButton) -> MouseButton {
         baseview::MouseButton::Forward => MouseButton::Other(5),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    // Lays out two side-by-side views in a window with a 2x system scale and a 1.5x user scale,
+    // then returns the name of the view which receives a click at the given baseview position.
+    fn clicked_view(position: baseview::Point) -> Option<&'static str> {
+        let window_scale_factor = 2.0;
+        let window_description =
+            WindowDescription::new().with_inner_size(200, 100).with_scale_factor(1.5);
+
+        let mut cx = BackendContext::new(Context::default());
+        cx.add_main_window(
+            Entity::root(),
+            &window_description,
+            (window_scale_factor * window_description.user_scale_factor) as f32,
+        );
+        cx.0.windows
+            .insert(Entity::root(), WindowState { window_description, ..Default::default() });
+
+        let clicked = Arc::new(Mutex::new(None));
+
+        HStack::new(cx.context(), |cx| {
+            for name in ["left", "right"] {
+                let clicked = clicked.clone();
+                Element::new(cx)
+                    .width(Pixels(100.0))
+                    .height(Stretch(1.0))
+                    .on_mouse_down(move |_, _| *clicked.lock().unwrap() = Some(name));
+            }
+        })
+        .size(Stretch(1.0));
+
+        cx.process_style_updates();
+        cx.process_visual_updates();
+
+        let (cursor_x, cursor_y) = physical_cursor_position(position, window_scale_factor);
+        cx.set_current(Entity::root());
+        cx.emit_origin(WindowEvent::MouseMove(cursor_x, cursor_y));
+        cx.emit_origin(WindowEvent::MouseDown(MouseButton::Left));
+
+        let mut event_manager = EventManager::new();
+        while event_manager.flush_events(cx.context()) {}
+
+        let clicked = *clicked.lock().unwrap();
+        clicked
+    }
+
+    #[test]
+    fn cursor_position_matches_view_bounds_with_user_scale() {
+        // The window is 300x150 logical baseview units, or 600x300 physical pixels. Each view is
+        // 100 logical vizia units wide, or 300 physical pixels.
+        assert_eq!(clicked_view(baseview::Point::new(110.0, 50.0)), Some("left"));
+        assert_eq!(clicked_view(baseview::Point::new(160.0, 50.0)), Some("right"));
+    }
+}
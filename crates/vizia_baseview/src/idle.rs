@@ -0,0 +1,79 @@
+use std::ops::{Deref, DerefMut};
+
+use vizia_core::prelude::*;
+
+/// The context passed to an idle callback registered with
+/// [`Application::on_idle_with_window`](crate::Application::on_idle_with_window).
+///
+/// In addition to the vizia [`Context`], which it dereferences to, the idle context provides access to
+/// the current size of the backend window and allows the window to be resized. This is useful for
+/// plugins which negotiate their size with the host during idle processing.
+pub struct IdleContext<'a> {
+    cx: &'a mut Context,
+    physical_size: WindowSize,
+    window_scale_factor: f64,
+    user_scale_factor: f64,
+    requested_size: Option<WindowSize>,
+}
+
+impl<'a> IdleContext<'a> {
+    pub(crate) fn new(
+        cx: &'a mut Context,
+        physical_size: WindowSize,
+        window_scale_factor: f64,
+        user_scale_factor: f64,
+    ) -> Self {
+        Self { cx, physical_size, window_scale_factor, user_scale_factor, requested_size: None }
+    }
+
+    /// Returns the vizia context.
+    pub fn context(&mut self) -> &mut Context {
+        self.cx
+    }
+
+    /// Returns the size of the window in physical pixels.
+    pub fn physical_size(&self) -> WindowSize {
+        self.physical_size
+    }
+
+    /// Returns the size of the window in logical points, in the same units as
+    /// [`Application::inner_size`](crate::Application::inner_size).
+    pub fn logical_size(&self) -> WindowSize {
+        let scale_factor = self.scale_factor();
+        WindowSize::new(
+            (self.physical_size.width as f64 / scale_factor).round() as u32,
+            (self.physical_size.height as f64 / scale_factor).round() as u32,
+        )
+    }
+
+    /// Returns the combined window and user scale factor used to convert logical points to physical pixels.
+    pub fn scale_factor(&self) -> f64 {
+        self.window_scale_factor * self.user_scale_factor
+    }
+
+    /// Requests that the window be resized to the given logical size, in the same units as
+    /// [`Application::inner_size`](crate::Application::inner_size).
+    ///
+    /// The backend window is resized, and its surface recreated, once the idle callback returns.
+    pub fn resize(&mut self, size: impl Into<WindowSize>) {
+        self.requested_size = Some(size.into());
+    }
+
+    pub(crate) fn requested_size(&self) -> Option<WindowSize> {
+        self.requested_size
+    }
+}
+
+impl Deref for IdleContext<'_> {
+    type Target = Context;
+
+    fn deref(&self) -> &Self::Target {
+        self.cx
+    }
+}
+
+impl DerefMut for IdleContext<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.cx
+    }
+}
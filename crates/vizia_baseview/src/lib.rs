@@ -1,5 +1,6 @@
 #![allow(clippy::type_complexity)]
 mod application;
+mod idle;
 mod parent_window;
 pub(crate) mod proxy;
 mod window;
@@ -7,5 +8,6 @@ mod window;
 pub use parent_window::ParentWindow;
 
 pub use application::{Application, ApplicationError};
+pub use idle::IdleContext;
 
 pub use baseview::{WindowHandle, WindowScalePolicy};
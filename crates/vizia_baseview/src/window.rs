@@ -1,11 +1,12 @@
 use crate::application::ApplicationRunner;
+use crate::idle::IdleContext;
 use baseview::gl::GlConfig;
 use baseview::{
     Event, EventStatus, Window, WindowHandle, WindowHandler, WindowOpenOptions, WindowScalePolicy,
 };
 use gl::types::GLint;
 use gl_rs as gl;
-use raw_window_handle::HasRawWindowHandle;
+use raw_window_handle::{HasRawDisplayHandle, HasRawWindowHandle};
 use skia_safe::gpu::gl::FramebufferInfo;
 use skia_safe::gpu::{
     self, backend_render_targets, context_options, ContextOptions, SurfaceOrigin,
@@ -20,17 +21,22 @@ use vizia_core::prelude::*;
 pub(crate) struct ViziaWindow {
     application: ApplicationRunner,
     #[allow(clippy::type_complexity)]
-    on_idle: Option<Box<dyn Fn(&mut Context) + Send>>,
+    on_idle: Option<Box<dyn Fn(&mut IdleContext) + Send>>,
+    on_frame: Option<Box<dyn Fn(&mut Context, Duration) + Send>>,
+    on_platform_event: Option<Box<dyn Fn(&mut Context, &baseview::Event) -> bool + Send>>,
 }
 
 impl ViziaWindow {
+    #[allow(clippy::too_many_arguments)]
     fn new(
         mut cx: BackendContext,
         win_desc: WindowDescription,
         window_scale_policy: WindowScalePolicy,
         window: &mut baseview::Window,
         builder: Option<Box<dyn FnOnce(&mut Context) + Send>>,
-        on_idle: Option<Box<dyn Fn(&mut Context) + Send>>,
+        on_idle: Option<Box<dyn Fn(&mut IdleContext) + Send>>,
+        on_frame: Option<Box<dyn Fn(&mut Context, Duration) + Send>>,
+        on_platform_event: Option<Box<dyn Fn(&mut Context, &baseview::Event) -> bool + Send>>,
     ) -> ViziaWindow {
         let context = window.gl_context().expect("Window was created without OpenGL support");
 
@@ -97,6 +103,14 @@ impl ViziaWindow {
             Entity::root(),
             WindowState { window_description: win_desc, ..Default::default() },
         );
+        cx.set_raw_window_handle(
+            Entity::root(),
+            convert_raw_window_handle(window.raw_window_handle()),
+        );
+        cx.set_raw_display_handle(
+            Entity::root(),
+            convert_raw_display_handle(window.raw_display_handle()),
+        );
 
         cx.context().remove_user_themes();
         if let Some(builder) = builder {
@@ -113,19 +127,22 @@ impl ViziaWindow {
         );
         unsafe { context.make_not_current() };
 
-        ViziaWindow { application, on_idle }
+        ViziaWindow { application, on_idle, on_frame, on_platform_event }
     }
 
     /// Open a new child window.
     ///
     /// * `parent` - The parent window.
     /// * `app` - The Vizia application builder.
+    #[allow(clippy::too_many_arguments)]
     pub fn open_parented<P, F>(
         parent: &P,
         win_desc: WindowDescription,
         scale_policy: WindowScalePolicy,
         app: F,
-        on_idle: Option<Box<dyn Fn(&mut Context) + Send>>,
+        on_idle: Option<Box<dyn Fn(&mut IdleContext) + Send>>,
+        on_frame: Option<Box<dyn Fn(&mut Context, Duration) + Send>>,
+        on_platform_event: Option<Box<dyn Fn(&mut Context, &baseview::Event) -> bool + Send>>,
         ignore_default_theme: bool,
     ) -> WindowHandle
     where
@@ -157,7 +174,16 @@ impl ViziaWindow {
                 let mut cx = BackendContext::new(cx);
 
                 cx.set_event_proxy(Box::new(BaseviewProxy()));
-                ViziaWindow::new(cx, win_desc, scale_policy, window, Some(Box::new(app)), on_idle)
+                ViziaWindow::new(
+                    cx,
+                    win_desc,
+                    scale_policy,
+                    window,
+                    Some(Box::new(app)),
+                    on_idle,
+                    on_frame,
+                    on_platform_event,
+                )
             },
         )
     }
@@ -169,7 +195,9 @@ impl ViziaWindow {
         win_desc: WindowDescription,
         scale_policy: WindowScalePolicy,
         app: F,
-        on_idle: Option<Box<dyn Fn(&mut Context) + Send>>,
+        on_idle: Option<Box<dyn Fn(&mut IdleContext) + Send>>,
+        on_frame: Option<Box<dyn Fn(&mut Context, Duration) + Send>>,
+        on_platform_event: Option<Box<dyn Fn(&mut Context, &baseview::Event) -> bool + Send>>,
         ignore_default_theme: bool,
     ) where
         F: Fn(&mut Context),
@@ -196,7 +224,16 @@ impl ViziaWindow {
                 let mut cx = BackendContext::new(cx);
 
                 cx.set_event_proxy(Box::new(BaseviewProxy()));
-                ViziaWindow::new(cx, win_desc, scale_policy, window, Some(Box::new(app)), on_idle)
+                ViziaWindow::new(
+                    cx,
+                    win_desc,
+                    scale_policy,
+                    window,
+                    Some(Box::new(app)),
+                    on_idle,
+                    on_frame,
+                    on_platform_event,
+                )
             },
         )
     }
@@ -209,18 +246,26 @@ impl WindowHandler for ViziaWindow {
         let context = window.gl_context().expect("Window was created without OpenGL support");
         unsafe { context.make_current() };
 
-        self.application.render();
+        let frame_drawn = self.application.render();
         context.swap_buffers();
 
         unsafe { context.make_not_current() };
+
+        if frame_drawn {
+            self.application.handle_frame(&self.on_frame);
+        }
     }
 
-    fn on_event(&mut self, _window: &mut Window<'_>, event: Event) -> EventStatus {
+    fn on_event(&mut self, window: &mut Window<'_>, event: Event) -> EventStatus {
+        if !self.application.handle_platform_event(&event, &self.on_platform_event) {
+            return EventStatus::Captured;
+        }
+
         let mut should_quit = false;
 
         self.application.handle_event(event, &mut should_quit);
 
-        self.application.handle_idle(&self.on_idle);
+        self.application.handle_idle(window, &self.on_idle);
 
         if should_quit {
             // TODO: Request close.
@@ -251,3 +296,59 @@ pub fn create_surface(
     )
     .expect("Could not create skia surface")
 }
+
+/// Converts the raw window handle provided by baseview to the version of the handle used by vizia.
+fn convert_raw_window_handle(
+    handle: raw_window_handle::RawWindowHandle,
+) -> Option<rwh_06::RawWindowHandle> {
+    use raw_window_handle::RawWindowHandle;
+    use std::num::{NonZeroIsize, NonZeroU32};
+    use std::ptr::NonNull;
+
+    match handle {
+        RawWindowHandle::Win32(handle) => {
+            let mut win32 =
+                rwh_06::Win32WindowHandle::new(NonZeroIsize::new(handle.hwnd as isize)?);
+            win32.hinstance = NonZeroIsize::new(handle.hinstance as isize);
+            Some(rwh_06::RawWindowHandle::Win32(win32))
+        }
+        RawWindowHandle::AppKit(handle) => Some(rwh_06::RawWindowHandle::AppKit(
+            rwh_06::AppKitWindowHandle::new(NonNull::new(handle.ns_view)?),
+        )),
+        RawWindowHandle::Xlib(handle) => {
+            let mut xlib = rwh_06::XlibWindowHandle::new(handle.window);
+            xlib.visual_id = handle.visual_id;
+            Some(rwh_06::RawWindowHandle::Xlib(xlib))
+        }
+        RawWindowHandle::Xcb(handle) => {
+            let mut xcb = rwh_06::XcbWindowHandle::new(NonZeroU32::new(handle.window)?);
+            xcb.visual_id = NonZeroU32::new(handle.visual_id);
+            Some(rwh_06::RawWindowHandle::Xcb(xcb))
+        }
+        _ => None,
+    }
+}
+
+/// Converts the raw display handle provided by baseview to the version of the handle used by vizia.
+fn convert_raw_display_handle(
+    handle: raw_window_handle::RawDisplayHandle,
+) -> Option<rwh_06::RawDisplayHandle> {
+    use raw_window_handle::RawDisplayHandle;
+    use std::ptr::NonNull;
+
+    match handle {
+        RawDisplayHandle::Windows(_) => {
+            Some(rwh_06::RawDisplayHandle::Windows(rwh_06::WindowsDisplayHandle::new()))
+        }
+        RawDisplayHandle::AppKit(_) => {
+            Some(rwh_06::RawDisplayHandle::AppKit(rwh_06::AppKitDisplayHandle::new()))
+        }
+        RawDisplayHandle::Xlib(handle) => Some(rwh_06::RawDisplayHandle::Xlib(
+            rwh_06::XlibDisplayHandle::new(NonNull::new(handle.display), handle.screen),
+        )),
+        RawDisplayHandle::Xcb(handle) => Some(rwh_06::RawDisplayHandle::Xcb(
+            rwh_06::XcbDisplayHandle::new(NonNull::new(handle.connection), handle.screen),
+        )),
+        _ => None,
+    }
+}
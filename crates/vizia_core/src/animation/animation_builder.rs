@@ -1,9 +1,38 @@
+use crate::animation::TimingFunction;
 use crate::prelude::*;
 
-use vizia_style::{BorderWidth, Property};
+use vizia_style::{BorderWidth, EasingFunction, Property};
+
+/// The direction in which each iteration of an animation plays.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum AnimationDirection {
+    /// Every iteration plays forwards.
+    #[default]
+    Normal,
+    /// Every iteration plays backwards.
+    Reverse,
+    /// Iterations alternate between playing forwards and backwards, starting forwards.
+    Alternate,
+    /// Iterations alternate between playing backwards and forwards, starting backwards.
+    AlternateReverse,
+}
+
+impl AnimationDirection {
+    /// Returns true if the iteration with the given index plays backwards.
+    pub(crate) fn is_reversed(&self, iteration: u32) -> bool {
+        match self {
+            AnimationDirection::Normal => false,
+            AnimationDirection::Reverse => true,
+            AnimationDirection::Alternate => iteration % 2 == 1,
+            AnimationDirection::AlternateReverse => iteration % 2 == 0,
+        }
+    }
+}
 
 pub struct AnimationBuilder<'a> {
     pub(crate) keyframes: Vec<KeyframeBuilder<'a>>,
+    pub(crate) iterations: f32,
+    pub(crate) direction: AnimationDirection,
 }
 
 impl<'a> Default for AnimationBuilder<'a> {
@@ -14,7 +43,7 @@ impl<'a> Default for AnimationBuilder<'a> {
 
 impl<'a> AnimationBuilder<'a> {
     pub fn new() -> Self {
-        Self { keyframes: Vec::new() }
+        Self { keyframes: Vec::new(), iterations: 1.0, direction: AnimationDirection::Normal }
     }
 
     pub fn keyframe(
@@ -27,17 +56,45 @@ impl<'a> AnimationBuilder<'a> {
 
         self
     }
+
+    /// Sets the number of times the animation plays. Fractional values stop the animation part way through its
+    /// last iteration. Defaults to one.
+    pub fn iterations(mut self, iterations: f32) -> Self {
+        self.iterations = iterations.max(0.0);
+
+        self
+    }
+
+    /// Sets the animation to repeat until it is replaced by another animation or the view is removed.
+    pub fn looping(self) -> Self {
+        self.iterations(f32::INFINITY)
+    }
+
+    /// Sets the direction in which each iteration of the animation plays.
+    pub fn direction(mut self, direction: AnimationDirection) -> Self {
+        self.direction = direction;
+
+        self
+    }
 }
 
 pub struct KeyframeBuilder<'a> {
     pub(crate) time: f32,
+    pub(crate) timing_function: TimingFunction,
     pub(crate) properties: Vec<Property<'a>>,
 }
 
 // TODO: Make a macro for these
 impl<'a> KeyframeBuilder<'a> {
     pub(crate) fn new(time: f32) -> Self {
-        Self { time, properties: Vec::new() }
+        Self { time, timing_function: TimingFunction::linear(), properties: Vec::new() }
+    }
+
+    /// Sets the easing used between this keyframe and the next. Defaults to linear.
+    pub fn timing_function(mut self, easing: EasingFunction) -> Self {
+        self.timing_function = easing.into();
+
+        self
     }
 
     // DISPLAY
@@ -13,13 +13,35 @@ pub(crate) struct Keyframe<T: Interpolator> {
     pub timing_function: TimingFunction,
 }
 
+/// The timing of a keyframe, and the playback of the animation it belongs to.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct KeyframeTiming {
+    pub time: f32,
+    pub timing_function: TimingFunction,
+    pub iterations: f32,
+    pub direction: AnimationDirection,
+}
+
+impl KeyframeTiming {
+    /// Creates the timing of a keyframe at the given normalized time, for an animation which plays once.
+    pub fn new(time: f32) -> Self {
+        Self {
+            time,
+            timing_function: TimingFunction::linear(),
+            iterations: 1.0,
+            direction: AnimationDirection::Normal,
+        }
+    }
+}
+
 /// Represents an animation of a property with type `T`.
 #[derive(Clone, Debug)]
 pub(crate) struct AnimationState<T: Interpolator> {
     /// ID of the animation description.
     pub id: Animation,
-    /// The start time of the animation.
-    pub start_time: Instant,
+    /// The start time of the animation, or `None` if the animation starts at the time of the frame in which it is
+    /// next ticked.
+    pub start_time: Option<Instant>,
     /// The duration of the animation.
     pub duration: Duration,
     /// The delay before the animation starts.
@@ -30,6 +52,10 @@ pub(crate) struct AnimationState<T: Interpolator> {
     pub output: Option<T>,
     /// Whether the animation should persist after finishing.
     pub persistent: bool,
+    /// The number of times the animation plays.
+    pub iterations: f32,
+    /// The direction in which each iteration of the animation plays.
+    pub direction: AnimationDirection,
     /// How far through the animation between 0.0 and 1.0.
     pub t: f32,
 
@@ -54,12 +80,14 @@ where
     pub(crate) fn new(id: Animation) -> Self {
         AnimationState {
             id,
-            start_time: Instant::now(),
+            start_time: None,
             duration: Duration::new(0, 0),
             delay: Duration::new(0, 0),
             keyframes: Vec::new(),
             output: None,
             persistent: false,
+            iterations: 1.0,
+            direction: AnimationDirection::Normal,
             t: 0.0,
             dt: 0.0,
             active: false,
@@ -91,10 +119,10 @@ where
         self.output.as_ref()
     }
 
-    pub(crate) fn play(&mut self, entity: Entity) {
+    pub(crate) fn play(&mut self, entity: Entity, start_time: Option<Instant>) {
         self.active = true;
         self.t = 0.0;
-        self.start_time = Instant::now();
+        self.start_time = start_time;
         self.entities.insert(entity);
     }
 
@@ -110,12 +138,14 @@ where
     fn default() -> Self {
         AnimationState {
             id: Animation::null(),
-            start_time: Instant::now(),
+            start_time: None,
             duration: Duration::new(0, 0),
             delay: Duration::new(0, 0),
             keyframes: Vec::new(),
             output: None,
             persistent: true,
+            iterations: 1.0,
+            direction: AnimationDirection::Normal,
             t: 0.0,
             dt: 0.0,
             active: false,
@@ -2,7 +2,7 @@ mod animation_id;
 pub use animation_id::{AnimId, Animation};
 
 mod animation_state;
-pub(crate) use animation_state::{AnimationState, Keyframe};
+pub(crate) use animation_state::{AnimationState, Keyframe, KeyframeTiming};
 
 mod interpolator;
 pub(crate) use interpolator::Interpolator;
@@ -10,5 +10,9 @@ pub(crate) use interpolator::Interpolator;
 mod timing_function;
 pub(crate) use timing_function::TimingFunction;
 
+mod spring;
+pub(crate) use spring::SpringState;
+pub use spring::{Spring, SpringAction, SpringHandle};
+
 mod animation_builder;
 pub use animation_builder::*;
@@ -0,0 +1,197 @@
+use std::rc::Rc;
+
+use web_time::{Duration, Instant};
+
+use crate::{context::EventContext, entity::Entity};
+
+// The largest step used when integrating a spring. Longer frames are split into several steps to keep stiff
+// springs stable.
+const MAX_STEP: f32 = 1.0 / 240.0;
+
+/// A damped spring which drives an `f32` value towards a target.
+///
+/// A spring can be stepped manually with [`step`](Spring::step), or played on a view with
+/// [`EventContext::play_spring`], in which case it is advanced by the real time elapsed between frames.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// let mut spring = Spring::new(0.0).target(100.0);
+/// spring.step(Duration::from_millis(16));
+/// assert!(spring.value() > 0.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spring {
+    stiffness: f32,
+    damping: f32,
+    mass: f32,
+    value: f32,
+    velocity: f32,
+    target: f32,
+    rest_threshold: f32,
+}
+
+impl Default for Spring {
+    fn default() -> Self {
+        Self::new(0.0)
+    }
+}
+
+impl Spring {
+    /// Creates a new spring at rest at the given value.
+    pub fn new(value: f32) -> Self {
+        Self {
+            stiffness: 170.0,
+            damping: 26.0,
+            mass: 1.0,
+            value,
+            velocity: 0.0,
+            target: value,
+            rest_threshold: 0.01,
+        }
+    }
+
+    /// Sets the stiffness of the spring. Stiffer springs move towards their target more quickly.
+    pub fn stiffness(mut self, stiffness: f32) -> Self {
+        self.stiffness = stiffness.max(0.0);
+
+        self
+    }
+
+    /// Sets the damping of the spring. Springs with less damping oscillate around their target for longer.
+    pub fn damping(mut self, damping: f32) -> Self {
+        self.damping = damping.max(0.0);
+
+        self
+    }
+
+    /// Sets the mass attached to the spring. Heavier springs respond more slowly.
+    pub fn mass(mut self, mass: f32) -> Self {
+        self.mass = mass.max(f32::EPSILON);
+
+        self
+    }
+
+    /// Sets the initial velocity of the spring, such as the velocity of a gesture when it is released.
+    pub fn velocity(mut self, velocity: f32) -> Self {
+        self.velocity = velocity;
+
+        self
+    }
+
+    /// Sets the target value of the spring.
+    pub fn target(mut self, target: f32) -> Self {
+        self.target = target;
+
+        self
+    }
+
+    /// Sets the distance from the target, and the speed, below which the spring is considered settled.
+    pub fn rest_threshold(mut self, threshold: f32) -> Self {
+        self.rest_threshold = threshold.abs();
+
+        self
+    }
+
+    /// Returns the current value of the spring.
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    /// Returns the current velocity of the spring, in units per second.
+    pub fn current_velocity(&self) -> f32 {
+        self.velocity
+    }
+
+    /// Returns the target value of the spring.
+    pub fn current_target(&self) -> f32 {
+        self.target
+    }
+
+    /// Sets the target value of the spring, keeping its current value and velocity.
+    pub fn set_target(&mut self, target: f32) {
+        self.target = target;
+    }
+
+    /// Moves the spring to the given value, keeping its current target and velocity.
+    pub fn set_value(&mut self, value: f32) {
+        self.value = value;
+    }
+
+    /// Sets the current velocity of the spring.
+    pub fn set_velocity(&mut self, velocity: f32) {
+        self.velocity = velocity;
+    }
+
+    /// Returns true if the spring is at rest at its target.
+    pub fn is_settled(&self) -> bool {
+        (self.target - self.value).abs() <= self.rest_threshold
+            && self.velocity.abs() <= self.rest_threshold
+    }
+
+    /// Advances the spring by the given time and returns its new value. The value snaps to the target once the
+    /// spring has settled.
+    pub fn step(&mut self, dt: Duration) -> f32 {
+        let mut remaining = dt.as_secs_f32();
+        while remaining > 0.0 && !self.is_settled() {
+            let step = remaining.min(MAX_STEP);
+            let force = -self.stiffness * (self.value - self.target) - self.damping * self.velocity;
+            self.velocity += force / self.mass * step;
+            self.value += self.velocity * step;
+            remaining -= step;
+        }
+
+        if self.is_settled() {
+            self.value = self.target;
+            self.velocity = 0.0;
+        }
+
+        self.value
+    }
+}
+
+/// Enum which can be used to determine the reason a spring callback was called.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SpringAction {
+    /// The spring was advanced and has the given value.
+    Update(f32),
+    /// The spring came to rest at its target, which is given.
+    Settled(f32),
+}
+
+/// A handle used to modify or stop a spring played with `cx.play_spring()`.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct SpringHandle(pub usize);
+
+#[derive(Clone)]
+pub(crate) struct SpringState {
+    pub(crate) entity: Entity,
+    pub(crate) id: SpringHandle,
+    pub(crate) spring: Spring,
+    pub(crate) time: Instant,
+    pub(crate) callback: Rc<dyn Fn(&mut EventContext, SpringAction)>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Spring;
+    use web_time::Duration;
+
+    #[test]
+    fn settles_at_target() {
+        let mut spring = Spring::new(0.0).target(1.0);
+        spring.step(Duration::from_secs(5));
+        assert!(spring.is_settled());
+        assert_eq!(spring.value(), 1.0);
+    }
+
+    #[test]
+    fn underdamped_overshoots() {
+        let mut spring = Spring::new(0.0).target(1.0).damping(2.0);
+        let mut max = 0.0f32;
+        for _ in 0..60 {
+            max = max.max(spring.step(Duration::from_millis(16)));
+        }
+        assert!(max > 1.0);
+    }
+}
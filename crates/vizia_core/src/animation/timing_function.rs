@@ -1,3 +1,5 @@
+use vizia_style::EasingFunction;
+
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct TimingFunction {
     x1: f32,
@@ -30,6 +32,19 @@ impl TimingFunction {
     }
 }
 
+impl From<EasingFunction> for TimingFunction {
+    fn from(easing: EasingFunction) -> Self {
+        match easing {
+            EasingFunction::Linear => TimingFunction::linear(),
+            EasingFunction::Ease => TimingFunction::ease(),
+            EasingFunction::EaseIn => TimingFunction::ease_in(),
+            EasingFunction::EaseOut => TimingFunction::ease_out(),
+            EasingFunction::EaseInOut => TimingFunction::ease_in_out(),
+            EasingFunction::CubicBezier(x1, y1, x2, y2) => TimingFunction::new(x1, y1, x2, y2),
+        }
+    }
+}
+
 impl TimingFunction {
     pub fn new(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
         Self { x1, y1, x2, y2 }
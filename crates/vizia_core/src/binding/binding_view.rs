@@ -137,6 +137,39 @@ where
     }
 }
 
+impl<L, O> Binding<Map<L, O>>
+where
+    L: 'static + Lens<Source: 'static>,
+    O: 'static + Data,
+{
+    /// Creates a new binding view which only rebuilds its contents when a value derived from the observed data changes.
+    ///
+    /// The `projection` is applied to the data observed through `lens` and the contents are rebuilt only when the projected
+    /// value changes, as determined by its [`Data`] implementation, which is `PartialEq` for simple types. This avoids
+    /// needlessly rebuilding the contents when the observed data changes frequently but the derived value does not.
+    ///
+    /// The builder receives a lens to the projected value. This is equivalent to `Binding::new(cx, lens.map(projection), builder)`,
+    /// so `lens` can be any lens, including one built with the combinators of [`LensExt`], such as [`then`](LensExt::then)
+    /// or [`map`](LensExt::map).
+    ///
+    /// # Example
+    /// The label inside of the binding is only rebuilt when `AppData::selected_option` crosses the threshold of 5.
+    /// ```ignore
+    /// Binding::derived(cx, AppData::selected_option, |selected| *selected > 5, |cx, is_large| {
+    ///     if is_large.get(cx) {
+    ///         Label::new(cx, "Large");
+    ///     }
+    /// });
+    /// ```
+    pub fn derived<P, F>(cx: &mut Context, lens: L, projection: P, builder: F)
+    where
+        P: 'static + Fn(&L::Target) -> O,
+        F: 'static + Fn(&mut Context, Map<L, O>),
+    {
+        Binding::new(cx, lens.map(projection), builder);
+    }
+}
+
 pub(crate) trait BindingHandler {
     fn update(&mut self, cx: &mut Context);
     fn remove(&self, cx: &mut Context);
@@ -74,6 +74,7 @@ impl_data_simple!(KeyChord);
 impl_data_simple!(FamilyOwned);
 impl_data_simple!(FontWeight);
 impl_data_simple!(TextAlign);
+impl_data_simple!(LineHeight);
 impl_data_simple!(LengthOrPercentage);
 impl_data_simple!(CornerShape);
 impl_data_simple!(Shadow);
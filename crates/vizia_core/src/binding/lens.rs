@@ -104,6 +104,32 @@ pub trait LensExt: Lens {
         Then::new(self, other)
     }
 
+    /// Used to construct a lens to a tuple containing the data of two lenses with the same source.
+    ///
+    /// A binding to the combined lens is updated when the data of either lens changes.
+    ///
+    /// # Example
+    /// Binds a label to both the `options` and the `selected_option` fields of the root `AppData` model:
+    /// ```compile_fail
+    /// Label::new(
+    ///     cx,
+    ///     AppData::options
+    ///         .zip(AppData::selected_option)
+    ///         .map(|(options, selected)| options[*selected].clone()),
+    /// );
+    /// ```
+    fn zip<Other>(self, other: Other) -> Zip<Self, Other>
+    where
+        Other: Lens<Source = Self::Source>,
+    {
+        Zip::new(self, other)
+    }
+
+    /// Used to construct a lens to the item at `index` of some lensed slice data.
+    ///
+    /// The lens yields no data, rather than panicking, if the index is out of bounds, for example when the
+    /// list has shrunk since the index was stored. Use [`get_ref`](crate::binding::ResGet::get_ref) to read
+    /// the item when the index may be out of bounds.
     fn idx<T>(self, index: usize) -> Index<Self, T>
     where
         T: 'static,
@@ -122,6 +148,22 @@ pub trait LensExt: Lens {
     }
 
     fn map_ref<O: 'static, F: 'static + Fn(&Self::Target) -> &O>(self, map: F) -> MapRef<Self, O> {
+        self.try_map_ref(move |target| Some(map(target)))
+    }
+
+    /// Used to construct a lens to a reference into some lensed data which may not exist.
+    ///
+    /// The lens yields no data when the closure returns `None`, so bindings to it don't panic when, for example,
+    /// a stored index is out of bounds of the list it indexes.
+    ///
+    /// # Example
+    /// ```compile_fail
+    /// let item = AppData::options.try_map_ref(move |options| options.get(index));
+    /// ```
+    fn try_map_ref<O: 'static, F: 'static + Fn(&Self::Target) -> Option<&O>>(
+        self,
+        map: F,
+    ) -> MapRef<Self, O> {
         let id = MAP_MANAGER.with_borrow_mut(|f| f.create());
         let entity = CURRENT.with_borrow(|f| *f);
         MAPS.with_borrow_mut(|f| {
@@ -153,7 +195,7 @@ pub struct MapState<T, O> {
 }
 
 pub struct MapRefState<T, O> {
-    closure: Rc<dyn Fn(&T) -> &O>,
+    closure: Rc<dyn Fn(&T) -> Option<&O>>,
 }
 
 pub struct Map<L: Lens, O> {
@@ -227,7 +269,7 @@ impl<L: Lens, O: 'static> Lens for MapRef<L, O> {
             Some(closure.clone())
         })?;
 
-        Some(LensValue::Borrowed(closure(target)))
+        closure(target).map(LensValue::Borrowed)
     }
 }
 
@@ -299,6 +341,53 @@ impl<A: Lens, B: Lens> Debug for Then<A, B> {
 
 impl<T: Copy, U: Copy> Copy for Then<T, U> {}
 
+/// `Lens` combining the data of two lenses with the same source into a tuple.
+#[derive(Hash)]
+pub struct Zip<A, B> {
+    a: A,
+    b: B,
+}
+
+impl<A, B> Zip<A, B> {
+    pub fn new(a: A, b: B) -> Self
+    where
+        A: Lens,
+        B: Lens<Source = A::Source>,
+    {
+        Self { a, b }
+    }
+}
+
+impl<A, B> Lens for Zip<A, B>
+where
+    A: Lens<Target: Clone>,
+    B: Lens<Source = A::Source, Target: Clone>,
+{
+    type Source = A::Source;
+    type Target = (A::Target, B::Target);
+
+    fn view<'a>(&self, source: &'a Self::Source) -> Option<LensValue<'a, Self::Target>> {
+        let a = self.a.view(source)?.into_owned();
+        let b = self.b.view(source)?.into_owned();
+
+        Some(LensValue::Owned((a, b)))
+    }
+}
+
+impl<T: Clone, U: Clone> Clone for Zip<T, U> {
+    fn clone(&self) -> Self {
+        Self { a: self.a.clone(), b: self.b.clone() }
+    }
+}
+
+impl<A: Lens, B: Lens> Debug for Zip<A, B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{:?}.zip({:?})", self.a, self.b))
+    }
+}
+
+impl<T: Copy, U: Copy> Copy for Zip<T, U> {}
+
 pub struct Index<L, T> {
     lens: L,
     index: usize,
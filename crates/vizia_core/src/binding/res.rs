@@ -123,6 +123,7 @@ impl_res_clone!(LinearGradientBuilder);
 impl_res_clone!(ShadowBuilder);
 impl_res_simple!(FontVariation);
 impl_res_clone!(Filter);
+impl_res_clone!(LineHeight);
 impl_res_simple!(Opacity);
 impl_res_simple!(FontWidth);
 impl_res_clone!(Translate);
@@ -133,6 +134,7 @@ impl_res_simple!(ButtonVariant);
 impl_res_simple!(AvatarVariant);
 impl_res_clone!(FamilyOwned);
 impl_res_simple!(TextDecorationLine);
+impl_res_simple!(WindowLevel);
 
 impl<'i> ResGet<FontFamily<'i>> for FontFamily<'i> {
     fn get_ref<'a>(&'a self, _: &'a impl DataContext) -> Option<LensValue<'a, Self>> {
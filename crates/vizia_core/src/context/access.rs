@@ -71,6 +71,16 @@ impl AccessNode {
         });
     }
 
+    /// Sets whether the node, such as a combobox or tree item, is expanded.
+    pub fn set_expanded(&mut self, expanded: bool) {
+        self.node_builder.set_expanded(expanded);
+    }
+
+    /// Sets whether the node, such as an option of a list box, is selected.
+    pub fn set_selected(&mut self, selected: bool) {
+        self.node_builder.set_selected(selected);
+    }
+
     /// Sets the value of a node.
     pub fn set_value(&mut self, value: impl Into<Box<str>>) {
         self.node_builder.set_value(value);
@@ -1,5 +1,6 @@
-use std::any::Any;
+use std::any::{Any, TypeId};
 
+use raw_window_handle::{RawDisplayHandle, RawWindowHandle};
 use skia_safe::Surface;
 use vizia_window::{WindowDescription, WindowPosition};
 
@@ -108,15 +109,73 @@ impl BackendContext {
     }
 
     /// Calls the draw system.
+    ///
+    /// Returns the region of the surface, in physical pixels, which changed since the previous frame, or `None`
+    /// if nothing was drawn. Only this region of the dirty surface is redrawn, but the whole of `surface` is
+    /// composited from it, so the region can be passed to the platform as present-only damage.
     pub fn draw(
         &mut self,
         window_entity: Entity,
         surface: &mut Surface,
         dirty_surface: &mut Surface,
-    ) -> bool {
+    ) -> Option<BoundingBox> {
         draw_system(&mut self.0, window_entity, surface, dirty_surface)
     }
 
+    /// Returns true if a view has requested a capture of the next frame drawn to the window.
+    pub fn has_frame_capture_requests(&self, window_entity: Entity) -> bool {
+        self.0
+            .windows
+            .get(&window_entity)
+            .is_some_and(|window_state| !window_state.frame_capture_requests.is_empty())
+    }
+
+    /// Sends a frame captured from the window to the views which requested it.
+    pub fn send_captured_frame(&mut self, window_entity: Entity, frame: CapturedFrame) {
+        let requests = self
+            .0
+            .windows
+            .get_mut(&window_entity)
+            .map(|window_state| std::mem::take(&mut window_state.frame_capture_requests))
+            .unwrap_or_default();
+
+        for entity in requests {
+            self.0.event_queue.push_back(
+                Event::new(WindowEvent::FrameCaptured(frame.clone()))
+                    .direct(entity)
+                    .origin(window_entity),
+            );
+        }
+    }
+
+    /// Sends a [`WindowEvent::WindowCloseRequested`] event to the window, which views can respond to by preventing
+    /// the window from closing. Use [`BackendContext::is_window_close_prevented`] once the event has been handled to
+    /// check whether to close the window.
+    pub fn request_window_close(&mut self, window_entity: Entity) {
+        if let Some(window_state) = self.0.windows.get_mut(&window_entity) {
+            window_state.close_prevented = false;
+        }
+
+        self.emit_window_event(window_entity, WindowEvent::WindowCloseRequested);
+    }
+
+    /// Returns true if a view prevented the window from closing while handling the latest close request.
+    pub fn is_window_close_prevented(&self, window_entity: Entity) -> bool {
+        self.0.windows.get(&window_entity).is_some_and(|window_state| window_state.close_prevented)
+    }
+
+    /// Sets the monitors connected to the system.
+    pub fn set_monitors(&mut self, monitors: Vec<MonitorInfo>) {
+        self.0.monitors = monitors;
+    }
+
+    /// Sets the monitor which the window is currently on.
+    pub fn set_window_monitor(&mut self, window_entity: Entity, monitor: Option<MonitorInfo>) {
+        if let Some(window_state) = self.0.windows.get_mut(&window_entity) {
+            window_state.monitor = monitor;
+        }
+    }
+
     /// Set the current entity. This is useful in user code when you're performing black magic and
     /// want to trick other parts of the code into thinking you're processing some other part of the
     /// tree.
@@ -157,6 +216,72 @@ impl BackendContext {
         }
     }
 
+    /// Sets whether the window has keyboard focus, emitting a [`WindowEvent::WindowFocused`] event to the focused
+    /// view if it is within the window, or to the window otherwise.
+    pub fn set_window_focused(&mut self, window_entity: Entity, flag: bool) {
+        self.0.window_has_focus = flag;
+
+        let focused = self.0.focused;
+        let target = if focused != Entity::null()
+            && self.0.tree.get_parent_window(focused).unwrap_or(Entity::root()) == window_entity
+        {
+            focused
+        } else {
+            window_entity
+        };
+
+        self.0.event_queue.push_back(
+            Event::new(WindowEvent::WindowFocused(flag))
+                .target(target)
+                .origin(window_entity)
+                .propagate(Propagation::Up),
+        );
+    }
+
+    /// Sets whether the window is minimized, emitting a [`WindowEvent::Minimized`] event to the window if
+    /// this has changed.
+    pub fn set_window_minimized(&mut self, window_entity: Entity, flag: bool) {
+        if let Some(state) = self.0.windows.get_mut(&window_entity) {
+            if state.minimized != flag {
+                state.minimized = flag;
+                self.emit_window_event(window_entity, WindowEvent::Minimized(flag));
+            }
+        }
+    }
+
+    /// Sets whether the window is maximized, emitting a [`WindowEvent::Maximized`] event to the window if
+    /// this has changed.
+    pub fn set_window_maximized(&mut self, window_entity: Entity, flag: bool) {
+        if let Some(state) = self.0.windows.get_mut(&window_entity) {
+            if state.maximized != flag {
+                state.maximized = flag;
+                self.emit_window_event(window_entity, WindowEvent::Maximized(flag));
+            }
+        }
+    }
+
+    /// Sets the raw handle of the platform window for the window with the given entity.
+    pub fn set_raw_window_handle(
+        &mut self,
+        window_entity: Entity,
+        handle: Option<RawWindowHandle>,
+    ) {
+        if let Some(state) = self.0.windows.get_mut(&window_entity) {
+            state.raw_window_handle = handle;
+        }
+    }
+
+    /// Sets the raw handle of the display for the window with the given entity.
+    pub fn set_raw_display_handle(
+        &mut self,
+        window_entity: Entity,
+        handle: Option<RawDisplayHandle>,
+    ) {
+        if let Some(state) = self.0.windows.get_mut(&window_entity) {
+            state.raw_display_handle = handle;
+        }
+    }
+
     /// Temporarily sets the current entity, calls the provided closure, and then resets the current entity back to previous.
     pub fn with_current(&mut self, e: Entity, f: impl FnOnce(&mut Context)) {
         let prev = self.0.current;
@@ -197,10 +322,61 @@ impl BackendContext {
         !self.0.event_queue.is_empty()
     }
 
+    /// Sets the theme mode reported by the system, applying it immediately if the application follows the
+    /// system theme.
+    ///
+    /// Backends should call this once a window has been created, before the first frame is drawn, so that
+    /// the application starts in the correct theme rather than switching after the first frame.
+    pub fn set_system_theme(&mut self, theme_mode: ThemeMode) {
+        let cx = &mut EventContext::new(&mut self.0);
+        if let Some(mut model_data_store) = cx.data.remove(&Entity::root()) {
+            if let Some(model) = model_data_store.models.get_mut(&TypeId::of::<Environment>()) {
+                model.event(cx, &mut Event::new(WindowEvent::ThemeChanged(theme_mode)));
+            }
+
+            self.0.data.insert(Entity::root(), model_data_store);
+        }
+    }
+
+    /// Sets whether the system requests reduced motion, emitting a [`WindowEvent::ReducedMotionChanged`] event
+    /// to the whole tree if the preference has changed.
+    pub fn set_reduced_motion(&mut self, flag: bool) {
+        if self.0.environment().reduced_motion != flag {
+            self.0.event_queue.push_back(
+                Event::new(WindowEvent::ReducedMotionChanged(flag))
+                    .target(Entity::root())
+                    .propagate(Propagation::Subtree),
+            );
+        }
+    }
+
+    /// Sets whether the system requests high contrast, emitting a [`WindowEvent::HighContrastChanged`] event
+    /// to the whole tree if the preference has changed.
+    pub fn set_high_contrast(&mut self, flag: bool) {
+        if self.0.environment().high_contrast != flag {
+            self.0.event_queue.push_back(
+                Event::new(WindowEvent::HighContrastChanged(flag))
+                    .target(Entity::root())
+                    .propagate(Propagation::Subtree),
+            );
+        }
+    }
+
     pub fn renegotiate_language(&mut self) {
         self.0.resource_manager.renegotiate_language();
     }
 
+    /// Restricts text rendering to fonts added with [`Context::add_font_mem`], ignoring any fonts
+    /// installed on the system. This is useful for rendering text identically across machines.
+    ///
+    /// Text whose font families don't match any of the added fonts is rendered with `fallback_family`.
+    pub fn disable_system_fonts(&mut self, fallback_family: &str) {
+        let font_collection = &mut self.0.text_context.font_collection;
+        font_collection.set_default_font_manager(None, fallback_family);
+        font_collection.disable_font_fallback();
+        font_collection.clear_caches();
+    }
+
     /// Calls the accessibility system and updates the accesskit node tree.
     pub fn process_tree_updates(
         &mut self,
@@ -225,9 +401,10 @@ impl BackendContext {
         image_system(&mut self.0);
     }
 
-    // Returns true if animations are playing
-    pub fn process_animations(&mut self) -> bool {
-        animation_system(&mut self.0)
+    /// Advances all animations, transitions and springs to the given frame time, which should be the time at which
+    /// the current frame started. Returns true if animations are playing.
+    pub fn process_animations(&mut self, time: Instant) -> bool {
+        animation_system(&mut self.0, time)
     }
 
     /// Massages the style system until everything is coherent
@@ -288,3 +465,32 @@ impl BackendContext {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn animations_advance_with_the_frame_time() {
+        let mut cx = BackendContext::new(Context::default());
+        let element = Element::new(cx.context()).entity();
+        let fade = cx.context().add_animation(
+            AnimationBuilder::new()
+                .keyframe(0.0, |key| key.opacity(0.0))
+                .keyframe(1.0, |key| key.opacity(1.0)),
+        );
+        cx.0.style.enqueue_animation(element, fade, Duration::from_secs(1), Duration::ZERO);
+
+        // The animation starts at the time of the first frame, even when that time is in the past.
+        let start = Instant::now() - Duration::from_secs(10);
+        let mut opacity_at = |time: Instant| {
+            cx.process_animations(time);
+            cx.style().opacity.get(element).map(|opacity| opacity.0)
+        };
+
+        for (elapsed, expected) in [(0, 0.0), (250, 0.25), (750, 0.75), (1000, 1.0)] {
+            let opacity = opacity_at(start + Duration::from_millis(elapsed)).unwrap();
+            assert!((opacity - expected).abs() < 1e-3, "{opacity} at {elapsed}ms");
+        }
+    }
+}
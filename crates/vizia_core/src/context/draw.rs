@@ -22,6 +22,21 @@ use crate::resource::{ImageOrSvg, ResourceManager};
 use crate::text::TextContext;
 use vizia_input::MouseState;
 
+/// Information about the frame passed to a post-process callback set with [`Context::set_post_process`].
+#[derive(Debug, Clone, Copy)]
+pub struct PostProcessInfo {
+    /// The window being drawn.
+    pub window: Entity,
+    /// The width of the window surface in physical pixels.
+    pub width: u32,
+    /// The height of the window surface in physical pixels.
+    pub height: u32,
+    /// The scale factor of the window.
+    pub scale_factor: f32,
+}
+
+pub(crate) type PostProcessCallback = Box<dyn Fn(&Canvas, &skia_safe::Image, &PostProcessInfo)>;
+
 /// A context used when drawing a view.
 ///
 /// The `DrawContext` is provided by the [`draw`](crate::view::View::draw) method in [`View`] and can be used to immutably access the
@@ -443,6 +458,17 @@ impl<'a> DrawContext<'a> {
         self.style.line_clamp.get(self.current).copied().map(|lc| lc.0 as usize)
     }
 
+    /// Returns the line height of the current view.
+    pub fn line_height(&self) -> LineHeight {
+        self.style.line_height.get(self.current).cloned().unwrap_or_default()
+    }
+
+    /// Returns the letter spacing of the current view in physical pixels.
+    pub fn letter_spacing(&self) -> f32 {
+        self.style.letter_spacing.get(self.current).and_then(Length::to_px).unwrap_or_default()
+            * self.scale_factor()
+    }
+
     /// Returns a reference to the shadows of the current view.
     pub fn shadows(&self) -> Option<&Vec<Shadow>> {
         self.style.shadow.get(self.current)
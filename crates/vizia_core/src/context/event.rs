@@ -2,19 +2,23 @@ use std::any::{Any, TypeId};
 use std::collections::{BinaryHeap, VecDeque};
 #[cfg(feature = "clipboard")]
 use std::error::Error;
+use std::future::Future;
 use std::rc::Rc;
 
 use hashbrown::{HashMap, HashSet};
-use vizia_storage::{LayoutTreeIterator, TreeIterator};
+use raw_window_handle::{RawDisplayHandle, RawWindowHandle};
+use vizia_storage::LayoutTreeIterator;
 use vizia_window::WindowPosition;
 
-use crate::animation::{AnimId, Interpolator};
+use crate::animation::{AnimId, Interpolator, SpringState};
 use crate::cache::CachedData;
 use crate::events::{TimedEvent, TimedEventHandle, TimerState, ViewHandler};
+use crate::input::HotkeyEntry;
 use crate::model::ModelDataStore;
 use crate::prelude::*;
 use crate::resource::ResourceManager;
-use crate::tree::{focus_backward, focus_forward, is_navigatable};
+use crate::systems::Inspector;
+use crate::tree::next_focus;
 use vizia_input::MouseState;
 
 use skia_safe::Matrix;
@@ -23,7 +27,10 @@ use crate::text::TextContext;
 #[cfg(feature = "clipboard")]
 use copypasta::ClipboardProvider;
 
-use super::{LocalizationContext, DARK_THEME, LIGHT_THEME};
+use super::{
+    cursor_auto_hide_callback, spawn_task, InternalEvent, LocalizationContext, ThrottleState,
+    DARK_THEME, LIGHT_THEME,
+};
 
 /// A context used when handling events.
 ///
@@ -80,13 +87,27 @@ pub struct EventContext<'a> {
     pub(crate) next_event_id: &'a mut usize,
     pub(crate) timers: &'a mut Vec<TimerState>,
     pub(crate) running_timers: &'a mut BinaryHeap<TimerState>,
+    pub(crate) springs: &'a mut Vec<SpringState>,
+    pub(crate) next_spring_id: &'a mut usize,
     cursor_icon_locked: &'a mut bool,
+    inspector: &'a mut Inspector,
     #[cfg(feature = "clipboard")]
     clipboard: &'a mut Box<dyn ClipboardProvider>,
     pub(crate) event_proxy: &'a mut Option<Box<dyn crate::context::EventProxy>>,
     pub(crate) ignore_default_theme: &'a bool,
+    pub(crate) window_has_focus: &'a bool,
     pub(crate) drop_data: &'a mut Option<DropData>,
+    pub(crate) monitors: &'a [MonitorInfo],
+    pub(crate) tooltip_config: &'a TooltipConfig,
+    pub(crate) long_press_origin: &'a mut Option<(f32, f32)>,
+    pub(crate) drag_threshold_exceeded: &'a bool,
+    pub(crate) tasks: &'a mut HashMap<Entity, Vec<TaskHandle>>,
+    pub(crate) debounced_events: &'a mut HashMap<(Entity, TypeId), TimedEventHandle>,
+    pub(crate) throttled_events: &'a mut HashMap<(Entity, TypeId), ThrottleState>,
+    pub(crate) hotkeys: &'a mut Vec<HotkeyEntry>,
+    pub(crate) next_hotkey_id: &'a mut usize,
     pub windows: &'a mut HashMap<Entity, WindowState>,
+    pub(crate) window_data: &'a mut HashMap<Entity, HashMap<TypeId, Box<dyn Any>>>,
 }
 
 macro_rules! get_length_property {
@@ -132,13 +153,27 @@ impl<'a> EventContext<'a> {
             next_event_id: &mut cx.next_event_id,
             timers: &mut cx.timers,
             running_timers: &mut cx.running_timers,
+            springs: &mut cx.springs,
+            next_spring_id: &mut cx.next_spring_id,
             cursor_icon_locked: &mut cx.cursor_icon_locked,
+            inspector: &mut cx.inspector,
             #[cfg(feature = "clipboard")]
             clipboard: &mut cx.clipboard,
             event_proxy: &mut cx.event_proxy,
             ignore_default_theme: &cx.ignore_default_theme,
+            window_has_focus: &cx.window_has_focus,
             drop_data: &mut cx.drop_data,
+            monitors: &cx.monitors,
+            tooltip_config: &cx.tooltip_config,
+            long_press_origin: &mut cx.long_press_origin,
+            drag_threshold_exceeded: &cx.drag_threshold_exceeded,
+            tasks: &mut cx.tasks,
+            debounced_events: &mut cx.debounced_events,
+            throttled_events: &mut cx.throttled_events,
+            hotkeys: &mut cx.hotkeys,
+            next_hotkey_id: &mut cx.next_hotkey_id,
             windows: &mut cx.windows,
+            window_data: &mut cx.window_data,
         }
     }
 
@@ -165,13 +200,27 @@ impl<'a> EventContext<'a> {
             next_event_id: &mut cx.next_event_id,
             timers: &mut cx.timers,
             running_timers: &mut cx.running_timers,
+            springs: &mut cx.springs,
+            next_spring_id: &mut cx.next_spring_id,
             cursor_icon_locked: &mut cx.cursor_icon_locked,
+            inspector: &mut cx.inspector,
             #[cfg(feature = "clipboard")]
             clipboard: &mut cx.clipboard,
             event_proxy: &mut cx.event_proxy,
             ignore_default_theme: &cx.ignore_default_theme,
+            window_has_focus: &cx.window_has_focus,
             drop_data: &mut cx.drop_data,
+            monitors: &cx.monitors,
+            tooltip_config: &cx.tooltip_config,
+            long_press_origin: &mut cx.long_press_origin,
+            drag_threshold_exceeded: &cx.drag_threshold_exceeded,
+            tasks: &mut cx.tasks,
+            debounced_events: &mut cx.debounced_events,
+            throttled_events: &mut cx.throttled_events,
+            hotkeys: &mut cx.hotkeys,
+            next_hotkey_id: &mut cx.next_hotkey_id,
             windows: &mut cx.windows,
+            window_data: &mut cx.window_data,
         }
     }
 
@@ -194,12 +243,147 @@ impl<'a> EventContext<'a> {
         WindowPosition::new(0, 0)
     }
 
+    /// Moves the window containing the current view to the given logical position.
+    ///
+    /// As with the `position` window modifier, the position of a secondary window is relative to its parent window.
+    pub fn set_window_position(&mut self, position: impl Into<WindowPosition>) {
+        let window_entity = self.current_window();
+        self.emit_to(window_entity, WindowEvent::SetPosition(position.into()));
+    }
+
+    /// Minimizes the window containing the current view, or restores it if `flag` is false.
+    ///
+    /// The window receives a [`WindowEvent::Minimized`] event once the change has been applied.
+    pub fn set_minimized(&mut self, flag: bool) {
+        let window_entity = self.current_window();
+        self.emit_to(window_entity, WindowEvent::SetMinimized(flag));
+    }
+
+    /// Maximizes the window containing the current view, or restores it if `flag` is false.
+    ///
+    /// The window receives a [`WindowEvent::Maximized`] event once the change has been applied.
+    pub fn set_maximized(&mut self, flag: bool) {
+        let window_entity = self.current_window();
+        self.emit_to(window_entity, WindowEvent::SetMaximized(flag));
+    }
+
+    /// Returns whether the window containing the current view is minimized.
+    pub fn is_minimized(&self) -> bool {
+        let parent_window = self.parent_window().unwrap_or(Entity::root());
+        self.windows.get(&parent_window).is_some_and(|state| state.minimized)
+    }
+
+    /// Returns whether the window containing the current view is maximized.
+    pub fn is_maximized(&self) -> bool {
+        let parent_window = self.parent_window().unwrap_or(Entity::root());
+        self.windows.get(&parent_window).is_some_and(|state| state.maximized)
+    }
+
+    /// Returns the raw handle of the platform window containing the current view, which can be passed to other
+    /// libraries, for example to attach a native overlay. Returns `None` if the backend doesn't provide one.
+    pub fn raw_window_handle(&self) -> Option<RawWindowHandle> {
+        let window_entity = self.current_window();
+        self.windows.get(&window_entity).and_then(|state| state.raw_window_handle)
+    }
+
+    /// Returns the raw handle of the display the window containing the current view is on. Most libraries that take
+    /// a [`RawWindowHandle`] also need this handle. Returns `None` if the backend doesn't provide one.
+    pub fn raw_display_handle(&self) -> Option<RawDisplayHandle> {
+        let window_entity = self.current_window();
+        self.windows.get(&window_entity).and_then(|state| state.raw_display_handle)
+    }
+
+    /// Sets the area of the window containing the current view, in physical pixels, which the input method editor
+    /// candidate window should avoid covering, such as the area around the text caret.
+    pub fn set_ime_cursor_area(&mut self, area: BoundingBox) {
+        let window_entity = self.current_window();
+        self.emit_to(window_entity, WindowEvent::SetImeCursorArea(area));
+    }
+
+    /// Sets the minimum logical inner size of the window containing the current view, or removes the constraint if
+    /// `None`.
+    pub fn set_window_min_size(&mut self, size: Option<WindowSize>) {
+        let window_entity = self.current_window();
+        if let Some(window_state) = self.windows.get_mut(&window_entity) {
+            window_state.window_description.min_inner_size = size;
+        }
+
+        self.emit_to(window_entity, WindowEvent::SetMinSize(size));
+    }
+
+    /// Sets the maximum logical inner size of the window containing the current view, or removes the constraint if
+    /// `None`.
+    pub fn set_window_max_size(&mut self, size: Option<WindowSize>) {
+        let window_entity = self.current_window();
+        if let Some(window_state) = self.windows.get_mut(&window_entity) {
+            window_state.window_description.max_inner_size = size;
+        }
+
+        self.emit_to(window_entity, WindowEvent::SetMaxSize(size));
+    }
+
+    /// Moves the window containing the current view to the center of the monitor it is on.
+    pub fn center_on_monitor(&mut self) {
+        let window_entity = self.current_window();
+        self.emit_to(window_entity, WindowEvent::CenterOnMonitor);
+    }
+
     pub fn window_size(&self) -> WindowSize {
         let parent_window = self.parent_window().unwrap_or(Entity::root());
         let bounds = self.cache.get_bounds(parent_window);
         WindowSize::new(bounds.width() as u32, bounds.height() as u32)
     }
 
+    // Returns the current entity if it is a window, otherwise its parent window.
+    fn current_window(&self) -> Entity {
+        if self.tree.is_window(self.current) {
+            self.current
+        } else {
+            self.parent_window().unwrap_or(Entity::root())
+        }
+    }
+
+    /// Returns a reference to the data of type `T` stored for the window containing the current view.
+    ///
+    /// Returns `None` if no data of that type has been stored for the window.
+    pub fn window_data<T: 'static>(&self) -> Option<&T> {
+        let window_entity = self.current_window();
+        self.window_data
+            .get(&window_entity)
+            .and_then(|data| data.get(&TypeId::of::<T>()))
+            .and_then(|data| data.downcast_ref::<T>())
+    }
+
+    /// Returns a mutable reference to the data of type `T` stored for the window containing the current view.
+    pub fn window_data_mut<T: 'static>(&mut self) -> Option<&mut T> {
+        let window_entity = self.current_window();
+        self.window_data
+            .get_mut(&window_entity)
+            .and_then(|data| data.get_mut(&TypeId::of::<T>()))
+            .and_then(|data| data.downcast_mut::<T>())
+    }
+
+    /// Stores data of type `T` for the window containing the current view, replacing any existing data of the same type.
+    ///
+    /// The data is dropped when the window is closed.
+    pub fn set_window_data<T: 'static>(&mut self, data: T) {
+        let window_entity = self.current_window();
+        self.window_data
+            .entry(window_entity)
+            .or_default()
+            .insert(TypeId::of::<T>(), Box::new(data));
+    }
+
+    /// Removes and returns the data of type `T` stored for the window containing the current view.
+    pub fn take_window_data<T: 'static>(&mut self) -> Option<T> {
+        let window_entity = self.current_window();
+        self.window_data
+            .get_mut(&window_entity)
+            .and_then(|data| data.remove(&TypeId::of::<T>()))
+            .and_then(|data| data.downcast::<T>().ok())
+            .map(|data| *data)
+    }
+
     /// Returns the [Entity] id associated with the given identifier.
     pub fn resolve_entity_identifier(&self, id: &str) -> Option<Entity> {
         self.entity_identifiers.get(id).cloned()
@@ -255,6 +439,50 @@ impl<'a> EventContext<'a> {
         self.style.dpi_factor as f32
     }
 
+    /// Returns the monitors connected to the system, as reported by the windowing backend.
+    ///
+    /// The winit backend updates the list when a window is created, gains focus or changes scale factor, as it isn't
+    /// told when monitors are connected or disconnected. The baseview backend doesn't report any monitors.
+    pub fn monitors(&self) -> &[MonitorInfo] {
+        self.monitors
+    }
+
+    /// Measures the size of the text when rendered with the given font families and size, using the same text
+    /// shaper as the renderer.
+    ///
+    /// If `max_width` is given the text is wrapped to fit within it. Returns the width, height and number of lines
+    /// of the text. Sizes are in logical pixels and account for the scale factor of the display. If `font` is empty,
+    /// the default sans-serif font is used.
+    pub fn measure_text(
+        &self,
+        text: &str,
+        font: &[FamilyOwned],
+        size: f32,
+        max_width: Option<f32>,
+    ) -> (f32, f32, usize) {
+        let scale = self.scale_factor();
+        let (width, height, line_count) = self.text_context.measure_text(
+            text,
+            font,
+            size * scale,
+            max_width.map(|max_width| max_width * scale),
+        );
+
+        (width / scale, height / scale, line_count)
+    }
+
+    /// Returns the delays used when showing and hiding tooltips.
+    pub fn tooltip_config(&self) -> TooltipConfig {
+        *self.tooltip_config
+    }
+
+    /// Returns the monitor which the window containing the current view is on, if known.
+    pub fn current_monitor(&self) -> Option<&MonitorInfo> {
+        self.windows
+            .get(&self.current_window())
+            .and_then(|window_state| window_state.monitor.as_ref())
+    }
+
     /// Converts logical points to physical pixels.
     pub fn logical_to_physical(&self, logical: f32) -> f32 {
         self.style.logical_to_physical(logical)
@@ -437,18 +665,63 @@ impl<'a> EventContext<'a> {
         }
     }
 
+    /// Returns the translation of a key, or a [`Localized`] with arguments, for the current language.
+    ///
+    /// Falls back to the key if there is no translation for it.
+    pub fn translate(&self, text: impl Into<Localized>) -> String {
+        text.into().to_string_local(self)
+    }
+
     /// Capture mouse input for the current view.
+    ///
+    /// While captured, mouse move, button, and click events are sent directly to the current view, regardless of
+    /// which view is under the cursor, until [`release`](Self::release) is called. This allows a drag to continue
+    /// when the cursor leaves the bounds of the view, or the window while a mouse button is held down on platforms
+    /// which keep reporting cursor movement outside the window. Scroll events are still sent to the hovered view.
+    ///
+    /// Capturing does not affect hover state, so the capturing view still receives [`WindowEvent::MouseLeave`]
+    /// and [`WindowEvent::MouseOut`] when the cursor leaves it, followed by further mouse move events.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// # let mut cx = EventContext::new(cx);
+    /// # let window_event = WindowEvent::MouseDown(MouseButton::Left);
+    /// match window_event {
+    ///     WindowEvent::MouseDown(MouseButton::Left) => cx.capture(),
+    ///     WindowEvent::MouseUp(MouseButton::Left) => cx.release(),
+    ///     _ => {}
+    /// }
+    /// ```
     pub fn capture(&mut self) {
         *self.captured = self.current;
     }
 
     /// Release mouse input capture for the current view.
+    ///
+    /// Has no effect if the current view has not captured mouse input.
     pub fn release(&mut self) {
         if self.current == *self.captured {
             *self.captured = Entity::null();
         }
     }
 
+    /// Returns true if the current view has captured mouse input.
+    pub fn is_captured(&self) -> bool {
+        self.current == *self.captured
+    }
+
+    /// Returns true if the cursor has moved further than the drag threshold since the left mouse button was last
+    /// pressed, meaning the press should be treated as a drag rather than a click.
+    ///
+    /// This remains true until the left mouse button is next pressed, so it can be checked when handling
+    /// [`WindowEvent::MouseUp`] to distinguish the end of a drag from a click. The threshold is set with
+    /// [`Context::set_drag_threshold`].
+    pub fn drag_threshold_exceeded(&self) -> bool {
+        *self.drag_threshold_exceeded
+    }
+
     /// Enables or disables PseudoClassFlags for the focus of an entity
     fn set_focus_pseudo_classes(&mut self, focused: Entity, enabled: bool, focus_visible: bool) {
         if let Some(pseudo_classes) = self.style.pseudo_classes.get_mut(focused) {
@@ -495,19 +768,38 @@ impl<'a> EventContext<'a> {
         self.focus_with_visibility(old_focus_visible)
     }
 
+    /// Sets application focus to the given view using the previous focus visibility.
+    pub fn focus_entity(&mut self, entity: Entity) {
+        self.with_current(entity, |cx| cx.focus());
+    }
+
+    /// Brings the window containing the current view to the front and gives it keyboard focus.
+    ///
+    /// The window receives a [`WindowEvent::WindowFocused`] event once it has been focused. This may have no
+    /// effect on platforms which prevent applications from stealing focus.
+    pub fn focus_window(&mut self) {
+        let window_entity = self.current_window();
+        self.emit_to(window_entity, WindowEvent::FocusWindow);
+    }
+
+    /// Returns true if a window of the application has keyboard focus.
+    pub fn is_window_focused(&self) -> bool {
+        *self.window_has_focus
+    }
+
     /// Moves the keyboard focus to the next navigable view.
     pub fn focus_next(&mut self) {
-        let lock_focus_to = self.tree.lock_focus_within(*self.focused);
-        let next_focused = if let Some(next_focused) =
-            focus_forward(self.tree, self.style, *self.focused, lock_focus_to)
-        {
-            next_focused
-        } else {
-            TreeIterator::full(self.tree)
-                .find(|node| is_navigatable(self.tree, self.style, *node, lock_focus_to))
-                .unwrap_or(Entity::root())
-        };
+        let next_focused = next_focus(self.tree, self.style, *self.focused, true);
+        self.move_focus(next_focused);
+    }
 
+    /// Moves the keyboard focus to the previous navigable view.
+    pub fn focus_prev(&mut self) {
+        let prev_focused = next_focus(self.tree, self.style, *self.focused, false);
+        self.move_focus(prev_focused);
+    }
+
+    fn move_focus(&mut self, next_focused: Entity) {
         if next_focused != *self.focused {
             self.event_queue.push_back(
                 Event::new(WindowEvent::FocusOut).target(*self.focused).origin(Entity::root()),
@@ -524,33 +816,13 @@ impl<'a> EventContext<'a> {
         }
     }
 
-    pub fn focus_prev(&mut self) {
-        let lock_focus_to = self.tree.lock_focus_within(*self.focused);
-        let prev_focused = if let Some(prev_focused) =
-            focus_backward(self.tree, self.style, *self.focused, lock_focus_to)
-        {
-            prev_focused
-        } else {
-            TreeIterator::full(self.tree)
-                .filter(|node| is_navigatable(self.tree, self.style, *node, lock_focus_to))
-                .next_back()
-                .unwrap_or(Entity::root())
-        };
-
-        if prev_focused != *self.focused {
-            self.event_queue.push_back(
-                Event::new(WindowEvent::FocusOut).target(*self.focused).origin(Entity::root()),
-            );
-            self.event_queue.push_back(
-                Event::new(WindowEvent::FocusIn).target(prev_focused).origin(Entity::root()),
-            );
-
-            if let Some(pseudo_classes) = self.style.pseudo_classes.get_mut(*self.triggered) {
-                pseudo_classes.set(PseudoClassFlags::ACTIVE, false);
-            }
-            self.needs_restyle();
-            *self.triggered = Entity::null();
-        }
+    /// Sets the position of a view in the keyboard focus order, overriding the tree order.
+    ///
+    /// Views with a positive focus order are visited first with `tab`, in ascending order, followed by views with
+    /// a focus order of zero or no focus order in tree order. Views with a negative focus order can still be
+    /// focused, such as by clicking them, but are skipped by keyboard navigation.
+    pub fn set_focus_order(&mut self, entity: Entity, order: i32) {
+        self.style.focus_order.insert(entity, order);
     }
 
     /// Returns the currently hovered view.
@@ -563,6 +835,11 @@ impl<'a> EventContext<'a> {
         *self.focused
     }
 
+    /// Returns true if the given view is the ancestor view or one of its descendants.
+    pub fn is_descendant_of(&self, entity: Entity, ancestor: Entity) -> bool {
+        entity.is_descendant_of(self.tree, ancestor)
+    }
+
     // PseudoClass Getters
 
     /// Returns true if the current view is being hovered.
@@ -639,11 +916,267 @@ impl<'a> EventContext<'a> {
         self.emit(WindowEvent::SetCursor(cursor));
     }
 
+    /// Sets how the mouse cursor is grabbed by the window containing the current view.
+    ///
+    /// While the cursor is grabbed, the hovered view, or the view which has captured mouse input, receives
+    /// [`WindowEvent::RawMouseMotion`] events with the relative motion of the mouse. Releasing the grab with
+    /// [`CursorGrabMode::None`] restores the visibility of the cursor. If the platform does not support the
+    /// requested mode, the other grab mode is used instead.
+    pub fn set_cursor_grab(&mut self, mode: CursorGrabMode) {
+        let window_entity = self.current_window();
+        self.emit_to(window_entity, WindowEvent::SetCursorGrab(mode));
+    }
+
+    /// Sets whether the mouse cursor is visible within the window containing the current view.
+    pub fn set_cursor_visible(&mut self, visible: bool) {
+        let window_entity = self.current_window();
+        self.emit_to(window_entity, WindowEvent::SetCursorVisible(visible));
+    }
+
+    /// Sets the mouse cursor icon of the window, unless the cursor icon is locked.
+    ///
+    /// The icon is reset to the `cursor` style of the hovered view when the hovered view changes. To show an icon
+    /// whenever a view is hovered, such as a resize cursor over a resize handle, use the `cursor` style property instead.
+    pub fn set_cursor_icon(&mut self, cursor: CursorIcon) {
+        if !*self.cursor_icon_locked {
+            self.emit(WindowEvent::SetCursor(cursor));
+        }
+    }
+
+    /// Removes the given entity and its descendants from the tree.
+    ///
+    /// The removal is deferred until the current event has been handled. See [`Context::remove`] for details.
+    pub fn remove(&mut self, entity: Entity) {
+        self.event_queue.push_back(
+            Event::new(InternalEvent::Remove(entity)).target(entity).origin(self.current),
+        );
+    }
+
     /// Returns true if the cursor icon is locked.
     pub fn is_cursor_icon_locked(&self) -> bool {
         *self.cursor_icon_locked
     }
 
+    /// Toggles the layout inspector overlay, which highlights the hovered view and shows its bounds and computed style.
+    ///
+    /// While the inspector is shown, clicking on a view pins it so that it remains inspected when the mouse moves away.
+    pub fn toggle_inspector(&mut self) {
+        self.inspector.enabled = !self.inspector.enabled;
+        self.inspector.pinned = None;
+
+        for (window_entity, window_state) in self.windows.iter_mut() {
+            window_state.redraw_list.insert(*window_entity);
+        }
+    }
+
+    /// Hides the cursor of the window containing the current view after the mouse has not moved for the given delay.
+    ///
+    /// The cursor is shown again as soon as the mouse moves. Pass `None` to disable auto-hiding, which also shows the
+    /// cursor if it is currently hidden.
+    pub fn auto_hide_cursor(&mut self, delay: Option<Duration>) {
+        let window_entity = self.current_window();
+
+        let Some(window_state) = self.windows.get_mut(&window_entity) else {
+            return;
+        };
+
+        let previous_timer = window_state.cursor_auto_hide_timer.take();
+        let was_hidden = std::mem::take(&mut window_state.cursor_hidden);
+
+        if let Some(previous_timer) = previous_timer {
+            self.stop_timer(previous_timer);
+        }
+
+        if was_hidden {
+            self.emit_to(window_entity, WindowEvent::SetCursorVisible(true));
+        }
+
+        if let Some(delay) = delay {
+            let timer =
+                self.add_timer(delay, Some(delay), cursor_auto_hide_callback(window_entity));
+
+            if let Some(window_state) = self.windows.get_mut(&window_entity) {
+                window_state.cursor_auto_hide_timer = Some(timer);
+            }
+
+            self.with_current(window_entity, |cx| cx.start_timer(timer));
+        }
+    }
+
+    /// Prevents the window containing the current view from closing in response to a
+    /// [`WindowEvent::WindowCloseRequested`] event, such as to ask the user to save any unsaved changes first.
+    ///
+    /// This only has an effect while handling the close request. The window can be closed afterwards by emitting
+    /// [`WindowEvent::WindowClose`].
+    pub fn prevent_close(&mut self) {
+        let window_entity = self.current_window();
+        if let Some(window_state) = self.windows.get_mut(&window_entity) {
+            window_state.close_prevented = true;
+        }
+    }
+
+    /// Sets whether the window containing the current view is shown in a window or fullscreen.
+    ///
+    /// The window receives a [`WindowEvent::FullscreenChanged`] event once the change has been applied.
+    pub fn set_fullscreen(&mut self, mode: FullscreenMode) {
+        let window_entity = self.current_window();
+        self.emit_to(window_entity, WindowEvent::SetFullscreen(mode));
+    }
+
+    /// Sets how rendered frames are presented to the display by the window containing the current view.
+    ///
+    /// The present mode is changed without recreating the window surface.
+    pub fn set_present_mode(&mut self, present_mode: PresentMode) {
+        let window_entity = self.current_window();
+        self.emit_to(window_entity, WindowEvent::SetPresentMode(present_mode));
+    }
+
+    /// Requests the attention of the user for the window containing the current view, such as by flashing its
+    /// taskbar entry, or cancels a previous request if `urgency` is `None`.
+    ///
+    /// The request is cancelled automatically once the window is focused. This has no effect if the window is
+    /// already focused, or on backends and platforms which don't support it.
+    pub fn request_user_attention(&mut self, urgency: Option<UrgencyLevel>) {
+        let window_entity = self.current_window();
+        self.emit_to(window_entity, WindowEvent::RequestUserAttention(urgency));
+    }
+
+    /// Begins moving the window containing the current view with the mouse, as if its title bar were dragged.
+    ///
+    /// This allows a view to act as a custom title bar for a window without decorations, and should be called
+    /// in response to a [`WindowEvent::MouseDown`] event while the left mouse button is pressed. To let buttons
+    /// within the title bar receive clicks, don't begin a drag when the hovered view is within one of the buttons.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// let mut close_button = Entity::null();
+    /// HStack::new(cx, |cx| {
+    ///     Label::new(cx, "Title");
+    ///     close_button = Button::new(cx, |cx| Label::new(cx, "Close"))
+    ///         .on_press(|cx| cx.emit(WindowEvent::WindowClose))
+    ///         .entity();
+    /// })
+    /// .class("title-bar")
+    /// .on_mouse_down(move |cx, button| {
+    ///     if button == MouseButton::Left && !cx.is_descendant_of(cx.hovered(), close_button) {
+    ///         cx.drag_window();
+    ///     }
+    /// });
+    /// ```
+    pub fn drag_window(&mut self) {
+        let window_entity = self.current_window();
+        self.emit_to(window_entity, WindowEvent::DragWindow);
+    }
+
+    /// Begins resizing the window containing the current view with the mouse from the given edge or corner.
+    ///
+    /// Like [`drag_window`](Self::drag_window), this should be called in response to a [`WindowEvent::MouseDown`]
+    /// event, such as on a view placed along the edge of a window without decorations.
+    pub fn drag_resize_window(&mut self, direction: ResizeDirection) {
+        let window_entity = self.current_window();
+        self.emit_to(window_entity, WindowEvent::DragResizeWindow(direction));
+    }
+
+    /// Sets the scale factor applied on top of any DPI scaling for the window containing the current view, such as
+    /// to implement a UI zoom setting.
+    ///
+    /// The window is resized to keep the same logical size. This is currently only supported by the baseview
+    /// backend. Factors which are not positive and finite are ignored.
+    pub fn set_user_scale_factor(&mut self, factor: f64) {
+        // The window size is divided by the scale factor, so a zero, negative or NaN factor would break layout.
+        if !(factor.is_finite() && factor > 0.0) {
+            return;
+        }
+
+        let window_entity = self.current_window();
+        if let Some(window_state) = self.windows.get_mut(&window_entity) {
+            window_state.window_description.user_scale_factor = factor;
+        }
+    }
+
+    /// Sets the icon of the window containing the current view from its width, height and RGBA pixel data.
+    ///
+    /// This has no effect on macOS, where the application bundle icon is used instead, or on backends which
+    /// don't support window icons.
+    pub fn set_window_icon(&mut self, width: u32, height: u32, image: Vec<u8>) {
+        let window_entity = self.current_window();
+        if let Some(window_state) = self.windows.get_mut(&window_entity) {
+            window_state.window_description.icon = Some(image.clone());
+            window_state.window_description.icon_width = width;
+            window_state.window_description.icon_height = height;
+        }
+
+        self.emit_to(window_entity, WindowEvent::SetIcon(width, height, image));
+    }
+
+    /// Sets the opacity of the contents of the window containing the current view, from 0.0 to 1.0.
+    ///
+    /// This only has an effect if the window was created with a transparent background, such as with
+    /// [`WindowDescription::with_transparent`](vizia_window::WindowDescription::with_transparent).
+    pub fn set_window_opacity(&mut self, opacity: f32) {
+        let window_entity = self.current_window();
+        if let Some(window_state) = self.windows.get_mut(&window_entity) {
+            window_state.window_description.opacity = opacity.clamp(0.0, 1.0);
+            window_state.redraw_list.insert(window_entity);
+        }
+    }
+
+    /// Sets whether the background behind the window containing the current view is blurred.
+    ///
+    /// This only has an effect if the window has a transparent background, and is not supported on all
+    /// backends and platforms.
+    pub fn set_window_blur(&mut self, flag: bool) {
+        let window_entity = self.current_window();
+        self.emit_to(window_entity, WindowEvent::SetBlur(flag));
+    }
+
+    /// Dismisses the most recently presented modal of the window containing the current view, returning focus
+    /// to the view which was focused before the modal was presented.
+    ///
+    /// See [`Context::present_modal`] for details.
+    pub fn dismiss_modal(&mut self) {
+        let window_entity = self.current_window();
+
+        if let Some(modal) =
+            self.windows.get_mut(&window_entity).and_then(|window_state| window_state.modals.pop())
+        {
+            self.remove(modal);
+        }
+    }
+
+    /// Dismisses the given modal, as returned by [`Context::present_modal`], even if other modals have been
+    /// presented above it.
+    pub fn dismiss_modal_entity(&mut self, modal: Entity) {
+        let window_entity = self.tree.get_parent_window(modal).unwrap_or(Entity::root());
+
+        if let Some(window_state) = self.windows.get_mut(&window_entity) {
+            if window_state.modals.contains(&modal) {
+                window_state.modals.retain(|entity| *entity != modal);
+                self.remove(modal);
+            }
+        }
+    }
+
+    /// Requests a capture of the next frame drawn to the window containing the current view.
+    ///
+    /// Once the frame has been drawn, the current view receives a [`WindowEvent::FrameCaptured`] event containing
+    /// the pixels of the frame.
+    pub fn capture_frame(&mut self) {
+        let window_entity = self.current_window();
+        let current = self.current;
+
+        if let Some(window_state) = self.windows.get_mut(&window_entity) {
+            if !window_state.frame_capture_requests.contains(&current) {
+                window_state.frame_capture_requests.push(current);
+            }
+
+            // Redraw the window so that a frame is drawn even if nothing has changed.
+            window_state.redraw_list.insert(window_entity);
+        }
+    }
+
     pub fn set_drop_data(&mut self, data: impl Into<DropData>) {
         *self.drop_data = Some(data.into())
     }
@@ -693,11 +1226,39 @@ impl<'a> EventContext<'a> {
         self.needs_restyle();
     }
 
+    /// Sends an [`UndoEvent::Undo`] event up the tree from the current view, to be handled by a model with an
+    /// [`UndoHistory`].
+    pub fn undo(&mut self) {
+        self.emit(UndoEvent::Undo);
+    }
+
+    /// Sends an [`UndoEvent::Redo`] event up the tree from the current view, to be handled by a model with an
+    /// [`UndoHistory`].
+    pub fn redo(&mut self) {
+        self.emit(UndoEvent::Redo);
+    }
+
     /// Returns a reference to the [Environment] model.
     pub fn environment(&self) -> &Environment {
         self.data::<Environment>().unwrap()
     }
 
+    /// Returns the [theme mode](ThemeMode) reported by the system, or `None` if the backend is unable to
+    /// query it.
+    pub fn system_theme(&self) -> Option<ThemeMode> {
+        self.environment().theme.sys_theme
+    }
+
+    /// Returns true if the system requests that motion from animations be reduced.
+    pub fn prefers_reduced_motion(&self) -> bool {
+        self.environment().reduced_motion
+    }
+
+    /// Returns true if the system requests a high contrast presentation.
+    pub fn prefers_high_contrast(&self) -> bool {
+        self.environment().high_contrast
+    }
+
     /// Sets the current [theme mode](ThemeMode).
     pub fn set_theme_mode(&mut self, theme_mode: ThemeMode) {
         if !self.ignore_default_theme {
@@ -748,6 +1309,18 @@ impl<'a> EventContext<'a> {
             return Ok(());
         }
 
+        // Re-read any watched stylesheets which have changed, collecting errors to report once the styles have been
+        // reloaded.
+        #[cfg(feature = "hot-reload")]
+        let errors = self
+            .resource_manager
+            .watched_stylesheets
+            .iter()
+            .filter_map(|stylesheet| {
+                stylesheet.refresh().err().map(|error| (stylesheet.on_error.clone(), error))
+            })
+            .collect::<Vec<_>>();
+
         self.style.remove_rules();
 
         self.style.clear_style_rules();
@@ -773,6 +1346,11 @@ impl<'a> EventContext<'a> {
             self.style.needs_text_update(entity);
         }
 
+        #[cfg(feature = "hot-reload")]
+        for (on_error, error) in errors {
+            (on_error)(self, error);
+        }
+
         Ok(())
     }
 
@@ -789,6 +1367,33 @@ impl<'a> EventContext<'a> {
         std::thread::spawn(move || target(&mut cxp));
     }
 
+    /// Spawns a task which runs the future returned by `task` on a worker thread, providing a [ContextProxy] for
+    /// sending events back to the main UI thread.
+    ///
+    /// The task is cancelled if the returned [TaskHandle] is used to cancel it, or when the current view is removed,
+    /// for example because its window was closed.
+    ///
+    /// # Example
+    /// ```ignore
+    /// cx.spawn_task(|mut cx| async move {
+    ///     let contents = load_file("data.json").await;
+    ///     cx.emit(AppEvent::Loaded(contents)).expect("Failed to send event");
+    /// });
+    /// ```
+    pub fn spawn_task<F, Fut>(&mut self, task: F) -> TaskHandle
+    where
+        F: 'static + Send + FnOnce(ContextProxy) -> Fut,
+        Fut: Future<Output = ()>,
+    {
+        let handle = spawn_task(self.get_proxy(), task);
+
+        let tasks = self.tasks.entry(self.current).or_default();
+        tasks.retain(|task| !task.is_finished());
+        tasks.push(handle.clone());
+
+        handle
+    }
+
     /// Returns a [ContextProxy] which can be moved between threads and used to send events back to the main UI thread.
     pub fn get_proxy(&self) -> ContextProxy {
         ContextProxy {
@@ -1131,7 +1736,48 @@ impl<'a> EventContext<'a> {
         self.style.pointer_events.insert(self.current, pointer_events.into());
     }
 
+    /// Sets a style property inline on the given view, overriding the value of the property from any stylesheets.
+    pub fn set_style_property(&mut self, entity: Entity, property: Property) {
+        self.style.insert_inline_property(entity, property);
+        self.style.system_flags |= SystemFlags::RELAYOUT | SystemFlags::REFLOW;
+        self.with_current(entity, |cx| {
+            cx.needs_restyle();
+            cx.needs_redraw();
+        });
+        self.style.needs_text_update(entity);
+    }
+
+    /// Removes a style property set inline on the given view, so that the value from any stylesheets is used
+    /// again. The property is given by its CSS name, such as `background-color`, and unknown names are ignored.
+    pub fn remove_style_property(&mut self, entity: Entity, name: &str) {
+        if !self.style.remove_inline_property(entity, name) {
+            return;
+        }
+
+        self.style.system_flags |= SystemFlags::RELAYOUT | SystemFlags::REFLOW;
+        self.with_current(entity, |cx| {
+            cx.needs_restyle();
+            cx.needs_redraw();
+        });
+        self.style.needs_text_update(entity);
+    }
+
+    /// Sets the value of a CSS custom property, such as `--accent`, overriding any value declared in stylesheets,
+    /// and reloads the styles of the application.
+    ///
+    /// Custom properties are global to the application and may only be declared in `:root` rules.
+    pub fn set_style_variable(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        self.style.style_variables.insert(name.into(), value.into());
+        self.reload_styles().ok();
+    }
+
     // GETTERS
+
+    /// Returns the computed style of the given view.
+    pub fn computed_style(&self, entity: Entity) -> ComputedStyle<'_> {
+        ComputedStyle::new(self.style, entity)
+    }
+
     get_length_property!(
         /// Returns the border width of the current view in physical pixels.
         border_width
@@ -1180,8 +1826,17 @@ impl<'a> EventContext<'a> {
         duration: Option<Duration>,
         callback: impl Fn(&mut EventContext, TimerAction) + 'static,
     ) -> Timer {
-        let id = Timer(self.timers.len());
-        self.timers.push(TimerState {
+        // Reuse the slot of a removed timer so that the ids of other timers are unchanged.
+        let index = self
+            .timers
+            .iter()
+            .position(|timer_state| timer_state.removed)
+            .unwrap_or(self.timers.len());
+        // Handles to the removed timer keep the previous generation, so they don't refer to the new timer.
+        let generation =
+            self.timers.get(index).map_or(0, |timer_state| timer_state.id.1.wrapping_add(1));
+        let id = Timer(index, generation);
+        let timer_state = TimerState {
             entity: Entity::root(),
             id,
             time: Instant::now(),
@@ -1191,16 +1846,108 @@ impl<'a> EventContext<'a> {
             callback: Rc::new(callback),
             ticking: false,
             stopping: false,
+            remove_on_stop: false,
+            removed: false,
+        };
+
+        if index < self.timers.len() {
+            self.timers[index] = timer_state;
+        } else {
+            self.timers.push(timer_state);
+        }
+
+        id
+    }
+
+    /// Adds a hotkey which calls `callback` when the key chord is pressed while keyboard focus is within the current
+    /// view.
+    ///
+    /// Hotkeys added to the root view, such as within the content closure of the application, are global. If several
+    /// views containing the focused view have a hotkey for the same key chord, only those of the innermost view are
+    /// called. A key chord without a control, alt or super modifier does not trigger hotkeys while a textbox has
+    /// focus, so that it can be typed. The key press is not sent to the focused view when a hotkey is triggered.
+    ///
+    /// The key chord can be a [`KeyChord`], which matches the physical key, or a [`HotkeyChord::Key`], which matches the
+    /// logical key produced by the keyboard layout. Key repeats while the key chord is held down do not trigger the
+    /// hotkey, unless enabled with [`set_hotkey_repeat`](Self::set_hotkey_repeat).
+    ///
+    /// Returns a [`Hotkey`] which can be used to remove the hotkey with [`remove_hotkey`](Self::remove_hotkey).
+    /// Hotkeys are also removed when the view which added them is removed.
+    ///
+    /// # Example
+    /// ```ignore
+    /// cx.add_hotkey(KeyChord::new(Modifiers::CTRL, Code::KeyS), |cx| cx.emit(AppEvent::Save));
+    /// cx.add_hotkey(HotkeyChord::Key(Modifiers::CTRL, Key::Character("+".into())), |cx| cx.emit(AppEvent::ZoomIn));
+    /// ```
+    pub fn add_hotkey(
+        &mut self,
+        chord: impl Into<HotkeyChord>,
+        callback: impl Fn(&mut EventContext) + 'static,
+    ) -> Hotkey {
+        let id = Hotkey(*self.next_hotkey_id);
+        *self.next_hotkey_id += 1;
+        self.hotkeys.push(HotkeyEntry {
+            id,
+            chord: chord.into(),
+            repeat: false,
+            scope: self.current,
+            callback: Rc::new(callback),
         });
 
         id
     }
 
+    /// Removes the hotkey with the given id.
+    pub fn remove_hotkey(&mut self, hotkey: Hotkey) {
+        self.hotkeys.retain(|entry| entry.id != hotkey);
+    }
+
+    /// Sets whether the hotkey with the given id is also triggered by key repeats while its key chord is held down.
+    pub fn set_hotkey_repeat(&mut self, hotkey: Hotkey, repeat: bool) {
+        if let Some(entry) = self.hotkeys.iter_mut().find(|entry| entry.id == hotkey) {
+            entry.repeat = repeat;
+        }
+    }
+
+    /// Adds and starts a timer which calls `callback` every `interval`, until it is stopped with
+    /// [`stop_timer`](Self::stop_timer). The timer is removed once it has been stopped, so it can't be restarted.
+    ///
+    /// This is shorthand for [`add_timer`](Self::add_timer) followed by [`start_timer`](Self::start_timer), for timers
+    /// which only respond to ticks. Events sent within the callback target the current view.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let timer = cx.start_interval(Duration::from_millis(500), |cx| cx.emit(AppEvent::Poll));
+    /// ```
+    pub fn start_interval(
+        &mut self,
+        interval: Duration,
+        callback: impl Fn(&mut EventContext) + 'static,
+    ) -> Timer {
+        let timer = self.add_timer(interval, None, move |cx, action| {
+            if let TimerAction::Tick(_) = action {
+                (callback)(cx);
+            }
+        });
+        self.timers[timer.0].remove_on_stop = true;
+        self.start_timer(timer);
+
+        timer
+    }
+
     /// Starts a timer with the provided timer id.
     ///
     /// Events sent within the timer callback provided in `add_timer()` will target the current view.
     pub fn start_timer(&mut self, timer: Timer) {
         let current = self.current;
+        if self
+            .timers
+            .get(timer.0)
+            .map_or(true, |timer_state| timer_state.removed || timer_state.id != timer)
+        {
+            return;
+        }
+
         if !self.timer_is_running(timer) {
             let timer_state = self.timers[timer.0].clone();
             // Copy timer state from pending to playing
@@ -1219,16 +1966,18 @@ impl<'a> EventContext<'a> {
 
     /// Modifies the state of an existing timer with the provided `Timer` id.
     pub fn modify_timer(&mut self, timer: Timer, timer_function: impl Fn(&mut TimerState)) {
-        while let Some(next_timer_state) = self.running_timers.peek() {
-            if next_timer_state.id == timer {
-                let mut timer_state = self.running_timers.pop().unwrap();
-
-                (timer_function)(&mut timer_state);
+        let mut running_timers = std::mem::take(self.running_timers).into_vec();
+        let mut is_running = false;
+        for timer_state in running_timers.iter_mut().filter(|timer_state| timer_state.id == timer) {
+            (timer_function)(timer_state);
+            is_running = true;
+        }
 
-                self.running_timers.push(timer_state);
+        // Rebuild the heap, as the next tick time of the timer may have changed.
+        *self.running_timers = running_timers.into();
 
-                return;
-            }
+        if is_running {
+            return;
         }
 
         for pending_timer in self.timers.iter_mut() {
@@ -1243,20 +1992,14 @@ impl<'a> EventContext<'a> {
         timer: Timer,
         timer_function: impl Fn(&TimerState) -> T,
     ) -> Option<T> {
-        while let Some(next_timer_state) = self.running_timers.peek() {
-            if next_timer_state.id == timer {
-                let timer_state = self.running_timers.pop().unwrap();
-
-                let t = (timer_function)(&timer_state);
-
-                self.running_timers.push(timer_state);
-
-                return Some(t);
-            }
+        if let Some(timer_state) =
+            self.running_timers.iter().find(|timer_state| timer_state.id == timer)
+        {
+            return Some((timer_function)(timer_state));
         }
 
         for pending_timer in self.timers.iter() {
-            if pending_timer.id == timer {
+            if pending_timer.id == timer && !pending_timer.removed {
                 return Some(timer_function(pending_timer));
             }
         }
@@ -1291,6 +2034,135 @@ impl<'a> EventContext<'a> {
 
         *self.running_timers =
             running_timers.drain().filter(|timer_state| timer_state.id != timer).collect();
+
+        // Timers added with `start_interval` are removed once stopped, dropping their callback.
+        if let Some(timer_state) =
+            self.timers.get_mut(timer.0).filter(|timer_state| timer_state.id == timer)
+        {
+            if timer_state.remove_on_stop {
+                timer_state.remove_on_stop = false;
+                timer_state.removed = true;
+                timer_state.callback = Rc::new(|_, _| {});
+            }
+        }
+    }
+
+    /// Plays a [`Spring`] on the current view, returning a handle which can be used to modify or stop it.
+    ///
+    /// The spring is advanced by the time elapsed between frames, and `callback` is called with the new value of
+    /// the spring each frame until it settles at its target, at which point the spring is removed. Events sent
+    /// within the callback will target the current view.
+    pub fn play_spring(
+        &mut self,
+        spring: Spring,
+        callback: impl Fn(&mut EventContext, SpringAction) + 'static,
+    ) -> SpringHandle {
+        let id = SpringHandle(*self.next_spring_id);
+        *self.next_spring_id += 1;
+        self.springs.push(SpringState {
+            entity: self.current,
+            id,
+            spring,
+            time: Instant::now(),
+            callback: Rc::new(callback),
+        });
+
+        id
+    }
+
+    /// Modifies a playing spring, such as to change its target. Does nothing if the spring has settled or has
+    /// been stopped.
+    pub fn modify_spring(
+        &mut self,
+        spring: SpringHandle,
+        spring_function: impl FnOnce(&mut Spring),
+    ) {
+        if let Some(spring_state) =
+            self.springs.iter_mut().find(|spring_state| spring_state.id == spring)
+        {
+            (spring_function)(&mut spring_state.spring);
+        }
+    }
+
+    /// Returns true if the spring with the given handle is still playing.
+    pub fn spring_is_playing(&self, spring: SpringHandle) -> bool {
+        self.springs.iter().any(|spring_state| spring_state.id == spring)
+    }
+
+    /// Stops a playing spring at its current value without calling its callback.
+    pub fn stop_spring(&mut self, spring: SpringHandle) {
+        self.springs.retain(|spring_state| spring_state.id != spring);
+    }
+
+    /// Send an event containing the provided message up the tree once `delay` has passed without another message
+    /// of the same type being sent this way from the current view.
+    ///
+    /// Each call replaces the pending message, so only the last message of a burst is sent. This is useful for
+    /// responding to input which changes rapidly, such as searching while the user types.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// # let mut cx = EventContext::new(cx);
+    /// # enum AppEvent {Search(String)}
+    /// cx.emit_debounced(AppEvent::Search(String::from("viz")), Duration::from_millis(300));
+    /// ```
+    pub fn emit_debounced<M: Any + Send>(&mut self, message: M, delay: Duration) {
+        let key = (self.current, TypeId::of::<M>());
+        if let Some(handle) = self.debounced_events.remove(&key) {
+            self.cancel_scheduled(handle);
+        }
+
+        let handle = self.schedule_emit(message, Instant::now() + delay);
+        self.debounced_events.insert(key, handle);
+    }
+
+    /// Send an event containing the provided message up the tree at most once per `interval` for messages of the
+    /// same type sent this way from the current view.
+    ///
+    /// A message sent within the interval of the previous one is held until the interval ends, replacing any message
+    /// already being held, so the last message of a burst is always sent.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// # let mut cx = EventContext::new(cx);
+    /// # enum AppEvent {SetProgress(f32)}
+    /// cx.emit_throttled(AppEvent::SetProgress(0.5), Duration::from_millis(100));
+    /// ```
+    pub fn emit_throttled<M: Any + Send>(&mut self, message: M, interval: Duration) {
+        let key = (self.current, TypeId::of::<M>());
+        let now = Instant::now();
+        let state = self
+            .throttled_events
+            .get(&key)
+            .copied()
+            .unwrap_or(ThrottleState { next: now, pending: None });
+
+        // Replace a held message which has not been sent yet.
+        if let Some((handle, at)) = state.pending {
+            if self.event_schedule.iter().any(|timed_event| timed_event.ident == handle) {
+                self.cancel_scheduled(handle);
+                let handle = self.schedule_emit(message, at);
+                self.throttled_events
+                    .insert(key, ThrottleState { pending: Some((handle, at)), ..state });
+                return;
+            }
+        }
+
+        if now >= state.next {
+            self.emit(message);
+            self.throttled_events
+                .insert(key, ThrottleState { next: now + interval, pending: None });
+        } else {
+            let handle = self.schedule_emit(message, state.next);
+            self.throttled_events.insert(
+                key,
+                ThrottleState { next: state.next + interval, pending: Some((handle, state.next)) },
+            );
+        }
     }
 }
 
@@ -7,8 +7,10 @@ mod draw;
 mod event;
 mod proxy;
 mod resource;
+mod task;
 
 use log::debug;
+use raw_window_handle::{RawDisplayHandle, RawWindowHandle};
 use skia_safe::{
     svg,
     textlayout::{FontCollection, TypefaceFontProvider},
@@ -17,12 +19,16 @@ use skia_safe::{
 use std::any::{Any, TypeId};
 use std::cell::RefCell;
 use std::collections::{BinaryHeap, VecDeque};
+use std::future::Future;
 use std::rc::Rc;
 use std::sync::Mutex;
 use vizia_id::IdManager;
-use vizia_window::{WindowDescription, WindowPosition};
+use vizia_window::{MonitorInfo, WindowDescription, WindowPosition};
 
-#[cfg(all(feature = "clipboard", feature = "x11"))]
+#[cfg(all(
+    feature = "clipboard",
+    any(feature = "x11", target_os = "windows", target_os = "macos")
+))]
 use copypasta::ClipboardContext;
 #[cfg(feature = "clipboard")]
 use copypasta::{nop_clipboard::NopClipboardContext, ClipboardProvider};
@@ -33,7 +39,9 @@ pub use draw::*;
 pub use event::*;
 pub use proxy::*;
 pub use resource::*;
+pub use task::*;
 
+use crate::animation::SpringState;
 use crate::events::{TimedEvent, TimedEventHandle, TimerState, ViewHandler};
 
 use crate::{
@@ -42,9 +50,11 @@ use crate::{
 };
 use crate::{cache::CachedData, resource::ImageOrSvg};
 
+use crate::input::HotkeyEntry;
 use crate::model::ModelDataStore;
 use crate::prelude::*;
 use crate::resource::ResourceManager;
+use crate::systems::Inspector;
 use crate::text::TextContext;
 use vizia_input::MouseState;
 use vizia_storage::{ChildIterator, LayoutTreeIterator};
@@ -54,9 +64,30 @@ static DARK_THEME: &str = include_str!("../../resources/themes/dark_theme.css");
 static LIGHT_THEME: &str = include_str!("../../resources/themes/light_theme.css");
 static MARKDOWN: &str = include_str!("../../resources/themes/markdown.css");
 
+// The time a bound window title must remain unchanged before the OS window title is updated.
+const WINDOW_TITLE_DEBOUNCE: Duration = Duration::from_millis(100);
+
+// How long to wait after a watched stylesheet changes before reloading it, so that a save is reloaded once.
+#[cfg(feature = "hot-reload")]
+const STYLESHEET_RELOAD_DELAY: Duration = Duration::from_millis(50);
+
+// The default maximum time between presses for them to count as a double or triple click.
+const DEFAULT_DOUBLE_CLICK_INTERVAL: Duration = Duration::from_millis(500);
+// The default maximum distance, in logical pixels, the cursor can move between presses of a double or triple click.
+const DEFAULT_DOUBLE_CLICK_DISTANCE: f32 = 4.0;
+
+// The default distance, in logical pixels, the cursor must move while the left mouse button is held down to start a drag.
+const DEFAULT_DRAG_THRESHOLD: f32 = 4.0;
+
+// The default time the left mouse button must be held down for a long press.
+const DEFAULT_LONG_PRESS_DURATION: Duration = Duration::from_millis(500);
+// The default maximum distance, in logical pixels, the cursor can move while held down for a long press.
+const DEFAULT_LONG_PRESS_DISTANCE: f32 = 8.0;
+
 type Views = HashMap<Entity, Box<dyn ViewHandler>>;
 type Models = HashMap<Entity, ModelDataStore>;
 type Bindings = HashMap<Entity, Box<dyn BindingHandler>>;
+type WindowData = HashMap<Entity, HashMap<TypeId, Box<dyn Any>>>;
 
 thread_local! {
     pub static MAP_MANAGER: RefCell<IdManager<MapId>> = RefCell::new(IdManager::new());
@@ -78,6 +109,66 @@ pub struct WindowState {
     pub is_modal: bool,
     pub should_close: bool,
     pub position: WindowPosition,
+    /// The timer used to hide the cursor after a period of inactivity, if enabled.
+    pub cursor_auto_hide_timer: Option<Timer>,
+    /// Whether the cursor is currently hidden due to inactivity.
+    pub cursor_hidden: bool,
+    /// The modals presented in the window, from bottom to top.
+    pub modals: Vec<Entity>,
+    /// The views which have requested a capture of the next frame drawn to the window.
+    pub frame_capture_requests: Vec<Entity>,
+    /// Whether a view prevented the window from closing while handling the latest close request.
+    pub close_prevented: bool,
+    /// The monitor the window is currently on, if known.
+    pub monitor: Option<MonitorInfo>,
+    /// How the mouse cursor is currently grabbed by the window.
+    pub cursor_grab: CursorGrabMode,
+    /// Whether the window is currently minimized.
+    pub minimized: bool,
+    /// Whether the window is currently maximized.
+    pub maximized: bool,
+    /// The raw handle of the platform window, if provided by the backend.
+    pub raw_window_handle: Option<RawWindowHandle>,
+    /// The raw handle of the display the platform window is on, if provided by the backend.
+    pub raw_display_handle: Option<RawDisplayHandle>,
+}
+
+/// The state of the events of one type emitted by a view with [`EventContext::emit_throttled`].
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ThrottleState {
+    // The time from which the next event can be sent immediately.
+    next: Instant,
+    // A trailing event waiting to be sent, and the time it is scheduled for.
+    pending: Option<(TimedEventHandle, Instant)>,
+}
+
+/// Returns the callback of the timer used to hide the cursor of a window after a period of inactivity.
+pub(crate) fn cursor_auto_hide_callback(
+    window_entity: Entity,
+) -> impl Fn(&mut EventContext, TimerAction) + 'static {
+    move |cx, action| {
+        if let TimerAction::Stop = action {
+            let Some(window_state) = cx.windows.get_mut(&window_entity) else {
+                return;
+            };
+
+            // The timer is also stopped when auto-hide is disabled or replaced, in which case the timer is cleared first.
+            if window_state.cursor_auto_hide_timer.is_some() && !window_state.cursor_hidden {
+                window_state.cursor_hidden = true;
+                cx.emit_to(window_entity, WindowEvent::SetCursorVisible(false));
+            }
+        }
+    }
+}
+
+fn long_press_callback(cx: &mut EventContext, action: TimerAction) {
+    if let TimerAction::Stop = action {
+        // The press origin is cleared first when the press is cancelled, i.e. released, moved too far, or the
+        // pressed view is removed, so only a timer which runs to completion emits a long press.
+        if let Some((x, y)) = cx.long_press_origin.take() {
+            cx.emit(WindowEvent::LongPress(x, y));
+        }
+    }
 }
 
 /// The main storage and control object for a Vizia application.
@@ -94,6 +185,8 @@ pub struct Context {
     pub(crate) next_event_id: usize,
     pub(crate) timers: Vec<TimerState>,
     pub(crate) running_timers: BinaryHeap<TimerState>,
+    pub(crate) springs: Vec<SpringState>,
+    pub(crate) next_spring_id: usize,
     pub(crate) tree_updates: Vec<Option<accesskit::TreeUpdate>>,
     pub(crate) listeners:
         HashMap<Entity, Box<dyn Fn(&mut dyn ViewHandler, &mut EventContext, &mut Event)>>,
@@ -101,6 +194,7 @@ pub struct Context {
     pub(crate) style: Style,
     pub(crate) cache: CachedData,
     pub windows: HashMap<Entity, WindowState>,
+    pub(crate) window_data: WindowData,
 
     pub mouse: MouseState<Entity>,
     pub(crate) modifiers: Modifiers,
@@ -111,6 +205,8 @@ pub struct Context {
     pub(crate) focused: Entity,
     pub(crate) focus_stack: Vec<Entity>,
     pub(crate) cursor_icon_locked: bool,
+    pub(crate) inspector: Inspector,
+    pub(crate) post_process: Option<PostProcessCallback>,
 
     pub(crate) resource_manager: ResourceManager,
 
@@ -125,11 +221,29 @@ pub struct Context {
     pub(crate) clicks: usize,
     pub(crate) click_pos: (f32, f32),
     pub(crate) click_button: MouseButton,
+    pub(crate) double_click_interval: Duration,
+    pub(crate) double_click_distance: f32,
+    pub(crate) drag_threshold: f32,
+    pub(crate) drag_threshold_exceeded: bool,
+    pub(crate) long_press_timer: Timer,
+    pub(crate) long_press_origin: Option<(f32, f32)>,
+    pub(crate) long_press_distance: f32,
+    // The view targeted by each active touch point.
+    pub(crate) touches: HashMap<u64, Entity>,
+    // The tasks spawned by each view, which are cancelled when it is removed.
+    pub(crate) tasks: HashMap<Entity, Vec<TaskHandle>>,
+    // The pending events sent with `emit_debounced` and `emit_throttled`, by view and message type.
+    pub(crate) debounced_events: HashMap<(Entity, TypeId), TimedEventHandle>,
+    pub(crate) throttled_events: HashMap<(Entity, TypeId), ThrottleState>,
+    pub(crate) hotkeys: Vec<HotkeyEntry>,
+    pub(crate) next_hotkey_id: usize,
 
     pub ignore_default_theme: bool,
     pub window_has_focus: bool,
 
     pub(crate) drop_data: Option<DropData>,
+    pub(crate) monitors: Vec<MonitorInfo>,
+    pub(crate) tooltip_config: TooltipConfig,
 }
 
 impl Default for Context {
@@ -155,11 +269,14 @@ impl Context {
             style: Style::default(),
             cache,
             windows: HashMap::new(),
+            window_data: HashMap::new(),
             event_queue: VecDeque::new(),
             event_schedule: BinaryHeap::new(),
             next_event_id: 0,
             timers: Vec::new(),
             running_timers: BinaryHeap::new(),
+            springs: Vec::new(),
+            next_spring_id: 0,
             tree_updates: Vec::new(),
             listeners: HashMap::default(),
             global_listeners: Vec::new(),
@@ -171,6 +288,8 @@ impl Context {
             focused: Entity::root(),
             focus_stack: Vec::new(),
             cursor_icon_locked: false,
+            inspector: Inspector::default(),
+            post_process: None,
             resource_manager: ResourceManager::new(),
             text_context: {
                 let mut font_collection = FontCollection::new();
@@ -196,24 +315,41 @@ impl Context {
 
             #[cfg(feature = "clipboard")]
             clipboard: {
-                #[cfg(feature = "x11")]
+                // The system clipboard is available without a window on Windows and macOS, so it also
+                // works in backends without an event loop of their own, such as baseview.
+                #[cfg(any(feature = "x11", target_os = "windows", target_os = "macos"))]
                 if let Ok(context) = ClipboardContext::new() {
                     Box::new(context)
                 } else {
                     Box::new(NopClipboardContext::new().unwrap())
                 }
-                #[cfg(not(feature = "x11"))]
+                #[cfg(not(any(feature = "x11", target_os = "windows", target_os = "macos")))]
                 Box::new(NopClipboardContext::new().unwrap())
             },
             click_time: Instant::now(),
             clicks: 0,
             click_pos: (0.0, 0.0),
             click_button: MouseButton::Left,
+            double_click_interval: DEFAULT_DOUBLE_CLICK_INTERVAL,
+            double_click_distance: DEFAULT_DOUBLE_CLICK_DISTANCE,
+            drag_threshold: DEFAULT_DRAG_THRESHOLD,
+            drag_threshold_exceeded: false,
+            long_press_timer: Timer(0, 0),
+            long_press_origin: None,
+            long_press_distance: DEFAULT_LONG_PRESS_DISTANCE,
+            touches: HashMap::new(),
+            tasks: HashMap::new(),
+            debounced_events: HashMap::new(),
+            throttled_events: HashMap::new(),
+            hotkeys: Vec::new(),
+            next_hotkey_id: 0,
 
             ignore_default_theme: false,
             window_has_focus: true,
 
             drop_data: None,
+            monitors: Vec::new(),
+            tooltip_config: TooltipConfig::default(),
         };
 
         result.tree.set_window(Entity::root(), true);
@@ -225,6 +361,12 @@ impl Context {
         // Build the environment model at the root.
         Environment::new(&mut result).build(&mut result);
 
+        result.long_press_timer = result.add_timer(
+            DEFAULT_LONG_PRESS_DURATION,
+            Some(DEFAULT_LONG_PRESS_DURATION),
+            long_press_callback,
+        );
+
         result.entity_manager.create();
 
         result.style.role.insert(Entity::root(), Role::Window);
@@ -254,6 +396,22 @@ impl Context {
         self.data::<Environment>().unwrap()
     }
 
+    /// Returns the [theme mode](ThemeMode) reported by the system, or `None` if the backend is unable to
+    /// query it.
+    pub fn system_theme(&self) -> Option<ThemeMode> {
+        self.environment().theme.sys_theme
+    }
+
+    /// Returns true if the system requests that motion from animations be reduced.
+    pub fn prefers_reduced_motion(&self) -> bool {
+        self.environment().reduced_motion
+    }
+
+    /// Returns true if the system requests a high contrast presentation.
+    pub fn prefers_high_contrast(&self) -> bool {
+        self.environment().high_contrast
+    }
+
     pub fn parent_window(&self) -> Entity {
         self.tree.get_parent_window(self.current).unwrap_or(Entity::root())
     }
@@ -263,6 +421,349 @@ impl Context {
         self.style.dpi_factor as f32
     }
 
+    /// Returns the monitors connected to the system, as reported by the windowing backend.
+    ///
+    /// The winit backend updates the list when a window is created, gains focus or changes scale factor, as it isn't
+    /// told when monitors are connected or disconnected. The baseview backend doesn't report any monitors.
+    pub fn monitors(&self) -> &[MonitorInfo] {
+        &self.monitors
+    }
+
+    /// Measures the size of the text when rendered with the given font families and size, using the same text
+    /// shaper as the renderer.
+    ///
+    /// If `max_width` is given the text is wrapped to fit within it. Returns the width, height and number of lines
+    /// of the text. Sizes are in logical pixels and account for the scale factor of the display. If `font` is empty,
+    /// the default sans-serif font is used.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// let font = [FamilyOwned::Generic(GenericFontFamily::SansSerif)];
+    /// let (width, height, lines) = cx.measure_text("Hello World", &font, 16.0, Some(200.0));
+    /// ```
+    pub fn measure_text(
+        &self,
+        text: &str,
+        font: &[FamilyOwned],
+        size: f32,
+        max_width: Option<f32>,
+    ) -> (f32, f32, usize) {
+        let scale = self.scale_factor();
+        let (width, height, line_count) = self.text_context.measure_text(
+            text,
+            font,
+            size * scale,
+            max_width.map(|max_width| max_width * scale),
+        );
+
+        (width / scale, height / scale, line_count)
+    }
+
+    // Returns the current entity if it is a window, otherwise its parent window.
+    fn current_window(&self) -> Entity {
+        if self.tree.is_window(self.current) {
+            self.current
+        } else {
+            self.parent_window()
+        }
+    }
+
+    /// Returns a reference to the data of type `T` stored for the window containing the current view.
+    ///
+    /// Returns `None` if no data of that type has been stored for the window.
+    pub fn window_data<T: 'static>(&self) -> Option<&T> {
+        self.window_data
+            .get(&self.current_window())
+            .and_then(|data| data.get(&TypeId::of::<T>()))
+            .and_then(|data| data.downcast_ref::<T>())
+    }
+
+    /// Returns a mutable reference to the data of type `T` stored for the window containing the current view.
+    pub fn window_data_mut<T: 'static>(&mut self) -> Option<&mut T> {
+        let window_entity = self.current_window();
+        self.window_data
+            .get_mut(&window_entity)
+            .and_then(|data| data.get_mut(&TypeId::of::<T>()))
+            .and_then(|data| data.downcast_mut::<T>())
+    }
+
+    /// Stores data of type `T` for the window containing the current view, replacing any existing data of the same type.
+    ///
+    /// The data is dropped when the window is closed.
+    pub fn set_window_data<T: 'static>(&mut self, data: T) {
+        let window_entity = self.current_window();
+        self.window_data
+            .entry(window_entity)
+            .or_default()
+            .insert(TypeId::of::<T>(), Box::new(data));
+    }
+
+    /// Removes and returns the data of type `T` stored for the window containing the current view.
+    pub fn take_window_data<T: 'static>(&mut self) -> Option<T> {
+        let window_entity = self.current_window();
+        self.window_data
+            .get_mut(&window_entity)
+            .and_then(|data| data.remove(&TypeId::of::<T>()))
+            .and_then(|data| data.downcast::<T>().ok())
+            .map(|data| *data)
+    }
+
+    /// Binds the title of the window containing the current view to the given lens.
+    ///
+    /// The OS window title is updated whenever the bound value changes. Rapid changes are debounced so that
+    /// the title is only set once the value has settled.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// #[derive(Lens)]
+    /// pub struct AppData {
+    ///     title: String,
+    /// }
+    ///
+    /// impl Model for AppData {}
+    ///
+    /// AppData { title: String::from("Document* - MyApp") }.build(cx);
+    ///
+    /// cx.bind_window_title(AppData::title);
+    /// ```
+    pub fn bind_window_title<L>(&mut self, lens: L)
+    where
+        L: Lens<Source: 'static, Target: Data + ToString>,
+    {
+        let window_entity = self.current_window();
+
+        let timer = self.add_timer(
+            WINDOW_TITLE_DEBOUNCE,
+            Some(WINDOW_TITLE_DEBOUNCE),
+            move |cx, action| {
+                if let TimerAction::Stop = action {
+                    let title = lens.get(cx).to_string();
+                    cx.emit_to(window_entity, WindowEvent::SetTitle(title));
+                }
+            },
+        );
+
+        // Restarting the timer on every change delays the update until the title has settled.
+        Binding::new(self, lens, move |cx, _| cx.start_timer(timer));
+    }
+
+    /// Sets a callback which post-processes each rendered frame before it is presented.
+    ///
+    /// The callback receives the canvas of the window surface, which has been cleared, along with an image of the
+    /// rendered scene and information about the frame. It is responsible for drawing the final frame, for example by
+    /// drawing the image with an image filter or with an SkSL shader created from a [`RuntimeEffect`](skia_safe::RuntimeEffect).
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_core::vg;
+    /// # let cx = &mut Context::default();
+    /// // Draw the frame in grayscale.
+    /// let matrix = vg::ColorMatrix::new(
+    ///     0.2126, 0.7152, 0.0722, 0.0, 0.0,
+    ///     0.2126, 0.7152, 0.0722, 0.0, 0.0,
+    ///     0.2126, 0.7152, 0.0722, 0.0, 0.0,
+    ///     0.0, 0.0, 0.0, 1.0, 0.0,
+    /// );
+    /// let filter = vg::color_filters::matrix(&matrix, None);
+    ///
+    /// cx.set_post_process(move |canvas, image, _info| {
+    ///     let mut paint = vg::Paint::default();
+    ///     paint.set_color_filter(filter.clone());
+    ///     canvas.draw_image(image, (0, 0), Some(&paint));
+    /// });
+    /// ```
+    pub fn set_post_process(
+        &mut self,
+        callback: impl Fn(&Canvas, &skia_safe::Image, &PostProcessInfo) + 'static,
+    ) {
+        self.post_process = Some(Box::new(callback));
+        self.needs_redraw_all_windows();
+    }
+
+    /// Sets an image filter which is applied to each rendered frame before it is presented.
+    ///
+    /// This is a shorthand for [`set_post_process`](Self::set_post_process) which draws the frame with the given filter.
+    pub fn set_post_process_filter(&mut self, filter: skia_safe::ImageFilter) {
+        self.set_post_process(move |canvas, image, _| {
+            let mut paint = skia_safe::Paint::default();
+            paint.set_image_filter(filter.clone());
+            canvas.draw_image(image, (0, 0), Some(&paint));
+        });
+    }
+
+    /// Removes any post-process callback or filter, presenting rendered frames unchanged.
+    pub fn clear_post_process(&mut self) {
+        self.post_process = None;
+        self.needs_redraw_all_windows();
+    }
+
+    // Marks every window as needing to be redrawn.
+    fn needs_redraw_all_windows(&mut self) {
+        for (window_entity, window_state) in self.windows.iter_mut() {
+            window_state.redraw_list.insert(*window_entity);
+        }
+    }
+
+    /// Toggles the layout inspector overlay, which highlights the hovered view and shows its bounds and computed style.
+    ///
+    /// While the inspector is shown, clicking on a view pins it so that it remains inspected when the mouse moves away.
+    pub fn toggle_inspector(&mut self) {
+        self.inspector.enabled = !self.inspector.enabled;
+        self.inspector.pinned = None;
+        self.needs_redraw_all_windows();
+    }
+
+    /// Hides the cursor of the window containing the current view after the mouse has not moved for the given delay.
+    ///
+    /// The cursor is shown again as soon as the mouse moves. Pass `None` to disable auto-hiding, which also shows the
+    /// cursor if it is currently hidden.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use instant::Duration;
+    /// # let cx = &mut Context::default();
+    /// cx.auto_hide_cursor(Some(Duration::from_secs(3)));
+    /// ```
+    pub fn auto_hide_cursor(&mut self, delay: Option<Duration>) {
+        let window_entity = self.current_window();
+
+        let Some(window_state) = self.windows.get_mut(&window_entity) else {
+            return;
+        };
+
+        let previous_timer = window_state.cursor_auto_hide_timer.take();
+        let was_hidden = std::mem::take(&mut window_state.cursor_hidden);
+
+        if let Some(previous_timer) = previous_timer {
+            self.stop_timer(previous_timer);
+        }
+
+        if was_hidden {
+            self.emit_to(window_entity, WindowEvent::SetCursorVisible(true));
+        }
+
+        if let Some(delay) = delay {
+            let timer =
+                self.add_timer(delay, Some(delay), cursor_auto_hide_callback(window_entity));
+
+            if let Some(window_state) = self.windows.get_mut(&window_entity) {
+                window_state.cursor_auto_hide_timer = Some(timer);
+            }
+
+            self.with_current(window_entity, |cx| cx.start_timer(timer));
+        }
+    }
+
+    /// Sets the maximum time between presses of the same mouse button for them to be reported as a
+    /// [`MouseDoubleClick`](WindowEvent::MouseDoubleClick) or [`MouseTripleClick`](WindowEvent::MouseTripleClick).
+    /// Defaults to 500ms.
+    pub fn set_double_click_interval(&mut self, interval: Duration) {
+        self.double_click_interval = interval;
+    }
+
+    /// Sets the maximum distance, in logical pixels, the cursor can move between presses of the same mouse button
+    /// for them to be reported as a [`MouseDoubleClick`](WindowEvent::MouseDoubleClick) or
+    /// [`MouseTripleClick`](WindowEvent::MouseTripleClick). Defaults to 4 pixels.
+    pub fn set_double_click_distance(&mut self, distance: f32) {
+        self.double_click_distance = distance.max(0.0);
+    }
+
+    /// Sets the distance, in logical pixels, the cursor must move while the left mouse button is held down before
+    /// the press is treated as a drag. Defaults to 4 pixels.
+    ///
+    /// Views can check whether the threshold has been exceeded with
+    /// [`EventContext::drag_threshold_exceeded`].
+    pub fn set_drag_threshold(&mut self, threshold: f32) {
+        self.drag_threshold = threshold.max(0.0);
+    }
+
+    /// Sets the time the left mouse button must be held down for a [`LongPress`](WindowEvent::LongPress) to be
+    /// emitted. Defaults to 500ms.
+    pub fn set_long_press_duration(&mut self, duration: Duration) {
+        self.modify_timer(self.long_press_timer, |timer_state| {
+            timer_state.interval = duration;
+            timer_state.duration = Some(duration);
+        });
+    }
+
+    /// Sets the maximum distance, in logical pixels, the cursor can move while the left mouse button is held down
+    /// for a [`LongPress`](WindowEvent::LongPress) to be emitted. Defaults to 8 pixels.
+    pub fn set_long_press_distance(&mut self, distance: f32) {
+        self.long_press_distance = distance.max(0.0);
+    }
+
+    /// Sets whether animations and transitions should ignore the system preference for reduced motion.
+    ///
+    /// By default, animations and transitions skip straight to their final values when the system requests
+    /// reduced motion.
+    pub fn set_ignore_reduced_motion(&mut self, flag: bool) {
+        self.style.ignore_reduced_motion = flag;
+    }
+
+    /// Sets the delays used when showing and hiding tooltips for all views.
+    pub fn set_tooltip_config(&mut self, config: TooltipConfig) {
+        self.tooltip_config = config;
+    }
+
+    /// Presents a [`Modal`] above the content of the current window, built from the given closure, and returns
+    /// the entity of the modal.
+    ///
+    /// Modals can be stacked, in which case [`dismiss_modal`](Self::dismiss_modal) closes the most recently
+    /// presented one.
+    pub fn present_modal(&mut self, content: impl FnOnce(&mut Context)) -> Entity {
+        let window_entity = self.current_window();
+        let previous_focus = self.focused;
+
+        // A view behind the modal which has captured the pointer would otherwise keep receiving pointer input.
+        self.captured = Entity::null();
+
+        let modal = self.with_current(window_entity, |cx| Modal::new(cx, content).entity());
+
+        // If the content has nothing to focus then the modal itself is focused, so that key presses don't reach
+        // the content behind it.
+        if !self.focused.is_descendant_of(&self.tree, modal) {
+            self.focus_stack.push(previous_focus);
+            self.with_current(modal, |cx| cx.focus());
+        }
+
+        if let Some(window_state) = self.windows.get_mut(&window_entity) {
+            window_state.modals.push(modal);
+        }
+
+        modal
+    }
+
+    /// Dismisses the most recently presented modal of the current window, returning focus to the view which
+    /// was focused before the modal was presented.
+    pub fn dismiss_modal(&mut self) {
+        let window_entity = self.current_window();
+
+        if let Some(modal) =
+            self.windows.get_mut(&window_entity).and_then(|window_state| window_state.modals.pop())
+        {
+            self.remove(modal);
+        }
+    }
+
+    /// Dismisses the given modal, as returned by [`present_modal`](Self::present_modal), even if other modals
+    /// have been presented above it.
+    pub fn dismiss_modal_entity(&mut self, modal: Entity) {
+        let window_entity = self.tree.get_parent_window(modal).unwrap_or(Entity::root());
+
+        if let Some(window_state) = self.windows.get_mut(&window_entity) {
+            if window_state.modals.contains(&modal) {
+                window_state.modals.retain(|entity| *entity != modal);
+                self.remove(modal);
+            }
+        }
+    }
+
     /// Mark the application as needing to rerun the draw method
     pub fn needs_redraw(&mut self, entity: Entity) {
         if self.entity_manager.is_alive(entity) {
@@ -288,6 +789,68 @@ impl Context {
         // self.style.needs_restyle();
     }
 
+    /// Returns the computed style of the given view.
+    pub fn computed_style(&self, entity: Entity) -> ComputedStyle<'_> {
+        ComputedStyle::new(&self.style, entity)
+    }
+
+    /// Sets a style property inline on the given view, overriding the value of the property from any stylesheets.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// let entity = Element::new(cx).entity();
+    /// cx.set_style_property(entity, Property::BackgroundColor(Color::red()));
+    /// ```
+    pub fn set_style_property(&mut self, entity: Entity, property: Property) {
+        self.style.insert_inline_property(entity, property);
+        self.style.system_flags |= SystemFlags::RELAYOUT | SystemFlags::REFLOW;
+        self.set_system_flags(entity, SystemFlags::all());
+    }
+
+    /// Removes a style property set inline on the given view with [`set_style_property`](Self::set_style_property),
+    /// so that the value from any stylesheets is used again. The property is given by its CSS name, such as
+    /// `background-color`, and unknown names are ignored.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// let entity = Element::new(cx).entity();
+    /// cx.set_style_property(entity, Property::BackgroundColor(Color::red()));
+    /// cx.remove_style_property(entity, "background-color");
+    /// ```
+    pub fn remove_style_property(&mut self, entity: Entity, name: &str) {
+        if !self.style.remove_inline_property(entity, name) {
+            return;
+        }
+
+        self.style.system_flags |= SystemFlags::RELAYOUT | SystemFlags::REFLOW;
+        self.set_system_flags(entity, SystemFlags::all());
+    }
+
+    /// Sets the value of a CSS custom property, such as `--accent`, overriding any value declared in stylesheets.
+    ///
+    /// Properties which reference the variable with `var()` are updated when the styles are next reloaded, which
+    /// allows a theme to be changed by swapping the values of its variables.
+    ///
+    /// Custom properties are global to the application. They may only be declared in `:root` rules, with later
+    /// declarations overriding earlier ones, and declarations under other selectors are ignored with a warning.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// cx.add_stylesheet(":root { --accent: #3b82f6; } button { background-color: var(--accent); }")
+    ///     .expect("Failed to add stylesheet");
+    /// cx.set_style_variable("--accent", "#ef4444");
+    /// ```
+    pub fn set_style_variable(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        self.style.style_variables.insert(name.into(), value.into());
+        self.emit_to(Entity::root(), WindowEvent::ReloadStyles);
+    }
+
     /// Mark the application as needing to rerun layout computations
     pub fn needs_relayout(&mut self) {
         self.style.needs_relayout();
@@ -377,6 +940,11 @@ impl Context {
         self.focus_with_visibility(old_focus_visible)
     }
 
+    /// Sets application focus to the given entity using the previous focus visibility
+    pub fn focus_entity(&mut self, entity: Entity) {
+        self.with_current(entity, |cx| cx.focus());
+    }
+
     /// Removes the children of the provided entity from the application.
     pub(crate) fn remove_children(&mut self, entity: Entity) {
         let child_iter = ChildIterator::new(&self.tree, entity);
@@ -386,7 +954,12 @@ impl Context {
         }
     }
 
-    /// Removes the provided entity from the application.
+    /// Removes the provided entity and its descendants from the application.
+    ///
+    /// Each removed view has its [`on_remove`](crate::view::View::on_remove) method called and receives a
+    /// [`WindowEvent::Destroyed`] event. Any timers, tasks, pending debounced or throttled events, animations,
+    /// bindings and listeners belonging to the removed views are cancelled, and focus, hover and pointer capture are
+    /// released if held by a removed view.
     pub fn remove(&mut self, entity: Entity) {
         let delete_list = entity.branch_iter(&self.tree).collect::<Vec<_>>();
 
@@ -398,6 +971,8 @@ impl Context {
 
         for entity in delete_list.iter().rev() {
             if let Some(mut view) = self.views.remove(entity) {
+                view.on_remove(&mut EventContext::new_with_current(self, *entity));
+
                 view.event(
                     &mut EventContext::new_with_current(self, *entity),
                     &mut Event::new(WindowEvent::Destroyed).direct(*entity),
@@ -437,6 +1012,16 @@ impl Context {
                 self.captured = Entity::null();
             }
 
+            if self.triggered == *entity {
+                self.triggered = Entity::null();
+            }
+
+            if self.hovered == *entity {
+                self.hovered = self.tree.get_parent(*entity).unwrap_or(Entity::root());
+            }
+
+            self.listeners.remove(entity);
+
             // Remove any map lenses associated with the entity.
 
             MAP_MANAGER.with_borrow_mut(|manager| {
@@ -465,9 +1050,45 @@ impl Context {
             }
 
             for timer in stopped_timers {
+                // Cancel a pending long press so that stopping its timer does not emit a `LongPress`.
+                if timer == self.long_press_timer {
+                    self.long_press_origin = None;
+                }
+
                 self.stop_timer(timer);
             }
 
+            self.springs.retain(|spring_state| spring_state.entity != *entity);
+
+            // Touches which started on the view are sent to the window for the rest of their lifetime.
+            self.touches.retain(|_, target| *target != *entity);
+
+            if let Some(tasks) = self.tasks.remove(entity) {
+                for task in tasks {
+                    task.cancel();
+                }
+            }
+
+            let mut cancelled_events = Vec::new();
+            self.debounced_events.retain(|(view, _), handle| {
+                if view == entity {
+                    cancelled_events.push(*handle);
+                }
+                view != entity
+            });
+            self.throttled_events.retain(|(view, _), state| {
+                if view == entity {
+                    cancelled_events.extend(state.pending.map(|(handle, _)| handle));
+                }
+                view != entity
+            });
+
+            for handle in cancelled_events {
+                self.cancel_scheduled(handle);
+            }
+
+            self.hotkeys.retain(|hotkey| hotkey.scope != *entity);
+
             let window_entity = self.tree.get_parent_window(*entity).unwrap_or(Entity::root());
 
             if !self.tree.is_window(*entity) {
@@ -487,6 +1108,11 @@ impl Context {
 
             if self.windows.contains_key(entity) {
                 self.windows.remove(entity);
+                self.window_data.remove(entity);
+            }
+
+            if self.inspector.pinned == Some(*entity) {
+                self.inspector.pinned = None;
             }
 
             self.tree.remove(*entity).expect("");
@@ -576,6 +1202,80 @@ impl Context {
         Ok(())
     }
 
+    /// Adds a stylesheet loaded from a file, which is reloaded and re-applied whenever the file changes.
+    ///
+    /// If the file can't be read or parsed, `on_error` is called with the error and the previous contents of the
+    /// stylesheet are kept, so a typo while editing does not remove the styling of the application.
+    ///
+    /// # Example
+    /// ```ignore
+    /// cx.add_stylesheet_watched("src/style.css", |_, error| eprintln!("{}", error));
+    /// ```
+    #[cfg(feature = "hot-reload")]
+    pub fn add_stylesheet_watched(
+        &mut self,
+        path: impl Into<std::path::PathBuf>,
+        on_error: impl Fn(&mut EventContext, StyleSheetError) + 'static,
+    ) {
+        use notify::Watcher;
+        use std::sync::{
+            atomic::{AtomicBool, Ordering},
+            Arc,
+        };
+
+        let path = path.into();
+        let path = path.canonicalize().unwrap_or(path);
+
+        // The file is read when the stylesheet is first loaded.
+        let changed = Arc::new(AtomicBool::new(true));
+
+        // Editors often save by replacing the file, so the parent directory is watched rather than the file itself.
+        let watcher = self.event_proxy.as_ref().map(|proxy| proxy.make_clone()).and_then(|proxy| {
+            let watched_path = path.clone();
+            let changed = changed.clone();
+            let mut watcher =
+                notify::recommended_watcher(move |result: notify::Result<notify::Event>| {
+                    let Ok(event) = result else { return };
+                    if (event.kind.is_create() || event.kind.is_modify())
+                        && event.paths.iter().any(|path| *path == watched_path)
+                    {
+                        // A single save often produces several events, so the styles are reloaded once the events
+                        // have settled rather than for each event.
+                        if !changed.swap(true, Ordering::AcqRel) {
+                            let proxy = proxy.make_clone();
+                            std::thread::spawn(move || {
+                                std::thread::sleep(STYLESHEET_RELOAD_DELAY);
+                                let _ = proxy.send(
+                                    Event::new(WindowEvent::ReloadStyles).target(Entity::root()),
+                                );
+                            });
+                        }
+                    }
+                })
+                .ok()?;
+
+            watcher.watch(path.parent()?, notify::RecursiveMode::NonRecursive).ok()?;
+
+            Some(watcher)
+        });
+
+        if watcher.is_none() {
+            log::warn!("Unable to watch stylesheet for changes: {}", path.display());
+        }
+
+        let contents = Rc::new(RefCell::new(String::new()));
+        self.resource_manager.styles.push(Box::new(crate::resource::WatchedCss(contents.clone())));
+        self.resource_manager.watched_stylesheets.push(crate::resource::WatchedStyleSheet {
+            path,
+            contents,
+            changed,
+            on_error: Rc::new(on_error),
+            _watcher: watcher,
+        });
+
+        EventContext::new(self).reload_styles().expect("Failed to reload styles");
+    }
+
     /// Remove all user themes from the application.
     pub fn remove_user_themes(&mut self) {
         self.resource_manager.themes.clear();
@@ -603,6 +1303,14 @@ impl Context {
         self.resource_manager.add_translation(lang, ftl.to_string());
     }
 
+    /// Returns the translation of a key, or a [`Localized`] with arguments, for the current language.
+    ///
+    /// Falls back to the key if there is no translation for it. Unlike binding a [`Localized`] to a view, the
+    /// returned text is not updated when the language changes.
+    pub fn translate(&self, text: impl Into<Localized>) -> String {
+        text.into().to_string_local(self)
+    }
+
     /// Adds a timer to the application.
     ///
     /// `interval` - The time between ticks of the timer.
@@ -639,8 +1347,17 @@ impl Context {
         duration: Option<Duration>,
         callback: impl Fn(&mut EventContext, TimerAction) + 'static,
     ) -> Timer {
-        let id = Timer(self.timers.len());
-        self.timers.push(TimerState {
+        // Reuse the slot of a removed timer so that the ids of other timers are unchanged.
+        let index = self
+            .timers
+            .iter()
+            .position(|timer_state| timer_state.removed)
+            .unwrap_or(self.timers.len());
+        // Handles to the removed timer keep the previous generation, so they don't refer to the new timer.
+        let generation =
+            self.timers.get(index).map_or(0, |timer_state| timer_state.id.1.wrapping_add(1));
+        let id = Timer(index, generation);
+        let timer_state = TimerState {
             entity: Entity::root(),
             id,
             time: Instant::now(),
@@ -650,16 +1367,123 @@ impl Context {
             callback: Rc::new(callback),
             ticking: false,
             stopping: false,
+            remove_on_stop: false,
+            removed: false,
+        };
+
+        if index < self.timers.len() {
+            self.timers[index] = timer_state;
+        } else {
+            self.timers.push(timer_state);
+        }
+
+        id
+    }
+
+    /// Adds a hotkey which calls `callback` when the key chord is pressed while keyboard focus is within the current
+    /// view.
+    ///
+    /// Hotkeys added to the root view, such as within the content closure of the application, are global. If several
+    /// views containing the focused view have a hotkey for the same key chord, only those of the innermost view are
+    /// called. A key chord without a control, alt or super modifier does not trigger hotkeys while a textbox has
+    /// focus, so that it can be typed. The key press is not sent to the focused view when a hotkey is triggered.
+    ///
+    /// The key chord can be a [`KeyChord`], which matches the physical key, or a [`HotkeyChord::Key`], which matches the
+    /// logical key produced by the keyboard layout. Key repeats while the key chord is held down do not trigger the
+    /// hotkey, unless enabled with [`set_hotkey_repeat`](Self::set_hotkey_repeat).
+    ///
+    /// Returns a [`Hotkey`] which can be used to remove the hotkey with [`remove_hotkey`](Self::remove_hotkey).
+    /// Hotkeys are also removed when the view which added them is removed.
+    ///
+    /// # Example
+    /// ```ignore
+    /// cx.add_hotkey(KeyChord::new(Modifiers::CTRL, Code::KeyS), |cx| cx.emit(AppEvent::Save));
+    /// cx.add_hotkey(HotkeyChord::Key(Modifiers::CTRL, Key::Character("+".into())), |cx| cx.emit(AppEvent::ZoomIn));
+    /// ```
+    pub fn add_hotkey(
+        &mut self,
+        chord: impl Into<HotkeyChord>,
+        callback: impl Fn(&mut EventContext) + 'static,
+    ) -> Hotkey {
+        let id = Hotkey(self.next_hotkey_id);
+        self.next_hotkey_id += 1;
+        self.hotkeys.push(HotkeyEntry {
+            id,
+            chord: chord.into(),
+            repeat: false,
+            scope: self.current,
+            callback: Rc::new(callback),
         });
 
         id
     }
 
+    /// Removes the hotkey with the given id.
+    pub fn remove_hotkey(&mut self, hotkey: Hotkey) {
+        self.hotkeys.retain(|entry| entry.id != hotkey);
+    }
+
+    /// Sets whether the hotkey with the given id is also triggered by key repeats while its key chord is held down.
+    pub fn set_hotkey_repeat(&mut self, hotkey: Hotkey, repeat: bool) {
+        if let Some(entry) = self.hotkeys.iter_mut().find(|entry| entry.id == hotkey) {
+            entry.repeat = repeat;
+        }
+    }
+
+    /// Adds hotkeys to the current view which send [`UndoEvent`]s while keyboard focus is within the view.
+    ///
+    /// Ctrl+Z undoes and Ctrl+Shift+Z redoes, using Cmd in place of Ctrl on macOS. The events are sent up the tree
+    /// from the current view, so this should be called from the view in which the model with the
+    /// [`UndoHistory`] is built, or one of its descendants.
+    pub fn add_undo_hotkeys(&mut self) {
+        #[cfg(target_os = "macos")]
+        let modifier = Modifiers::SUPER;
+        #[cfg(not(target_os = "macos"))]
+        let modifier = Modifiers::CTRL;
+
+        self.add_hotkey(KeyChord::new(modifier, Code::KeyZ), |cx| cx.undo());
+        self.add_hotkey(KeyChord::new(modifier | Modifiers::SHIFT, Code::KeyZ), |cx| cx.redo());
+    }
+
+    /// Adds and starts a timer which calls `callback` every `interval`, until it is stopped with
+    /// [`stop_timer`](Self::stop_timer). The timer is removed once it has been stopped, so it can't be restarted.
+    ///
+    /// This is shorthand for [`add_timer`](Self::add_timer) followed by [`start_timer`](Self::start_timer), for timers
+    /// which only respond to ticks. Events sent within the callback target the current view.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let timer = cx.start_interval(Duration::from_millis(500), |cx| cx.emit(AppEvent::Poll));
+    /// ```
+    pub fn start_interval(
+        &mut self,
+        interval: Duration,
+        callback: impl Fn(&mut EventContext) + 'static,
+    ) -> Timer {
+        let timer = self.add_timer(interval, None, move |cx, action| {
+            if let TimerAction::Tick(_) = action {
+                (callback)(cx);
+            }
+        });
+        self.timers[timer.0].remove_on_stop = true;
+        self.start_timer(timer);
+
+        timer
+    }
+
     /// Starts a timer with the provided timer id.
     ///
     /// Events sent within the timer callback provided in `add_timer()` will target the current view.
     pub fn start_timer(&mut self, timer: Timer) {
         let current = self.current;
+        if self
+            .timers
+            .get(timer.0)
+            .map_or(true, |timer_state| timer_state.removed || timer_state.id != timer)
+        {
+            return;
+        }
+
         if !self.timer_is_running(timer) {
             let timer_state = self.timers[timer.0].clone();
             // Copy timer state from pending to playing
@@ -678,16 +1502,18 @@ impl Context {
 
     /// Modifies the state of an existing timer with the provided `Timer` id.
     pub fn modify_timer(&mut self, timer: Timer, timer_function: impl Fn(&mut TimerState)) {
-        while let Some(next_timer_state) = self.running_timers.peek() {
-            if next_timer_state.id == timer {
-                let mut timer_state = self.running_timers.pop().unwrap();
-
-                (timer_function)(&mut timer_state);
+        let mut running_timers = std::mem::take(&mut self.running_timers).into_vec();
+        let mut is_running = false;
+        for timer_state in running_timers.iter_mut().filter(|timer_state| timer_state.id == timer) {
+            (timer_function)(timer_state);
+            is_running = true;
+        }
 
-                self.running_timers.push(timer_state);
+        // Rebuild the heap, as the next tick time of the timer may have changed.
+        self.running_timers = running_timers.into();
 
-                return;
-            }
+        if is_running {
+            return;
         }
 
         for pending_timer in self.timers.iter_mut() {
@@ -725,6 +1551,17 @@ impl Context {
 
         self.running_timers =
             running_timers.drain().filter(|timer_state| timer_state.id != timer).collect();
+
+        // Timers added with `start_interval` are removed once stopped, dropping their callback.
+        if let Some(timer_state) =
+            self.timers.get_mut(timer.0).filter(|timer_state| timer_state.id == timer)
+        {
+            if timer_state.remove_on_stop {
+                timer_state.remove_on_stop = false;
+                timer_state.removed = true;
+                timer_state.callback = Rc::new(|_, _| {});
+            }
+        }
     }
 
     // Tick all timers.
@@ -837,6 +1674,33 @@ impl Context {
         std::thread::spawn(move || target(&mut cxp));
     }
 
+    /// Spawns a task which runs the future returned by `task` on a worker thread, providing a [ContextProxy] for
+    /// sending events back to the main UI thread.
+    ///
+    /// The task is cancelled if the returned [TaskHandle] is used to cancel it, or when the current view is removed,
+    /// for example because its window was closed.
+    ///
+    /// # Example
+    /// ```ignore
+    /// cx.spawn_task(|mut cx| async move {
+    ///     let contents = load_file("data.json").await;
+    ///     cx.emit(AppEvent::Loaded(contents)).expect("Failed to send event");
+    /// });
+    /// ```
+    pub fn spawn_task<F, Fut>(&mut self, task: F) -> TaskHandle
+    where
+        F: 'static + Send + FnOnce(ContextProxy) -> Fut,
+        Fut: Future<Output = ()>,
+    {
+        let handle = spawn_task(self.get_proxy(), task);
+
+        let tasks = self.tasks.entry(self.current).or_default();
+        tasks.retain(|task| !task.is_finished());
+        tasks.push(handle.clone());
+
+        handle
+    }
+
     pub fn get_proxy(&self) -> ContextProxy {
         ContextProxy {
             current: self.current,
@@ -878,6 +1742,7 @@ impl Context {
 
 pub(crate) enum InternalEvent {
     Redraw,
+    Remove(Entity),
     LoadImage { path: String, image: Mutex<Option<skia_safe::Image>>, policy: ImageRetentionPolicy },
 }
 
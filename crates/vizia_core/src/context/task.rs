@@ -0,0 +1,85 @@
+use std::future::Future;
+use std::pin::pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::task::{Poll, Wake, Waker};
+use std::thread::{self, Thread};
+
+use crate::prelude::*;
+
+/// A handle to a task spawned with [`spawn_task`](crate::context::Context::spawn_task), which can be used to cancel
+/// the task.
+///
+/// A task is also cancelled when the view which spawned it is removed, for example when its window is closed.
+#[derive(Clone)]
+pub struct TaskHandle {
+    cancelled: Arc<AtomicBool>,
+    finished: Arc<AtomicBool>,
+    thread: Thread,
+}
+
+impl TaskHandle {
+    /// Cancels the task.
+    ///
+    /// The future of the task is dropped instead of being polled again. A future which is blocked within a
+    /// synchronous call is only dropped once that call returns.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Release);
+        self.thread.unpark();
+    }
+
+    /// Returns true if the task has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Acquire)
+    }
+
+    /// Returns true if the future of the task has completed or been dropped.
+    pub fn is_finished(&self) -> bool {
+        self.finished.load(Ordering::Acquire)
+    }
+}
+
+// Wakes the worker thread of a task so that its future is polled again.
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Spawns a worker thread which runs the future returned by `task` to completion, or until it is cancelled.
+pub(crate) fn spawn_task<F, Fut>(proxy: ContextProxy, task: F) -> TaskHandle
+where
+    F: 'static + Send + FnOnce(ContextProxy) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    let cancelled = Arc::new(AtomicBool::new(false));
+    let finished = Arc::new(AtomicBool::new(false));
+
+    let task_cancelled = cancelled.clone();
+    let task_finished = finished.clone();
+    let worker = thread::spawn(move || {
+        let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+        let mut task_cx = std::task::Context::from_waker(&waker);
+
+        {
+            let mut future = pin!((task)(proxy));
+            while !task_cancelled.load(Ordering::Acquire) {
+                if let Poll::Ready(()) = future.as_mut().poll(&mut task_cx) {
+                    break;
+                }
+
+                thread::park();
+            }
+        }
+
+        task_finished.store(true, Ordering::Release);
+    });
+
+    TaskHandle { cancelled, finished, thread: worker.thread().clone() }
+}
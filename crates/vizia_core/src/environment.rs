@@ -60,6 +60,10 @@ pub struct Environment {
     pub locale: LanguageIdentifier,
     /// Current application and system theme.
     pub theme: Theme,
+    /// Whether the system requests that motion from animations be reduced.
+    pub reduced_motion: bool,
+    /// Whether the system requests a high contrast presentation.
+    pub high_contrast: bool,
     /// The timer used to blink the caret of a textbox.
     pub(crate) caret_timer: Timer,
 }
@@ -72,7 +76,13 @@ impl Environment {
                 cx.emit(TextEvent::ToggleCaret);
             }
         });
-        Self { locale, theme: Theme::default(), caret_timer }
+        Self {
+            locale,
+            theme: Theme::default(),
+            reduced_motion: false,
+            high_contrast: false,
+            caret_timer,
+        }
     }
 }
 
@@ -129,6 +139,13 @@ impl Model for Environment {
                     cx.reload_styles().unwrap();
                 }
             }
+            WindowEvent::ReducedMotionChanged(flag) => {
+                self.reduced_motion = *flag;
+                cx.style.reduced_motion = *flag;
+            }
+            WindowEvent::HighContrastChanged(flag) => {
+                self.high_contrast = *flag;
+            }
             _ => (),
         })
     }
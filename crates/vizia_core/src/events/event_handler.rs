@@ -8,8 +8,14 @@ pub(crate) trait ViewHandler: Any {
         None
     }
 
+    fn accepts_text_input(&self) -> bool {
+        false
+    }
+
     fn event(&mut self, cx: &mut EventContext, event: &mut Event);
 
+    fn on_remove(&mut self, cx: &mut EventContext);
+
     fn draw(&self, cx: &mut DrawContext, canvas: &Canvas);
 
     fn accessibility(&self, cx: &mut AccessContext, node: &mut AccessNode);
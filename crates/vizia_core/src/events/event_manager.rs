@@ -1,10 +1,11 @@
 use crate::context::{InternalEvent, ResourceContext};
 use crate::events::EventMeta;
+use crate::input::dispatch_hotkeys;
 use crate::prelude::*;
 #[cfg(debug_assertions)]
 use crate::systems::compute_matched_rules;
-use crate::systems::{binding_system, hover_system};
-use crate::tree::{focus_backward, focus_forward, is_navigatable};
+use crate::systems::{binding_system, hit_test, hover_system};
+use crate::tree::next_focus;
 #[cfg(debug_assertions)]
 use log::debug;
 use std::any::Any;
@@ -13,8 +14,6 @@ use vizia_storage::LayoutParentIterator;
 use vizia_storage::ParentIterator;
 use vizia_storage::TreeIterator;
 
-const DOUBLE_CLICK_INTERVAL: Duration = Duration::from_millis(500);
-
 /// Dispatches events to views and models.
 ///
 /// The [EventManager] is responsible for taking the events in the event queue in cx
@@ -51,6 +50,12 @@ impl EventManager {
             // Handle internal events.
             event.take(|internal_event, _| match internal_event {
                 InternalEvent::Redraw => cx.needs_redraw(Entity::root()),
+                InternalEvent::Remove(entity) => {
+                    if cx.entity_manager.is_alive(entity) {
+                        let parent = cx.tree.get_parent(entity).unwrap_or(Entity::root());
+                        cx.with_current(parent, |cx| cx.remove(entity));
+                    }
+                }
                 InternalEvent::LoadImage { path, image, policy } => {
                     if let Some(image) = image.lock().unwrap().take() {
                         ResourceContext::new(cx).load_image(path, image, policy);
@@ -207,6 +212,36 @@ fn internal_state_updates(cx: &mut Context, window_event: &WindowEvent, meta: &m
 
                 hover_system(cx, meta.origin);
 
+                if cx.mouse.left.state == MouseButtonState::Pressed && !cx.drag_threshold_exceeded {
+                    let (down_x, down_y) = cx.mouse.left.pos_down;
+                    let threshold = cx.drag_threshold * cx.scale_factor();
+                    cx.drag_threshold_exceeded = (*x - down_x).hypot(*y - down_y) > threshold;
+                }
+
+                if let Some((origin_x, origin_y)) = cx.long_press_origin {
+                    let distance = cx.long_press_distance * cx.scale_factor();
+                    if (*x - origin_x).hypot(*y - origin_y) > distance {
+                        cancel_long_press(cx);
+                    }
+                }
+
+                // Show the cursor if it was hidden due to inactivity and restart the inactivity timer.
+                if let Some(window_state) = cx.windows.get_mut(&meta.origin) {
+                    if let Some(timer) = window_state.cursor_auto_hide_timer {
+                        if std::mem::take(&mut window_state.cursor_hidden) {
+                            cx.emit_to(meta.origin, WindowEvent::SetCursorVisible(true));
+                        }
+
+                        cx.with_current(meta.origin, |cx| cx.start_timer(timer));
+                    }
+                }
+
+                if cx.inspector.enabled {
+                    if let Some(window_state) = cx.windows.get_mut(&meta.origin) {
+                        window_state.redraw_list.insert(meta.origin);
+                    }
+                }
+
                 mutate_direct_or_up(meta, cx.captured, cx.hovered, false);
             }
 
@@ -225,6 +260,18 @@ fn internal_state_updates(cx: &mut Context, window_event: &WindowEvent, meta: &m
             //     );
             // }
         }
+        WindowEvent::MouseDown(MouseButton::Left) if cx.inspector.enabled => {
+            // Pin or unpin the hovered view in the inspector instead of interacting with it.
+            cx.inspector.pinned =
+                if cx.inspector.pinned == Some(cx.hovered) { None } else { Some(cx.hovered) };
+            if let Some(window_state) = cx.windows.get_mut(&meta.origin) {
+                window_state.redraw_list.insert(meta.origin);
+            }
+            meta.consume();
+        }
+        WindowEvent::MouseUp(MouseButton::Left) if cx.inspector.enabled => {
+            meta.consume();
+        }
         WindowEvent::MouseDown(button) => {
             // do direct state-updates
             match button {
@@ -233,6 +280,7 @@ fn internal_state_updates(cx: &mut Context, window_event: &WindowEvent, meta: &m
 
                     cx.mouse.left.pos_down = (cx.mouse.cursor_x, cx.mouse.cursor_y);
                     cx.mouse.left.pressed = cx.hovered;
+                    cx.drag_threshold_exceeded = false;
                     cx.triggered = cx.hovered;
 
                     let disabled = cx.style.disabled.get(cx.hovered).copied().unwrap_or_default();
@@ -253,6 +301,13 @@ fn internal_state_updates(cx: &mut Context, window_event: &WindowEvent, meta: &m
                     // Reset drag data
                     cx.drop_data = None;
 
+                    // Start waiting for a long press on the pressed view.
+                    let timer = cx.long_press_timer;
+                    cx.long_press_origin = Some((cx.mouse.cursor_x, cx.mouse.cursor_y));
+                    let target =
+                        if cx.captured != Entity::null() { cx.captured } else { cx.hovered };
+                    cx.with_current(target, |cx| cx.start_timer(timer));
+
                     cx.with_current(if focusable { cx.hovered } else { cx.focused }, |cx| {
                         cx.focus_with_visibility(false)
                     });
@@ -285,8 +340,10 @@ fn internal_state_updates(cx: &mut Context, window_event: &WindowEvent, meta: &m
             let new_click_time = Instant::now();
             let click_duration = new_click_time - cx.click_time;
             let new_click_pos = (cx.mouse.cursor_x, cx.mouse.cursor_y);
-            if click_duration <= DOUBLE_CLICK_INTERVAL
-                && new_click_pos == cx.click_pos
+            let click_distance =
+                (new_click_pos.0 - cx.click_pos.0).hypot(new_click_pos.1 - cx.click_pos.1);
+            if click_duration <= cx.double_click_interval
+                && click_distance <= cx.double_click_distance * cx.scale_factor()
                 && *button == cx.click_button
             {
                 if cx.clicks <= 2 {
@@ -313,6 +370,7 @@ fn internal_state_updates(cx: &mut Context, window_event: &WindowEvent, meta: &m
                     cx.mouse.left.pos_up = (cx.mouse.cursor_x, cx.mouse.cursor_y);
                     cx.mouse.left.released = cx.hovered;
                     cx.mouse.left.state = MouseButtonState::Released;
+                    cancel_long_press(cx);
                 }
                 MouseButton::Right => {
                     cx.mouse.right.pos_up = (cx.mouse.cursor_x, cx.mouse.cursor_y);
@@ -353,12 +411,46 @@ fn internal_state_updates(cx: &mut Context, window_event: &WindowEvent, meta: &m
 
             mutate_direct_or_up(meta, cx.captured, cx.hovered, true);
         }
-        WindowEvent::MouseScroll(_, _) => {
+        WindowEvent::MouseScroll(_, _)
+        | WindowEvent::MouseScrollPixels(_, _)
+        | WindowEvent::Zoom(_)
+        | WindowEvent::Rotate(_) => {
+            meta.target = cx.hovered;
+        }
+        WindowEvent::RawMouseMotion(_, _) => {
+            mutate_direct_or_up(meta, cx.captured, cx.hovered, true);
+        }
+        WindowEvent::Touch { id, phase, x, y } => {
+            // A touch targets the view it started on for its whole lifetime.
+            meta.target = match phase {
+                TouchPhase::Started => {
+                    let target = if cx.captured != Entity::null() {
+                        cx.captured
+                    } else {
+                        hit_test(cx, meta.origin, *x, *y)
+                    };
+                    cx.touches.insert(*id, target);
+                    target
+                }
+                TouchPhase::Moved => cx.touches.get(id).copied().unwrap_or(meta.origin),
+                TouchPhase::Ended | TouchPhase::Cancelled => {
+                    cx.touches.remove(id).unwrap_or(meta.origin)
+                }
+            };
+        }
+        WindowEvent::FileHover(_)
+        | WindowEvent::FileHoverCancelled
+        | WindowEvent::FileDrop { .. } => {
             meta.target = cx.hovered;
         }
-        WindowEvent::KeyDown(code, _) => {
+        WindowEvent::KeyDown(code, key, repeat) => {
             meta.target = cx.focused;
 
+            if dispatch_hotkeys(cx, *code, key.as_ref(), *repeat) {
+                meta.consume();
+                return;
+            }
+
             #[cfg(debug_assertions)]
             if *code == Code::KeyP && cx.modifiers.ctrl() {
                 for entity in TreeIterator::full(&cx.tree) {
@@ -497,79 +589,34 @@ fn internal_state_updates(cx: &mut Context, window_event: &WindowEvent, meta: &m
                 EventContext::new(cx).reload_styles().unwrap();
             }
 
-            if *code == Code::Tab {
-                let lock_focus_to = cx.tree.lock_focus_within(cx.focused);
-                if cx.modifiers.shift() {
-                    let prev_focused = if let Some(prev_focused) =
-                        focus_backward(&cx.tree, &cx.style, cx.focused, lock_focus_to)
-                    {
-                        prev_focused
-                    } else {
-                        TreeIterator::full(&cx.tree)
-                            .filter(|node| {
-                                is_navigatable(&cx.tree, &cx.style, *node, lock_focus_to)
-                            })
-                            .next_back()
-                            .unwrap_or(Entity::root())
-                    };
-
-                    if prev_focused != cx.focused {
-                        cx.set_focus_pseudo_classes(cx.focused, false, true);
-                        cx.set_focus_pseudo_classes(prev_focused, true, true);
-                        cx.event_queue.push_back(
-                            Event::new(WindowEvent::FocusOut)
-                                .target(cx.focused)
-                                .origin(Entity::root()),
-                        );
-                        cx.event_queue.push_back(
-                            Event::new(WindowEvent::FocusIn)
-                                .target(prev_focused)
-                                .origin(Entity::root()),
-                        );
-
-                        cx.focused = prev_focused;
-
-                        if let Some(pseudo_classes) = cx.style.pseudo_classes.get_mut(cx.triggered)
-                        {
-                            pseudo_classes.set(PseudoClassFlags::ACTIVE, false);
-                            cx.needs_restyle(cx.triggered);
-                        }
-                        cx.triggered = Entity::null();
-                    }
-                } else {
-                    let next_focused = if let Some(next_focused) =
-                        focus_forward(&cx.tree, &cx.style, cx.focused, lock_focus_to)
-                    {
-                        next_focused
-                    } else {
-                        TreeIterator::full(&cx.tree)
-                            .find(|node| is_navigatable(&cx.tree, &cx.style, *node, lock_focus_to))
-                            .unwrap_or(Entity::root())
-                    };
-
-                    if next_focused != cx.focused {
-                        cx.set_focus_pseudo_classes(cx.focused, false, true);
-                        cx.set_focus_pseudo_classes(next_focused, true, true);
-                        cx.event_queue.push_back(
-                            Event::new(WindowEvent::FocusOut)
-                                .target(cx.focused)
-                                .origin(Entity::root()),
-                        );
-                        cx.event_queue.push_back(
-                            Event::new(WindowEvent::FocusIn)
-                                .target(next_focused)
-                                .origin(Entity::root()),
-                        );
+            #[cfg(debug_assertions)]
+            if *code == Code::F12 {
+                cx.toggle_inspector();
+            }
 
-                        cx.focused = next_focused;
+            if *code == Code::Tab {
+                let next_focused =
+                    next_focus(&cx.tree, &cx.style, cx.focused, !cx.modifiers.shift());
+
+                if next_focused != cx.focused {
+                    cx.set_focus_pseudo_classes(cx.focused, false, true);
+                    cx.set_focus_pseudo_classes(next_focused, true, true);
+                    cx.event_queue.push_back(
+                        Event::new(WindowEvent::FocusOut).target(cx.focused).origin(Entity::root()),
+                    );
+                    cx.event_queue.push_back(
+                        Event::new(WindowEvent::FocusIn)
+                            .target(next_focused)
+                            .origin(Entity::root()),
+                    );
+
+                    cx.focused = next_focused;
 
-                        if let Some(pseudo_classes) = cx.style.pseudo_classes.get_mut(cx.triggered)
-                        {
-                            pseudo_classes.set(PseudoClassFlags::ACTIVE, false);
-                            cx.needs_restyle(cx.triggered);
-                        }
-                        cx.triggered = Entity::null();
+                    if let Some(pseudo_classes) = cx.style.pseudo_classes.get_mut(cx.triggered) {
+                        pseudo_classes.set(PseudoClassFlags::ACTIVE, false);
+                        cx.needs_restyle(cx.triggered);
                     }
+                    cx.triggered = Entity::null();
                 }
             }
 
@@ -596,7 +643,7 @@ fn internal_state_updates(cx: &mut Context, window_event: &WindowEvent, meta: &m
                 cx.triggered = Entity::null();
             }
         }
-        WindowEvent::CharInput(_) => {
+        WindowEvent::CharInput(_) | WindowEvent::ImePreedit(_, _) | WindowEvent::ImeCommit(_) => {
             meta.target = cx.focused;
         }
         WindowEvent::FocusOut => {
@@ -632,6 +679,13 @@ fn internal_state_updates(cx: &mut Context, window_event: &WindowEvent, meta: &m
     }
 }
 
+// Stops waiting for a long press without emitting one.
+fn cancel_long_press(cx: &mut Context) {
+    if cx.long_press_origin.take().is_some() {
+        cx.stop_timer(cx.long_press_timer);
+    }
+}
+
 fn mutate_direct_or_up(meta: &mut EventMeta, direct: Entity, up: Entity, root: bool) {
     if direct != Entity::null() {
         meta.target = direct;
@@ -30,6 +30,10 @@ pub struct TimerState {
     pub(crate) callback: Rc<dyn Fn(&mut EventContext, TimerAction)>,
     pub(crate) ticking: bool,
     pub(crate) stopping: bool,
+    // Whether the timer is removed when it is stopped, as for timers added with `start_interval`.
+    pub(crate) remove_on_stop: bool,
+    // Whether the timer has been removed, in which case its slot is reused by the next timer to be added.
+    pub(crate) removed: bool,
 }
 
 impl TimerState {
@@ -94,5 +98,40 @@ impl Ord for TimerState {
 }
 
 /// A handle used to start, stop, and check the running status of a timer added with `cx.add_timer()`.
+///
+/// The handle also holds the generation of the timer, so that a handle to a removed timer doesn't control a timer
+/// which is later added in its place.
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
-pub struct Timer(pub usize);
+pub struct Timer(pub usize, pub(crate) u32);
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn stopped_interval_is_removed() {
+        let mut cx = Context::default();
+
+        let interval = cx.start_interval(Duration::from_millis(10), |_| {});
+        assert!(cx.timer_is_running(interval));
+
+        cx.stop_timer(interval);
+        assert!(!cx.timer_is_running(interval));
+        assert!(cx.timers[interval.0].removed);
+
+        // The slot of the removed interval is reused rather than growing the list of timers.
+        let timer_count = cx.timers.len();
+        let timer = cx.add_timer(Duration::from_millis(10), None, |_, _| {});
+        assert_eq!(timer.0, interval.0);
+        assert_eq!(cx.timers.len(), timer_count);
+        assert!(!cx.timers[timer.0].removed);
+
+        // The stale handle of the interval doesn't control the timer which took its slot.
+        assert_ne!(timer, interval);
+        cx.start_timer(interval);
+        assert!(!cx.timer_is_running(timer));
+        cx.start_timer(timer);
+        cx.stop_timer(interval);
+        assert!(cx.timer_is_running(timer));
+    }
+}
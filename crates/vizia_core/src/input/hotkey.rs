@@ -0,0 +1,106 @@
+use std::rc::Rc;
+
+use crate::prelude::*;
+
+/// A handle to a hotkey added with [`add_hotkey`](crate::context::Context::add_hotkey), used to remove it with
+/// [`remove_hotkey`](crate::context::Context::remove_hotkey).
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub struct Hotkey(pub usize);
+
+/// The key combination which triggers a hotkey.
+#[derive(Debug, Clone, PartialEq)]
+pub enum HotkeyChord {
+    /// Matches the physical key, regardless of the keyboard layout.
+    Code(KeyChord),
+    /// Matches the logical key produced by the keyboard layout, such as [`Key::Character`]. The shift modifier is
+    /// ignored, since it is often needed to produce the character.
+    Key(Modifiers, Key),
+}
+
+impl HotkeyChord {
+    fn matches(&self, modifiers: Modifiers, code: Code, key: Option<&Key>) -> bool {
+        match self {
+            HotkeyChord::Code(chord) => *chord == KeyChord::new(modifiers, code),
+            HotkeyChord::Key(chord_modifiers, chord_key) => {
+                chord_modifiers.difference(Modifiers::SHIFT)
+                    == modifiers.difference(Modifiers::SHIFT)
+                    && key == Some(chord_key)
+            }
+        }
+    }
+}
+
+impl From<KeyChord> for HotkeyChord {
+    fn from(chord: KeyChord) -> Self {
+        HotkeyChord::Code(chord)
+    }
+}
+
+/// A hotkey registered by a view.
+#[derive(Clone)]
+pub(crate) struct HotkeyEntry {
+    pub id: Hotkey,
+    pub chord: HotkeyChord,
+    // Whether the hotkey is also triggered by key repeats while the key chord is held down.
+    pub repeat: bool,
+    // The view which added the hotkey. The hotkey only fires while keyboard focus is within this view.
+    pub scope: Entity,
+    pub callback: Rc<dyn Fn(&mut EventContext)>,
+}
+
+/// Calls the callbacks of the hotkeys matching the pressed key chord, for the innermost view containing keyboard focus
+/// which has a matching hotkey. Returns true if any hotkeys matched, even if they were skipped because the key press
+/// is a repeat.
+pub(crate) fn dispatch_hotkeys(
+    cx: &mut Context,
+    code: Code,
+    key: Option<&Key>,
+    repeat: bool,
+) -> bool {
+    let modifiers = cx.modifiers;
+
+    // Without a command modifier the key chord is text input for a focused text field, so it is left to the view.
+    let has_command_modifier =
+        modifiers.intersects(Modifiers::CTRL | Modifiers::ALT | Modifiers::SUPER);
+    let text_input_focused =
+        cx.views.get(&cx.focused).is_some_and(|view| view.accepts_text_input());
+    if text_input_focused && !has_command_modifier {
+        return false;
+    }
+
+    let focused = if cx.focused == Entity::null() { Entity::root() } else { cx.focused };
+    for scope in focused.parent_iter(&cx.tree) {
+        let hotkeys = cx
+            .hotkeys
+            .iter()
+            .filter(|hotkey| hotkey.scope == scope && hotkey.chord.matches(modifiers, code, key))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        if !hotkeys.is_empty() {
+            // A held key chord still belongs to the hotkeys, so repeats are consumed even when they are skipped.
+            for hotkey in hotkeys.into_iter().filter(|hotkey| !repeat || hotkey.repeat) {
+                (hotkey.callback)(&mut EventContext::new_with_current(cx, scope));
+            }
+
+            return true;
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn key_chord_ignores_shift() {
+        let chord = HotkeyChord::Key(Modifiers::CTRL, Key::Character("+".into()));
+        let key = Key::Character("+".into());
+
+        assert!(chord.matches(Modifiers::CTRL | Modifiers::SHIFT, Code::Equal, Some(&key)));
+        assert!(!chord.matches(Modifiers::SHIFT, Code::Equal, Some(&key)));
+        assert!(!chord.matches(Modifiers::CTRL, Code::Equal, None));
+    }
+}
@@ -178,7 +178,7 @@ where
             KeymapEvent::RemoveAction(chord, action) => self.remove(chord, action),
         });
         event.map(|window_event, _| match window_event {
-            WindowEvent::KeyDown(code, _) => {
+            WindowEvent::KeyDown(code, _, _) => {
                 if let Some(entries) = self.entries.get(&KeyChord::new(*cx.modifiers, *code)) {
                     for entry in entries {
                         (entry.on_action())(cx)
@@ -6,4 +6,7 @@ pub use keymap::*;
 mod entry;
 pub use entry::*;
 
+mod hotkey;
+pub use hotkey::*;
+
 pub use vizia_input::{Code, Key, Modifiers, MouseButton, MouseButtonData, MouseState};
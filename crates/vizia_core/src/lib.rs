@@ -55,41 +55,49 @@ pub mod backend {
 pub mod prelude {
     pub use super::binding::{
         Binding, Data, Index, Lens, LensExt, LensValue, Map, MapRef, Res, ResGet, StaticLens, Then,
-        UnwrapLens, Wrapper,
+        UnwrapLens, Wrapper, Zip,
     };
 
     pub use super::impl_res_simple;
 
-    pub use crate::model::Model;
+    pub use crate::model::{Model, UndoEvent, UndoHistory};
 
-    pub use super::animation::{Animation, AnimationBuilder, KeyframeBuilder};
+    pub use super::animation::{
+        Animation, AnimationBuilder, AnimationDirection, KeyframeBuilder, Spring, SpringAction,
+        SpringHandle,
+    };
     pub use super::context::{
         AccessContext, AccessNode, Context, ContextProxy, DataContext, DrawContext, EmitContext,
-        EventContext, ProxyEmitError, WindowState,
+        EventContext, PostProcessInfo, ProxyEmitError, TaskHandle, WindowState,
     };
     pub use super::entity::Entity;
     pub use super::environment::{AppTheme, Environment, EnvironmentEvent, ThemeMode};
     pub use super::events::{Event, Propagation, Timer, TimerAction};
     pub use super::include_style;
-    pub use super::input::{Keymap, KeymapEntry, KeymapEvent};
+    pub use super::input::{Hotkey, HotkeyChord, Keymap, KeymapEntry, KeymapEvent};
     pub use super::layout::{BoundingBox, GeoChanged};
     pub use super::localization::{Localized, ToStringLocalized};
     pub use super::modifiers::{
         AbilityModifiers, AccessibilityModifiers, ActionModifiers, LayoutModifiers,
         LinearGradientBuilder, ShadowBuilder, StyleModifiers, TextModifiers,
     };
-    pub use super::resource::{ImageId, ImageRetentionPolicy};
+    pub use super::resource::{ImageId, ImageRetentionPolicy, StyleSheetError};
     pub use super::util::{IntoCssStr, CSS};
     pub use super::view::{Handle, View};
     pub use super::views::*;
-    pub use super::window::{DropData, WindowEvent};
+    pub use super::window::{CapturedFrame, DropData, WindowEvent};
     pub use accesskit::{Action, DefaultActionVerb, Live, Role};
     pub use skia_safe::Canvas;
     pub use vizia_derive::{Data, Lens};
     pub use vizia_id::GenerationalId;
-    pub use vizia_input::{Code, Key, KeyChord, Modifiers, MouseButton, MouseButtonState};
+    pub use vizia_input::{
+        Code, Key, KeyChord, Modifiers, MouseButton, MouseButtonState, TouchPhase,
+    };
     pub use vizia_storage::{Tree, TreeExt};
-    pub use vizia_window::{WindowButtons, WindowPosition, WindowSize};
+    pub use vizia_window::{
+        AlphaMode, CursorGrabMode, FullscreenMode, MonitorInfo, PresentMode, ResizeDirection,
+        UrgencyLevel, VideoMode, WindowButtons, WindowLevel, WindowPosition, WindowSize, WorkArea,
+    };
 
     pub use super::style::*;
 
@@ -68,6 +68,16 @@
 //! # }
 //! Label::new(cx, Localized::new("welcome").arg("user", AppData::user));
 //! ```
+//!
+//! ## Translating in Code
+//! Translated text can also be looked up directly, for example when handling an event, with `translate(...)`. This
+//! returns the translation for the current locale, or the key itself if no translation is found.
+//! ```ignore
+//! # use vizia_core::prelude::*;
+//! # let mut cx = &mut Context::default();
+//! let title = cx.translate("hello-world");
+//! let welcome = cx.translate(Localized::new("welcome").arg_const("user", "Jane"));
+//! ```
 use crate::context::LocalizationContext;
 use crate::prelude::*;
 use fluent_bundle::FluentArgs;
@@ -244,30 +254,13 @@ impl ResGet<String> for Localized {
     }
 
     fn get(&self, cx: &impl DataContext) -> String {
-        let cx = cx.as_context().expect("Failed to get context");
-        let locale = &cx.environment().locale;
-        let bundle = cx.resource_manager.current_translation(locale);
-        let message = if let Some(msg) = bundle.get_message(&self.key) {
-            msg
-        } else {
-            return (self.map)(&self.key);
-        };
-
-        let value = if let Some(value) = message.value() {
-            value
-        } else {
-            return (self.map)(&self.key);
-        };
-
-        let mut err = vec![];
-        let args = self.get_args(&cx);
-        let res = bundle.format_pattern(value, Some(&args), &mut err);
+        self.to_string_local(cx)
+    }
+}
 
-        if err.is_empty() {
-            (self.map)(&res)
-        } else {
-            format!("{} {{ERROR: {:?}}}", res, err)
-        }
+impl From<&str> for Localized {
+    fn from(key: &str) -> Self {
+        Localized::new(key)
     }
 }
 
@@ -1,6 +1,7 @@
 //! Models are used to store application data and can be bound to by views to visually display the data.
 
 use std::any::{Any, TypeId};
+use std::collections::VecDeque;
 
 use crate::binding::Store;
 use crate::binding::StoreId;
@@ -168,3 +169,298 @@ impl<'a> ModelOrView<'a> {
         }
     }
 }
+
+/// Events used to undo or redo changes to model state recorded with an [`UndoHistory`].
+///
+/// These are sent with [`EventContext::undo`] and [`EventContext::redo`], or by the hotkeys added with
+/// [`Context::add_undo_hotkeys`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UndoEvent {
+    /// Reverts the most recent change.
+    Undo,
+    /// Reapplies the most recently undone change.
+    Redo,
+}
+
+/// A history of snapshots of some model state, used to undo and redo changes to it.
+///
+/// Before a change is applied to the state, such as when handling an event in [`Model::event`], call
+/// [`commit`](Self::commit) to record a snapshot of the state. [`UndoEvent`]s are applied to the state with
+/// [`handle_event`](Self::handle_event).
+///
+/// Continuous edits, such as dragging a slider, can be grouped into a single change by calling
+/// [`begin`](Self::begin) when the edit starts and [`end`](Self::end) when it finishes. Calls to `commit` in between
+/// are ignored.
+///
+/// # Examples
+///
+/// ```
+/// # use vizia_core::prelude::*;
+/// #
+/// #[derive(Clone, Data, Lens)]
+/// pub struct Document {
+///     text: String,
+/// }
+///
+/// #[derive(Lens)]
+/// pub struct AppData {
+///     document: Document,
+///     #[lens(ignore)]
+///     history: UndoHistory<Document>,
+/// }
+///
+/// enum AppEvent {
+///     SetText(String),
+/// }
+///
+/// impl Model for AppData {
+///     fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+///         self.history.handle_event(&mut self.document, event);
+///
+///         event.map(|app_event, _| match app_event {
+///             AppEvent::SetText(text) => {
+///                 self.history.commit(&self.document);
+///                 self.document.text = text.clone();
+///             }
+///         });
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct UndoHistory<T> {
+    undo_stack: VecDeque<T>,
+    redo_stack: Vec<T>,
+    limit: Option<usize>,
+    // The snapshot of the most recent commit, which is only recorded once the change is known to have had an effect.
+    pending: Option<T>,
+    // The number of nested transactions which have begun but not ended.
+    transaction_depth: usize,
+}
+
+impl<T> Default for UndoHistory<T> {
+    fn default() -> Self {
+        Self {
+            undo_stack: VecDeque::new(),
+            redo_stack: Vec::new(),
+            limit: None,
+            pending: None,
+            transaction_depth: 0,
+        }
+    }
+}
+
+impl<T: Data> UndoHistory<T> {
+    /// Creates a new empty history with no limit on the number of changes recorded.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new empty history which keeps at most `limit` changes, discarding the oldest changes first.
+    pub fn with_limit(limit: usize) -> Self {
+        Self { limit: Some(limit), ..Default::default() }
+    }
+
+    /// Records a snapshot of the state before a change is applied to it, and clears the changes which can be redone.
+    ///
+    /// The snapshot is compared with the state when the next change is committed or undone, and is discarded if the
+    /// change had no effect, so that it doesn't need to be undone. Ignored while a transaction is in progress.
+    pub fn commit(&mut self, state: &T) {
+        if self.transaction_depth > 0 {
+            return;
+        }
+
+        self.flush(state);
+        self.pending = Some(state.clone());
+        self.redo_stack.clear();
+    }
+
+    /// Begins a transaction, recording a snapshot of the state before a group of changes is applied to it. The
+    /// changes are undone together once the transaction is ended with [`end`](Self::end).
+    ///
+    /// Transactions can be nested, in which case the changes are grouped until the outermost transaction ends.
+    pub fn begin(&mut self, state: &T) {
+        if self.transaction_depth == 0 {
+            self.commit(state);
+        }
+
+        self.transaction_depth += 1;
+    }
+
+    /// Ends a transaction started with [`begin`](Self::begin). Nothing is recorded if the state is the same as when
+    /// the transaction began.
+    pub fn end(&mut self, state: &T) {
+        self.transaction_depth = self.transaction_depth.saturating_sub(1);
+        if self.transaction_depth == 0 {
+            self.flush(state);
+        }
+    }
+
+    /// Records the snapshot of the state before the most recent change if the change had an effect on the state.
+    fn flush(&mut self, state: &T) {
+        if let Some(snapshot) = self.pending.take() {
+            if !snapshot.same(state) {
+                self.undo_stack.push_back(snapshot);
+
+                if let Some(limit) = self.limit {
+                    while self.undo_stack.len() > limit {
+                        self.undo_stack.pop_front();
+                    }
+                }
+            }
+        }
+    }
+
+    /// Reverts the state to the most recent snapshot, ending any transaction in progress. Returns false if there are
+    /// no changes to undo.
+    pub fn undo(&mut self, state: &mut T) -> bool {
+        self.transaction_depth = 0;
+        self.flush(state);
+
+        if let Some(snapshot) = self.undo_stack.pop_back() {
+            self.redo_stack.push(std::mem::replace(state, snapshot));
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Reapplies the most recently undone change to the state. Returns false if there are no changes to redo.
+    pub fn redo(&mut self, state: &mut T) -> bool {
+        self.transaction_depth = 0;
+        self.flush(state);
+
+        if let Some(snapshot) = self.redo_stack.pop() {
+            self.undo_stack.push_back(std::mem::replace(state, snapshot));
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Applies an [`UndoEvent`] to the state, consuming the event if a change was undone or redone.
+    pub fn handle_event(&mut self, state: &mut T, event: &mut Event) {
+        event.map(|undo_event, meta| {
+            let changed = match undo_event {
+                UndoEvent::Undo => self.undo(state),
+                UndoEvent::Redo => self.redo(state),
+            };
+
+            if changed {
+                meta.consume();
+            }
+        });
+    }
+
+    /// Returns true if there is a change which can be undone.
+    ///
+    /// This includes the most recently committed change, even if it turns out to have had no effect on the state.
+    pub fn can_undo(&self) -> bool {
+        !self.undo_stack.is_empty() || self.pending.is_some()
+    }
+
+    /// Returns true if there is a change which can be redone.
+    pub fn can_redo(&self) -> bool {
+        !self.redo_stack.is_empty()
+    }
+
+    /// Removes all recorded changes and ends any transaction in progress.
+    pub fn clear(&mut self) {
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+        self.pending = None;
+        self.transaction_depth = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UndoHistory;
+
+    #[test]
+    fn commit_undo_redo() {
+        let mut history = UndoHistory::new();
+        let mut state = 0;
+
+        history.commit(&state);
+        state = 1;
+        history.commit(&state);
+        state = 2;
+
+        assert!(history.undo(&mut state));
+        assert_eq!(state, 1);
+        assert!(history.undo(&mut state));
+        assert_eq!(state, 0);
+        assert!(!history.undo(&mut state));
+
+        assert!(history.redo(&mut state));
+        assert_eq!(state, 1);
+        assert!(history.redo(&mut state));
+        assert_eq!(state, 2);
+        assert!(!history.redo(&mut state));
+    }
+
+    #[test]
+    fn change_without_effect_is_not_recorded() {
+        let mut history = UndoHistory::new();
+        let mut state = 0;
+
+        history.commit(&state);
+        state = 1;
+        // A change which leaves the state unchanged.
+        history.commit(&state);
+
+        assert!(history.undo(&mut state));
+        assert_eq!(state, 0);
+        assert!(!history.can_undo());
+    }
+
+    #[test]
+    fn commit_clears_redo() {
+        let mut history = UndoHistory::new();
+        let mut state = 0;
+
+        history.commit(&state);
+        state = 1;
+        history.undo(&mut state);
+        assert!(history.can_redo());
+
+        history.commit(&state);
+        state = 2;
+        assert!(!history.can_redo());
+        assert!(!history.redo(&mut state));
+        assert_eq!(state, 2);
+    }
+
+    #[test]
+    fn transaction_is_undone_as_one_change() {
+        let mut history = UndoHistory::new();
+        let mut state = 0;
+
+        history.begin(&state);
+        for value in 1..=10 {
+            history.commit(&state);
+            state = value;
+        }
+        history.end(&state);
+
+        assert!(history.undo(&mut state));
+        assert_eq!(state, 0);
+        assert!(!history.can_undo());
+    }
+
+    #[test]
+    fn limit_discards_oldest_changes() {
+        let mut history = UndoHistory::with_limit(2);
+        let mut state = 0;
+
+        for value in 1..=4 {
+            history.commit(&state);
+            state = value;
+        }
+
+        assert!(history.undo(&mut state));
+        assert!(history.undo(&mut state));
+        assert_eq!(state, 2);
+        assert!(!history.undo(&mut state));
+    }
+}
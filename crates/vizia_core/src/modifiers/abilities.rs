@@ -117,6 +117,34 @@ pub trait AbilityModifiers: internal::Modifiable {
 
         self
     }
+
+    /// Sets the position of the view in the keyboard focus order, overriding the tree order.
+    ///
+    /// Views with a positive focus order are visited first with `tab`, in ascending order, followed by views with
+    /// a focus order of zero or no focus order in tree order. Views with a negative focus order can still be
+    /// focused, such as by clicking them, but are skipped by keyboard navigation.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// HStack::new(cx, |cx| {
+    ///     Button::new(cx, |cx| Label::new(cx, "Second")).focus_order(2);
+    ///     Button::new(cx, |cx| Label::new(cx, "First")).focus_order(1);
+    /// });
+    /// ```
+    fn focus_order(mut self, order: impl Res<i32>) -> Self {
+        let entity = self.entity();
+        let current = self.current();
+        self.context().with_current(current, move |cx| {
+            order.set_or_bind(cx, entity, move |cx, v| {
+                let order = v.get(cx);
+                cx.style.focus_order.insert(entity, order);
+            });
+        });
+
+        self
+    }
 }
 
 impl<'a, V> AbilityModifiers for Handle<'a, V> {}
@@ -28,6 +28,21 @@ pub trait AccessibilityModifiers: internal::Modifiable {
         self
     }
 
+    /// Sets the accessibility description of the view, which gives assistive technologies additional information
+    /// about the view beyond its name.
+    fn description<U: ToStringLocalized>(mut self, description: impl Res<U>) -> Self {
+        let entity = self.entity();
+        let current = self.current();
+        self.context().with_current(current, move |cx| {
+            description.set_or_bind(cx, entity, move |cx, description| {
+                cx.style.description.insert(entity, description.get(cx).to_string_local(cx));
+                cx.style.needs_access_update(entity);
+            });
+        });
+
+        self
+    }
+
     /// Sets the accessibility default action for the view.
     fn default_action_verb(mut self, action_verb: DefaultActionVerb) -> Self {
         let id = self.entity();
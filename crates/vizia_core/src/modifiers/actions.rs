@@ -1,3 +1,4 @@
+use crate::events::TimedEventHandle;
 use crate::prelude::*;
 use std::any::TypeId;
 
@@ -5,6 +6,25 @@ use std::any::TypeId;
 pub struct ModalModel {
     pub tooltip_visible: bool,
     pub menu_visible: bool,
+    // The pending event which will show or hide the tooltip after the configured delay.
+    #[lens(ignore)]
+    tooltip_timeout: Option<TimedEventHandle>,
+}
+
+impl ModalModel {
+    // Shows or hides the tooltip after the given delay, replacing any pending change.
+    fn set_tooltip_visible(&mut self, cx: &mut EventContext, visible: bool, delay: Duration) {
+        if let Some(timeout) = self.tooltip_timeout.take() {
+            cx.cancel_scheduled(timeout);
+        }
+
+        if delay.is_zero() {
+            self.tooltip_visible = visible;
+        } else if visible != self.tooltip_visible {
+            let event = if visible { ModalEvent::ShowTooltip } else { ModalEvent::HideTooltip };
+            self.tooltip_timeout = Some(cx.schedule_emit(event, Instant::now() + delay));
+        }
+    }
 }
 
 pub enum ModalEvent {
@@ -15,13 +35,15 @@ pub enum ModalEvent {
 }
 
 impl Model for ModalModel {
-    fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
         event.map(|modal_event, _| match modal_event {
             ModalEvent::ShowTooltip => {
+                self.tooltip_timeout = None;
                 self.tooltip_visible = true;
             }
 
             ModalEvent::HideTooltip => {
+                self.tooltip_timeout = None;
                 self.tooltip_visible = false;
             }
 
@@ -35,9 +57,17 @@ impl Model for ModalModel {
         });
 
         event.map(|window_event, _| match window_event {
-            WindowEvent::MouseOver => self.tooltip_visible = true,
-            WindowEvent::MouseOut => self.tooltip_visible = false,
-            WindowEvent::PressDown { mouse: _ } => self.tooltip_visible = false,
+            WindowEvent::MouseOver => {
+                let delay = cx.tooltip_config().show_delay;
+                self.set_tooltip_visible(cx, true, delay);
+            }
+            WindowEvent::MouseOut => {
+                let delay = cx.tooltip_config().hide_delay;
+                self.set_tooltip_visible(cx, false, delay);
+            }
+            WindowEvent::PressDown { mouse: _ } => {
+                self.set_tooltip_visible(cx, false, Duration::ZERO);
+            }
             _ => {}
         });
     }
@@ -498,7 +528,8 @@ fn build_modal_model(cx: &mut Context, entity: Entity) {
         .is_none()
     {
         cx.with_current(entity, |cx| {
-            ModalModel { tooltip_visible: false, menu_visible: false }.build(cx);
+            ModalModel { tooltip_visible: false, menu_visible: false, tooltip_timeout: None }
+                .build(cx);
         });
     }
 }
@@ -516,7 +547,7 @@ impl<'a, V: View> ActionModifiers<V> for Handle<'a, V> {
                         cx.play_animation(
                             "tooltip_fade",
                             Duration::from_millis(100),
-                            Duration::from_millis(500),
+                            Duration::ZERO,
                         )
                     });
                 }
@@ -56,6 +56,29 @@ pub trait LayoutModifiers: internal::Modifiable {
         SystemFlags::RELAYOUT
     );
 
+    modifier!(
+        /// Sets the horizontal direction of the view and its descendants.
+        ///
+        /// When set to `Direction::Rtl` the children of the view are mirrored horizontally, so that rows are laid out
+        /// from right to left, the left and right spacing of each child is swapped, and horizontal scrollbars start
+        /// from the right. Text which does not specify a `text_align` is aligned to the start of the line in the
+        /// given direction. The direction is inherited by descendants unless overridden.
+        ///
+        /// # Example
+        /// ```
+        /// # use vizia_core::prelude::*;
+        /// # let cx = &mut Context::default();
+        /// HStack::new(cx, |cx| {
+        ///     Label::new(cx, "First");
+        ///     Label::new(cx, "Second");
+        /// })
+        /// .direction(Direction::Rtl);
+        /// ```
+        direction,
+        Direction,
+        SystemFlags::RELAYOUT | SystemFlags::REFLOW
+    );
+
     modifier!(
         /// Sets the space on the left side of the view.
         ///
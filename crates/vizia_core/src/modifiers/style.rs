@@ -104,6 +104,7 @@ pub trait StyleModifiers: internal::Modifiable {
                     pseudo_classes.set(PseudoClassFlags::CHECKED, val);
                 }
                 cx.needs_restyle(entity);
+                cx.style.needs_access_update(entity);
             });
         });
 
@@ -10,7 +10,9 @@ pub trait TextModifiers: internal::Modifiable {
         self.context().with_current(current, |cx| {
             value.set_or_bind(cx, entity, move |cx, val| {
                 let cx: &mut EventContext<'_> = &mut EventContext::new_with_current(cx, entity);
-                let text_data = val.get(cx).to_string_local(cx);
+                // Missing data, such as an item which has been removed from a list, shows no text.
+                let text_data =
+                    val.get_ref(cx).map(|val| val.to_string_local(cx)).unwrap_or_default();
                 // cx.text_context.set_text(entity, &text_data);
                 cx.style.text.insert(entity, text_data);
 
@@ -115,6 +117,20 @@ pub trait TextModifiers: internal::Modifiable {
         SystemFlags::REFLOW
     );
 
+    modifier!(
+        /// Sets the height of each line of text within the view, either as a multiple of the font size or as a fixed length.
+        line_height,
+        LineHeight,
+        SystemFlags::REFLOW
+    );
+
+    modifier!(
+        /// Sets the additional spacing between the characters of text within the view.
+        letter_spacing,
+        Length,
+        SystemFlags::REFLOW
+    );
+
     modifier!(
         /// Sets the text overflow.
         text_overflow,
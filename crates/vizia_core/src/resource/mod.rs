@@ -3,6 +3,11 @@
 mod image_id;
 
 pub use image_id::ImageId;
+
+mod stylesheet;
+pub use stylesheet::StyleSheetError;
+#[cfg(feature = "hot-reload")]
+pub(crate) use stylesheet::{WatchedCss, WatchedStyleSheet};
 use vizia_id::{GenerationalId, IdManager};
 
 use crate::context::ResourceContext;
@@ -38,6 +43,8 @@ pub enum ImageRetentionPolicy {
 pub struct ResourceManager {
     pub themes: Vec<String>, // Themes are the string content stylesheets
     pub styles: Vec<Box<dyn IntoCssStr>>,
+    #[cfg(feature = "hot-reload")]
+    pub(crate) watched_stylesheets: Vec<WatchedStyleSheet>,
 
     pub(crate) image_id_manager: IdManager<ImageId>,
     pub(crate) images: HashMap<ImageId, StoredImage>,
@@ -113,6 +120,8 @@ impl ResourceManager {
             images,
             image_ids: HashMap::new(),
             styles: Vec::new(),
+            #[cfg(feature = "hot-reload")]
+            watched_stylesheets: Vec::new(),
 
             translations: HashMap::from([(
                 LanguageIdentifier::default(),
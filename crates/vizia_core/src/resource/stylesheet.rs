@@ -0,0 +1,93 @@
+use std::{fmt, path::PathBuf};
+
+#[cfg(feature = "hot-reload")]
+use std::{
+    cell::RefCell,
+    rc::Rc,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+#[cfg(feature = "hot-reload")]
+use crate::{context::EventContext, util::IntoCssStr};
+
+/// An error produced when a watched stylesheet could not be read or parsed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StyleSheetError {
+    /// The path of the stylesheet.
+    pub path: PathBuf,
+    /// A description of the error.
+    pub message: String,
+}
+
+impl fmt::Display for StyleSheetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path.display(), self.message)
+    }
+}
+
+impl std::error::Error for StyleSheetError {}
+
+/// A stylesheet loaded from a file which is reloaded whenever the file changes.
+#[cfg(feature = "hot-reload")]
+pub(crate) struct WatchedStyleSheet {
+    pub(crate) path: PathBuf,
+    // The contents of the file the last time it was parsed successfully.
+    pub(crate) contents: Rc<RefCell<String>>,
+    // Set by the watcher when the file changes, and cleared once the file has been re-read.
+    pub(crate) changed: Arc<AtomicBool>,
+    pub(crate) on_error: Rc<dyn Fn(&mut EventContext, StyleSheetError)>,
+    // Dropping the watcher stops watching the file.
+    pub(crate) _watcher: Option<notify::RecommendedWatcher>,
+}
+
+#[cfg(feature = "hot-reload")]
+impl WatchedStyleSheet {
+    /// Re-reads the stylesheet from its file if it has changed since it was last read, keeping the previous
+    /// contents if the file can't be read or parsed.
+    ///
+    /// An error is only returned once per change to the file.
+    pub(crate) fn refresh(&self) -> Result<(), StyleSheetError> {
+        if !self.changed.swap(false, Ordering::AcqRel) {
+            return Ok(());
+        }
+
+        let error = |message: String| StyleSheetError { path: self.path.clone(), message };
+
+        let contents = std::fs::read_to_string(&self.path).map_err(|err| error(err.to_string()))?;
+
+        // Some editors truncate the file before writing it, in which case the write is reported as a further change.
+        if contents.trim().is_empty() && !self.contents.borrow().is_empty() {
+            return Ok(());
+        }
+
+        let filename = self.path.to_string_lossy();
+        if let Err(err) = vizia_style::StyleSheet::parse(
+            &filename,
+            &contents,
+            vizia_style::ParserOptions::default(),
+        ) {
+            return Err(error(match err.location {
+                Some(location) => format!("{:?} at {}", err.kind, location),
+                None => format!("{:?}", err.kind),
+            }));
+        }
+
+        *self.contents.borrow_mut() = contents;
+
+        Ok(())
+    }
+}
+
+/// The contents of a watched stylesheet, as added to the stylesheets of the application.
+#[cfg(feature = "hot-reload")]
+pub(crate) struct WatchedCss(pub(crate) Rc<RefCell<String>>);
+
+#[cfg(feature = "hot-reload")]
+impl IntoCssStr for WatchedCss {
+    fn get_style(&self) -> Result<String, std::io::Error> {
+        Ok(self.0.borrow().clone())
+    }
+}
@@ -1,5 +1,6 @@
 use crate::animation::{AnimationState, Interpolator};
 use crate::prelude::*;
+use crate::style::PropertyTarget;
 use vizia_storage::{SparseSet, SparseSetGeneric, SparseSetIndex};
 
 const INDEX_MASK: u32 = u32::MAX / 4;
@@ -291,6 +292,14 @@ where
         self.shared_data.insert(rule, value);
     }
 
+    /// Inserts a value either as shared data for a rule or as inline data of an entity.
+    pub(crate) fn insert_target(&mut self, target: PropertyTarget, value: T) {
+        match target {
+            PropertyTarget::Rule(rule) => self.insert_rule(rule, value),
+            PropertyTarget::Entity(entity) => self.insert(entity, value),
+        }
+    }
+
     // pub(crate) fn remove_rule(&mut self, rule: Rule) -> Option<T> {
     //     self.shared_data.remove(rule)
     // }
@@ -311,7 +320,7 @@ where
         &mut self,
         entity: Entity,
         animation: Animation,
-        start_time: Instant,
+        start_time: Option<Instant>,
         duration: Duration,
         delay: Duration,
     ) {
@@ -377,13 +386,17 @@ where
                     .value
                     .clone(),
             );
-            anim_state.play(entity);
+            anim_state.play(entity, start_time);
             self.inline_data.sparse[entity_index].anim_index = self.active_animations.len() as u32;
             self.active_animations.push(anim_state);
         }
     }
 
-    pub fn tick(&mut self, time: Instant) -> Vec<Entity> {
+    /// Advances the active animations to the given time, returning the entities whose animated values changed.
+    ///
+    /// If `reduce_motion` is true, every animation is finished immediately. Animations which repeat indefinitely
+    /// are held at the end of their first iteration.
+    pub fn tick(&mut self, time: Instant, reduce_motion: bool) -> Vec<Entity> {
         self.remove_innactive_animations();
 
         if self.has_animations() {
@@ -398,9 +411,30 @@ where
                     continue;
                 }
 
-                let elapsed_time = time.duration_since(state.start_time);
-                let mut normalised_time =
-                    (elapsed_time.as_secs_f32() / state.duration.as_secs_f32()) - state.dt;
+                // Animations started outside of a frame, such as transitions, start at the time of this frame.
+                let start_time = *state.start_time.get_or_insert(time);
+                let elapsed_time = time.saturating_duration_since(start_time);
+                let progress = ((elapsed_time.as_secs_f32() / state.duration.as_secs_f32())
+                    - state.dt)
+                    .max(0.0);
+
+                // Split the progress into the current iteration and how far through that iteration the animation is,
+                // holding the animation at the end of its last iteration once it has finished.
+                let finished = reduce_motion || progress >= state.iterations;
+                let (iteration, iteration_time) = if finished && !state.iterations.is_finite() {
+                    (0.0, 1.0)
+                } else if finished {
+                    let last = state.iterations.ceil().max(1.0) - 1.0;
+                    (last, (state.iterations - last).min(1.0))
+                } else {
+                    (progress.floor(), progress.fract())
+                };
+
+                let mut normalised_time = if state.direction.is_reversed(iteration as u32) {
+                    1.0 - iteration_time
+                } else {
+                    iteration_time
+                };
 
                 normalised_time = normalised_time.clamp(0.0, 1.0);
 
@@ -415,7 +449,14 @@ where
                 let normalised_elapsed_time =
                     (normalised_time - start.time) / (end.time - start.time);
 
-                state.t = normalised_time;
+                // The animation is only complete once its last iteration has finished.
+                state.t = if finished {
+                    1.0
+                } else if state.iterations.is_finite() {
+                    (progress / state.iterations).min(1.0 - f32::EPSILON)
+                } else {
+                    0.0
+                };
 
                 let timing_t = start.timing_function.value(normalised_elapsed_time);
                 state.output = Some(T::interpolate(&start.value, &end.value, timing_t));
@@ -485,6 +526,7 @@ where
             let anim_index = self.inline_data.sparse[entity_index].anim_index as usize;
             if anim_index < self.active_animations.len()
                 && self.active_animations[anim_index].id == animation
+                && self.active_animations[anim_index].t < 1.0
             {
                 return true;
             }
@@ -624,7 +666,7 @@ where
                                         .clone();
 
                                 current_anim_state.dt = current_anim_state.t - 1.0;
-                                current_anim_state.start_time = Instant::now();
+                                current_anim_state.start_time = None;
                             } else {
                                 // Transitioning to new rule
                                 current_anim_state.to_rule = rule_data_index;
@@ -635,7 +677,7 @@ where
                                         .value
                                         .clone();
                                 current_anim_state.t = 0.0;
-                                current_anim_state.start_time = Instant::now();
+                                current_anim_state.start_time = None;
                             }
                         }
                     }
@@ -666,13 +708,7 @@ where
                     if transition_state.from_rule != DataIndex::null().index()
                         && transition_state.from_rule != transition_state.to_rule
                     {
-                        self.play_animation(
-                            entity,
-                            rule_animation,
-                            Instant::now(),
-                            duration,
-                            delay,
-                        );
+                        self.play_animation(entity, rule_animation, None, duration, delay);
                     }
                     //}
                 }
@@ -727,6 +763,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::animation::{Keyframe, TimingFunction};
 
     // DataIndex tests
 
@@ -795,4 +832,44 @@ mod tests {
         animatable_storage.insert(Entity::root(), 5.0);
         //assert_eq!(animatable_storage.entity_indices.first().unwrap().data_index, DataIndex::inline(0));
     }
+
+    /// Test that reduced motion holds an infinitely repeating animation at its last keyframe.
+    #[test]
+    fn reduced_motion_finishes_infinite_animation() {
+        let mut animatable_storage = AnimatableSet::<f32>::default();
+        let animation = Animation::new(0, 0);
+        let mut animation_state = AnimationState::new(animation)
+            .with_duration(Duration::from_secs(1))
+            .with_keyframe(Keyframe {
+                time: 0.0,
+                value: 0.0,
+                timing_function: TimingFunction::linear(),
+            })
+            .with_keyframe(Keyframe {
+                time: 1.0,
+                value: 10.0,
+                timing_function: TimingFunction::linear(),
+            });
+        animation_state.iterations = f32::INFINITY;
+        animation_state.persistent = true;
+        animatable_storage.insert_animation(animation, animation_state);
+
+        let start_time = Instant::now();
+        animatable_storage.play_animation(
+            Entity::root(),
+            animation,
+            Some(start_time),
+            Duration::from_secs(1),
+            Duration::ZERO,
+        );
+
+        animatable_storage.tick(start_time + Duration::from_millis(250), false);
+        let value = *animatable_storage.get(Entity::root()).unwrap();
+        assert!(value > 0.0 && value < 10.0);
+        assert!(animatable_storage.has_animations());
+
+        animatable_storage.tick(start_time + Duration::from_millis(250), true);
+        assert_eq!(animatable_storage.get(Entity::root()), Some(&10.0));
+        assert!(!animatable_storage.has_animations());
+    }
 }
@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use crate::style::PropertyTarget;
 
 use vizia_storage::{SparseSetGeneric, SparseSetIndex};
 
@@ -251,6 +252,14 @@ where
         self.shared_data.insert(rule, value);
     }
 
+    /// Inserts a value either as shared data for a rule or as inline data of an entity.
+    pub(crate) fn insert_target(&mut self, target: PropertyTarget, value: T) {
+        match target {
+            PropertyTarget::Rule(rule) => self.insert_rule(rule, value),
+            PropertyTarget::Entity(entity) => self.insert(entity, value),
+        }
+    }
+
     // pub(crate) fn remove_rule(&mut self, rule: Rule) -> Option<T> {
     //     self.shared_data.remove(rule)
     // }
@@ -0,0 +1,144 @@
+use crate::prelude::*;
+
+macro_rules! get_copied_property {
+    (
+        $(#[$meta:meta])*
+        $name:ident: $ty:ty
+    ) => {
+        $(#[$meta])*
+        pub fn $name(&self) -> $ty {
+            self.style.$name.get(self.entity).copied().unwrap_or_default()
+        }
+    };
+}
+
+macro_rules! get_color_property {
+    (
+        $(#[$meta:meta])*
+        $name:ident
+    ) => {
+        $(#[$meta])*
+        pub fn $name(&self) -> Color {
+            self.style.$name.get(self.entity).copied().unwrap_or(Color::transparent())
+        }
+    };
+}
+
+macro_rules! get_units_property {
+    (
+        $(#[$meta:meta])*
+        $name:ident
+    ) => {
+        $(#[$meta])*
+        pub fn $name(&self) -> Option<Units> {
+            self.style.$name.get(self.entity).copied()
+        }
+    };
+}
+
+/// The computed style of a view, after stylesheets, inline properties, inheritance and animations have been
+/// applied.
+///
+/// Returned by [`Context::computed_style`] and [`EventContext::computed_style`]. Lengths and units are in logical
+/// pixels, as they are specified in stylesheets.
+pub struct ComputedStyle<'a> {
+    style: &'a Style,
+    entity: Entity,
+}
+
+impl<'a> ComputedStyle<'a> {
+    pub(crate) fn new(style: &'a Style, entity: Entity) -> Self {
+        Self { style, entity }
+    }
+
+    /// Returns the entity of the view.
+    pub fn entity(&self) -> Entity {
+        self.entity
+    }
+
+    get_copied_property!(
+        /// Returns the display type of the view.
+        display: Display
+    );
+
+    get_copied_property!(
+        /// Returns the visibility of the view.
+        visibility: Visibility
+    );
+
+    /// Returns the opacity of the view, between 0.0 (transparent) and 1.0 (opaque).
+    pub fn opacity(&self) -> f32 {
+        self.style.opacity.get(self.entity).copied().unwrap_or_default().0
+    }
+
+    get_color_property!(
+        /// Returns the background color of the view, which is transparent if none is set.
+        background_color
+    );
+
+    get_color_property!(
+        /// Returns the border color of the view, which is transparent if none is set.
+        border_color
+    );
+
+    /// Returns the border width of the view.
+    pub fn border_width(&self) -> LengthOrPercentage {
+        self.style.border_width.get(self.entity).cloned().unwrap_or_default()
+    }
+
+    get_color_property!(
+        /// Returns the outline color of the view, which is transparent if none is set.
+        outline_color
+    );
+
+    /// Returns the font color of the view, including any color inherited from its ancestors.
+    pub fn font_color(&self) -> Color {
+        self.style.font_color.get(self.entity).copied().unwrap_or(Color::black())
+    }
+
+    /// Returns the font size of the view in logical pixels, including any size inherited from its ancestors.
+    pub fn font_size(&self) -> f32 {
+        self.style.font_size.get(self.entity).copied().map(|size| size.0).unwrap_or(16.0)
+    }
+
+    get_copied_property!(
+        /// Returns the font weight of the view, including any weight inherited from its ancestors.
+        font_weight: FontWeight
+    );
+
+    /// Returns the font families of the view, including any families inherited from its ancestors, or `None` if
+    /// the default font is used.
+    pub fn font_family(&self) -> Option<&[FamilyOwned]> {
+        self.style.font_family.get(self.entity).map(|families| families.as_slice())
+    }
+
+    get_units_property!(
+        /// Returns the width of the view, or `None` if it has not been set.
+        width
+    );
+
+    get_units_property!(
+        /// Returns the height of the view, or `None` if it has not been set.
+        height
+    );
+
+    get_units_property!(
+        /// Returns the left space of the view, or `None` if it has not been set.
+        left
+    );
+
+    get_units_property!(
+        /// Returns the right space of the view, or `None` if it has not been set.
+        right
+    );
+
+    get_units_property!(
+        /// Returns the top space of the view, or `None` if it has not been set.
+        top
+    );
+
+    get_units_property!(
+        /// Returns the bottom space of the view, or `None` if it has not been set.
+        bottom
+    );
+}
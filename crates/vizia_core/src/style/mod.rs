@@ -70,17 +70,18 @@ use crate::prelude::*;
 
 pub use vizia_style::{
     Angle, BackgroundImage, BackgroundSize, BorderStyleKeyword, ClipPath, Color, CornerShape,
-    CssRule, CursorIcon, Display, Filter, FontFamily, FontSize, FontSlant, FontVariation,
-    FontWeight, FontWeightKeyword, FontWidth, GenericFontFamily, Gradient, HorizontalPosition,
-    HorizontalPositionKeyword, Length, LengthOrPercentage, LengthValue, LineClamp, LineDirection,
-    LinearGradient, Matrix, Opacity, Overflow, PointerEvents, Position, Scale, Shadow, TextAlign,
-    TextDecorationLine, TextDecorationStyle, TextOverflow, Transform, Transition, Translate,
-    VerticalPosition, VerticalPositionKeyword, Visibility, RGBA,
+    CssRule, CursorIcon, Direction, Display, EasingFunction, Filter, FontFamily, FontSize,
+    FontSlant, FontVariation, FontWeight, FontWeightKeyword, FontWidth, GenericFontFamily,
+    Gradient, HorizontalPosition, HorizontalPositionKeyword, Length, LengthOrPercentage,
+    LengthValue, LineClamp, LineDirection, LineHeight, LinearGradient, Matrix, Opacity, Overflow,
+    PointerEvents, Position, Property, Scale, Shadow, TextAlign, TextDecorationLine,
+    TextDecorationStyle, TextOverflow, Transform, Transition, Translate, VerticalPosition,
+    VerticalPositionKeyword, Visibility, RGBA,
 };
 
 use vizia_style::{
-    BlendMode, EasingFunction, KeyframeSelector, ParserOptions, Property, SelectorList, Selectors,
-    StyleSheet,
+    selectors::parser::Component, BlendMode, KeyframeSelector, ParserOptions, SelectorList,
+    Selectors, StyleSheet, TokenList,
 };
 
 mod rule;
@@ -92,7 +93,17 @@ pub(crate) use pseudoclass::*;
 mod transform;
 pub(crate) use transform::*;
 
-use crate::animation::{AnimationState, Interpolator, Keyframe, TimingFunction};
+mod computed;
+pub use computed::ComputedStyle;
+
+/// Where a style property is inserted, either as shared data for a stylesheet rule or as inline data of an entity.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum PropertyTarget {
+    Rule(Rule),
+    Entity(Entity),
+}
+
+use crate::animation::{AnimationState, Interpolator, Keyframe, KeyframeTiming, TimingFunction};
 use crate::storage::animatable_set::AnimatableSet;
 use crate::storage::style_set::StyleSet;
 use bitflags::bitflags;
@@ -197,6 +208,7 @@ pub struct Style {
     pub(crate) animations: HashMap<String, Animation>,
     // List of animations to be started on the next frame
     pub(crate) pending_animations: Vec<(Entity, Animation, Duration, Duration)>,
+    pub(crate) playing_animations: Vec<(Entity, Animation)>,
 
     // List of rules
     pub(crate) rules: IndexMap<Rule, SelectorList<Selectors>>,
@@ -209,6 +221,7 @@ pub struct Style {
     pub(crate) pseudo_classes: SparseSet<PseudoClassFlags>,
     pub(crate) disabled: StyleSet<bool>,
     pub(crate) abilities: SparseSet<Abilities>,
+    pub(crate) focus_order: SparseSet<i32>,
 
     // Accessibility Properties
     pub name: StyleSet<String>,
@@ -218,6 +231,7 @@ pub struct Style {
     pub labelled_by: SparseSet<Entity>,
     pub hidden: SparseSet<bool>,
     pub text_value: SparseSet<String>,
+    pub description: SparseSet<String>,
     pub numeric_value: SparseSet<f64>,
 
     // Visibility
@@ -290,6 +304,8 @@ pub struct Style {
     pub(crate) text_overflow: StyleSet<TextOverflow>,
     pub(crate) line_clamp: StyleSet<LineClamp>,
     pub(crate) text_align: StyleSet<TextAlign>,
+    pub(crate) line_height: StyleSet<LineHeight>,
+    pub(crate) letter_spacing: StyleSet<Length>,
     pub(crate) text_decoration_line: StyleSet<TextDecorationLine>,
     pub(crate) underline_style: StyleSet<TextDecorationLine>,
     pub(crate) overline_style: StyleSet<TextDecorationStyle>,
@@ -325,6 +341,9 @@ pub struct Style {
     // Position Type
     pub(crate) position_type: StyleSet<PositionType>,
 
+    // Direction
+    pub(crate) direction: StyleSet<Direction>,
+
     // Spacing
     pub(crate) left: AnimatableSet<Units>,
     pub(crate) right: AnimatableSet<Units>,
@@ -371,9 +390,45 @@ pub struct Style {
 
     /// This includes both the system's HiDPI scaling factor as well as `cx.user_scale_factor`.
     pub(crate) dpi_factor: f64,
+
+    /// Whether the system requests reduced motion.
+    pub(crate) reduced_motion: bool,
+    /// Whether animations should ignore the system request for reduced motion.
+    pub(crate) ignore_reduced_motion: bool,
+
+    /// Values of custom properties set at runtime, which override any values declared in stylesheets.
+    pub(crate) style_variables: HashMap<String, String>,
+}
+
+// The maximum depth of nested variable references, which also stops cyclic references.
+const MAX_VARIABLE_DEPTH: usize = 16;
+
+/// Resolves the value of a custom property, substituting any variables it references.
+fn resolve_variable(
+    name: &str,
+    variables: &HashMap<String, TokenList>,
+    overrides: &HashMap<String, String>,
+    depth: usize,
+) -> Option<String> {
+    if let Some(value) = overrides.get(name) {
+        return Some(value.clone());
+    }
+
+    if depth >= MAX_VARIABLE_DEPTH {
+        return None;
+    }
+
+    variables
+        .get(name)?
+        .to_css_string(&|name| resolve_variable(name, variables, overrides, depth + 1))
 }
 
 impl Style {
+    /// Returns true if animations and transitions should skip straight to their final values.
+    pub(crate) fn should_reduce_motion(&self) -> bool {
+        self.reduced_motion && !self.ignore_reduced_motion
+    }
+
     pub fn scale_factor(&self) -> f32 {
         self.dpi_factor as f32
     }
@@ -400,21 +455,24 @@ impl Style {
     pub(crate) fn add_keyframe(
         &mut self,
         animation_id: Animation,
-        time: f32,
+        timing: &KeyframeTiming,
         properties: &[Property],
     ) {
         fn insert_keyframe<T: 'static + Interpolator + Debug + Clone + PartialEq + Default>(
             storage: &mut AnimatableSet<T>,
             animation_id: Animation,
-            time: f32,
+            timing: &KeyframeTiming,
             value: T,
         ) {
-            let keyframe = Keyframe { time, value, timing_function: TimingFunction::linear() };
+            let keyframe =
+                Keyframe { time: timing.time, value, timing_function: timing.timing_function };
 
             if let Some(anim_state) = storage.get_animation_mut(animation_id) {
                 anim_state.keyframes.push(keyframe)
             } else {
-                let anim_state = AnimationState::new(animation_id).with_keyframe(keyframe);
+                let mut anim_state = AnimationState::new(animation_id).with_keyframe(keyframe);
+                anim_state.iterations = timing.iterations;
+                anim_state.direction = timing.direction;
                 storage.insert_animation(animation_id, anim_state);
             }
         }
@@ -423,39 +481,39 @@ impl Style {
             match property {
                 // DISPLAY
                 Property::Display(value) => {
-                    insert_keyframe(&mut self.display, animation_id, time, *value);
+                    insert_keyframe(&mut self.display, animation_id, timing, *value);
                 }
 
                 Property::Opacity(value) => {
-                    insert_keyframe(&mut self.opacity, animation_id, time, *value);
+                    insert_keyframe(&mut self.opacity, animation_id, timing, *value);
                 }
 
                 Property::ClipPath(value) => {
-                    insert_keyframe(&mut self.clip_path, animation_id, time, value.clone());
+                    insert_keyframe(&mut self.clip_path, animation_id, timing, value.clone());
                 }
 
                 // TRANSFORM
                 Property::Transform(value) => {
-                    insert_keyframe(&mut self.transform, animation_id, time, value.clone());
+                    insert_keyframe(&mut self.transform, animation_id, timing, value.clone());
                 }
 
                 Property::TransformOrigin(transform_origin) => {
                     let x = transform_origin.x.to_length_or_percentage();
                     let y = transform_origin.y.to_length_or_percentage();
                     let value = Translate { x, y };
-                    insert_keyframe(&mut self.transform_origin, animation_id, time, value);
+                    insert_keyframe(&mut self.transform_origin, animation_id, timing, value);
                 }
 
                 Property::Translate(value) => {
-                    insert_keyframe(&mut self.translate, animation_id, time, value.clone());
+                    insert_keyframe(&mut self.translate, animation_id, timing, value.clone());
                 }
 
                 Property::Rotate(value) => {
-                    insert_keyframe(&mut self.rotate, animation_id, time, *value);
+                    insert_keyframe(&mut self.rotate, animation_id, timing, *value);
                 }
 
                 Property::Scale(value) => {
-                    insert_keyframe(&mut self.scale, animation_id, time, *value);
+                    insert_keyframe(&mut self.scale, animation_id, timing, *value);
                 }
 
                 // BORDER
@@ -463,20 +521,20 @@ impl Style {
                     insert_keyframe(
                         &mut self.border_width,
                         animation_id,
-                        time,
+                        timing,
                         value.left.0.clone(),
                     );
                 }
 
                 Property::BorderColor(value) => {
-                    insert_keyframe(&mut self.border_color, animation_id, time, *value);
+                    insert_keyframe(&mut self.border_color, animation_id, timing, *value);
                 }
 
                 Property::CornerTopLeftRadius(value) => {
                     insert_keyframe(
                         &mut self.corner_top_left_radius,
                         animation_id,
-                        time,
+                        timing,
                         value.clone(),
                     );
                 }
@@ -485,7 +543,7 @@ impl Style {
                     insert_keyframe(
                         &mut self.corner_top_right_radius,
                         animation_id,
-                        time,
+                        timing,
                         value.clone(),
                     );
                 }
@@ -494,7 +552,7 @@ impl Style {
                     insert_keyframe(
                         &mut self.corner_bottom_left_radius,
                         animation_id,
-                        time,
+                        timing,
                         value.clone(),
                     );
                 }
@@ -503,7 +561,7 @@ impl Style {
                     insert_keyframe(
                         &mut self.corner_bottom_right_radius,
                         animation_id,
-                        time,
+                        timing,
                         value.clone(),
                     );
                 }
@@ -513,22 +571,22 @@ impl Style {
                     insert_keyframe(
                         &mut self.outline_width,
                         animation_id,
-                        time,
+                        timing,
                         value.left.0.clone(),
                     );
                 }
 
                 Property::OutlineColor(value) => {
-                    insert_keyframe(&mut self.outline_color, animation_id, time, *value);
+                    insert_keyframe(&mut self.outline_color, animation_id, timing, *value);
                 }
 
                 Property::OutlineOffset(value) => {
-                    insert_keyframe(&mut self.outline_offset, animation_id, time, value.clone());
+                    insert_keyframe(&mut self.outline_offset, animation_id, timing, value.clone());
                 }
 
                 // BACKGROUND
                 Property::BackgroundColor(value) => {
-                    insert_keyframe(&mut self.background_color, animation_id, time, *value);
+                    insert_keyframe(&mut self.background_color, animation_id, timing, *value);
                 }
 
                 Property::BackgroundImage(images) => {
@@ -544,142 +602,142 @@ impl Style {
                             }
                         })
                         .collect::<Vec<_>>();
-                    insert_keyframe(&mut self.background_image, animation_id, time, images);
+                    insert_keyframe(&mut self.background_image, animation_id, timing, images);
                 }
 
                 Property::BackgroundSize(value) => {
-                    insert_keyframe(&mut self.background_size, animation_id, time, value.clone());
+                    insert_keyframe(&mut self.background_size, animation_id, timing, value.clone());
                 }
 
                 // BOX SHADOW
                 Property::Shadow(value) => {
-                    insert_keyframe(&mut self.shadow, animation_id, time, value.clone());
+                    insert_keyframe(&mut self.shadow, animation_id, timing, value.clone());
                 }
 
                 // TEXT
                 Property::FontColor(value) => {
-                    insert_keyframe(&mut self.font_color, animation_id, time, *value);
+                    insert_keyframe(&mut self.font_color, animation_id, timing, *value);
                 }
 
                 Property::FontSize(value) => {
-                    insert_keyframe(&mut self.font_size, animation_id, time, *value);
+                    insert_keyframe(&mut self.font_size, animation_id, timing, *value);
                 }
 
                 Property::CaretColor(value) => {
-                    insert_keyframe(&mut self.caret_color, animation_id, time, *value);
+                    insert_keyframe(&mut self.caret_color, animation_id, timing, *value);
                 }
 
                 Property::SelectionColor(value) => {
-                    insert_keyframe(&mut self.selection_color, animation_id, time, *value);
+                    insert_keyframe(&mut self.selection_color, animation_id, timing, *value);
                 }
 
                 // SPACE
                 Property::Left(value) => {
-                    insert_keyframe(&mut self.left, animation_id, time, *value);
+                    insert_keyframe(&mut self.left, animation_id, timing, *value);
                 }
 
                 Property::Right(value) => {
-                    insert_keyframe(&mut self.right, animation_id, time, *value);
+                    insert_keyframe(&mut self.right, animation_id, timing, *value);
                 }
 
                 Property::Top(value) => {
-                    insert_keyframe(&mut self.top, animation_id, time, *value);
+                    insert_keyframe(&mut self.top, animation_id, timing, *value);
                 }
 
                 Property::Bottom(value) => {
-                    insert_keyframe(&mut self.bottom, animation_id, time, *value);
+                    insert_keyframe(&mut self.bottom, animation_id, timing, *value);
                 }
 
                 // CHILD SPACE
                 Property::ChildLeft(value) => {
-                    insert_keyframe(&mut self.child_left, animation_id, time, *value);
+                    insert_keyframe(&mut self.child_left, animation_id, timing, *value);
                 }
 
                 Property::ChildRight(value) => {
-                    insert_keyframe(&mut self.child_right, animation_id, time, *value);
+                    insert_keyframe(&mut self.child_right, animation_id, timing, *value);
                 }
 
                 Property::ChildTop(value) => {
-                    insert_keyframe(&mut self.child_top, animation_id, time, *value);
+                    insert_keyframe(&mut self.child_top, animation_id, timing, *value);
                 }
 
                 Property::ChildBottom(value) => {
-                    insert_keyframe(&mut self.child_bottom, animation_id, time, *value);
+                    insert_keyframe(&mut self.child_bottom, animation_id, timing, *value);
                 }
 
                 Property::ColBetween(value) => {
-                    insert_keyframe(&mut self.col_between, animation_id, time, *value);
+                    insert_keyframe(&mut self.col_between, animation_id, timing, *value);
                 }
 
                 Property::RowBetween(value) => {
-                    insert_keyframe(&mut self.row_between, animation_id, time, *value);
+                    insert_keyframe(&mut self.row_between, animation_id, timing, *value);
                 }
 
                 // SIZE
                 Property::Width(value) => {
-                    insert_keyframe(&mut self.width, animation_id, time, *value);
+                    insert_keyframe(&mut self.width, animation_id, timing, *value);
                 }
 
                 Property::Height(value) => {
-                    insert_keyframe(&mut self.height, animation_id, time, *value);
+                    insert_keyframe(&mut self.height, animation_id, timing, *value);
                 }
 
                 // SIZE CONSTRAINTS
                 Property::MinWidth(value) => {
-                    insert_keyframe(&mut self.min_width, animation_id, time, *value);
+                    insert_keyframe(&mut self.min_width, animation_id, timing, *value);
                 }
 
                 Property::MaxWidth(value) => {
-                    insert_keyframe(&mut self.max_width, animation_id, time, *value);
+                    insert_keyframe(&mut self.max_width, animation_id, timing, *value);
                 }
 
                 Property::MinHeight(value) => {
-                    insert_keyframe(&mut self.min_height, animation_id, time, *value);
+                    insert_keyframe(&mut self.min_height, animation_id, timing, *value);
                 }
 
                 Property::MaxHeight(value) => {
-                    insert_keyframe(&mut self.max_height, animation_id, time, *value);
+                    insert_keyframe(&mut self.max_height, animation_id, timing, *value);
                 }
 
                 // SPACE CONSTRAINTS
                 Property::MinLeft(value) => {
-                    insert_keyframe(&mut self.min_left, animation_id, time, *value);
+                    insert_keyframe(&mut self.min_left, animation_id, timing, *value);
                 }
 
                 Property::MaxLeft(value) => {
-                    insert_keyframe(&mut self.max_left, animation_id, time, *value);
+                    insert_keyframe(&mut self.max_left, animation_id, timing, *value);
                 }
 
                 Property::MinRight(value) => {
-                    insert_keyframe(&mut self.min_right, animation_id, time, *value);
+                    insert_keyframe(&mut self.min_right, animation_id, timing, *value);
                 }
 
                 Property::MaxRight(value) => {
-                    insert_keyframe(&mut self.max_right, animation_id, time, *value);
+                    insert_keyframe(&mut self.max_right, animation_id, timing, *value);
                 }
 
                 Property::MinTop(value) => {
-                    insert_keyframe(&mut self.min_top, animation_id, time, *value);
+                    insert_keyframe(&mut self.min_top, animation_id, timing, *value);
                 }
 
                 Property::MaxTop(value) => {
-                    insert_keyframe(&mut self.max_top, animation_id, time, *value);
+                    insert_keyframe(&mut self.max_top, animation_id, timing, *value);
                 }
 
                 Property::MinBottom(value) => {
-                    insert_keyframe(&mut self.min_bottom, animation_id, time, *value);
+                    insert_keyframe(&mut self.min_bottom, animation_id, timing, *value);
                 }
 
                 Property::MaxBottom(value) => {
-                    insert_keyframe(&mut self.max_bottom, animation_id, time, *value);
+                    insert_keyframe(&mut self.max_bottom, animation_id, timing, *value);
                 }
 
                 Property::UnderlineColor(value) => {
-                    insert_keyframe(&mut self.underline_color, animation_id, time, *value);
+                    insert_keyframe(&mut self.underline_color, animation_id, timing, *value);
                 }
 
                 Property::Fill(value) => {
-                    insert_keyframe(&mut self.fill, animation_id, time, *value);
+                    insert_keyframe(&mut self.fill, animation_id, timing, *value);
                 }
 
                 _ => {}
@@ -690,7 +748,13 @@ impl Style {
     pub(crate) fn add_animation(&mut self, animation: AnimationBuilder) -> Animation {
         let animation_id = self.animation_manager.create();
         for keyframe in animation.keyframes.iter() {
-            self.add_keyframe(animation_id, keyframe.time, &keyframe.properties);
+            let timing = KeyframeTiming {
+                time: keyframe.time,
+                timing_function: keyframe.timing_function,
+                iterations: animation.iterations,
+                direction: animation.direction,
+            };
+            self.add_keyframe(animation_id, &timing, &keyframe.properties);
         }
 
         animation_id
@@ -706,16 +770,29 @@ impl Style {
         self.pending_animations.push((entity, animation, duration, delay));
     }
 
-    pub(crate) fn play_pending_animations(&mut self) {
-        let start_time = Instant::now();
-
+    pub(crate) fn play_pending_animations(&mut self, start_time: Instant) {
         let pending_animations = self.pending_animations.drain(..).collect::<Vec<_>>();
 
+        // The delay is applied when the animation is ticked, so all pending animations share the same start time.
         for (entity, animation, duration, delay) in pending_animations {
-            self.play_animation(entity, animation, start_time + delay, duration, delay)
+            self.play_animation(entity, animation, start_time, duration, delay);
+            if !self.playing_animations.contains(&(entity, animation)) {
+                self.playing_animations.push((entity, animation));
+            }
         }
     }
 
+    /// Removes and returns the animations played with `play_animation` which have since finished, either by
+    /// running to completion or by being replaced with another animation.
+    pub(crate) fn take_finished_animations(&mut self) -> Vec<(Entity, Animation)> {
+        let (playing, finished) = std::mem::take(&mut self.playing_animations)
+            .into_iter()
+            .partition(|(entity, animation)| self.is_animating(*entity, *animation));
+        self.playing_animations = playing;
+
+        finished
+    }
+
     pub(crate) fn play_animation(
         &mut self,
         entity: Entity,
@@ -724,6 +801,7 @@ impl Style {
         duration: Duration,
         delay: Duration,
     ) {
+        let start_time = Some(start_time);
         self.display.play_animation(entity, animation, start_time, duration, delay);
         self.opacity.play_animation(entity, animation, start_time, duration, delay);
         self.clip_path.play_animation(entity, animation, start_time, duration, delay);
@@ -852,6 +930,36 @@ impl Style {
         {
             let rules = stylesheet.rules.0;
 
+            // Custom properties are global and may only be declared in `:root` rules, with later declarations
+            // overriding earlier ones. They are not inherited or cascaded per view.
+            let mut variables = HashMap::new();
+            for rule in rules.iter() {
+                if let CssRule::Style(style_rule) = rule {
+                    let is_root = style_rule.selectors.0.iter().all(|selector| {
+                        selector
+                            .iter_raw_match_order()
+                            .all(|component| matches!(component, Component::Root))
+                    });
+
+                    for property in style_rule.declarations.declarations.iter() {
+                        if let Property::Custom(custom) = property {
+                            if !custom.name.starts_with("--") {
+                                continue;
+                            }
+
+                            if is_root {
+                                variables.insert(custom.name.to_string(), custom.value.clone());
+                            } else {
+                                warn!(
+                                    "Custom property {} ignored, custom properties may only be declared in :root rules",
+                                    custom.name
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+
             for rule in rules {
                 match rule {
                     CssRule::Style(style_rule) => {
@@ -869,8 +977,27 @@ impl Style {
                                     }
                                 }
 
+                                Property::Custom(_) => {}
+
+                                // Properties which reference variables are parsed once the variables are substituted.
+                                Property::Unparsed(unparsed) => {
+                                    let value = unparsed.value.to_css_string(&|name| {
+                                        resolve_variable(name, &variables, &self.style_variables, 0)
+                                    });
+
+                                    match value.as_deref().and_then(|value| {
+                                        Property::parse_str(&unparsed.name, value)
+                                    }) {
+                                        Some(property) => self.insert_property(
+                                            PropertyTarget::Rule(rule_id),
+                                            property,
+                                        ),
+                                        None => warn!("Unparsed: {}", unparsed.name),
+                                    }
+                                }
+
                                 _ => {
-                                    self.insert_property(rule_id, property);
+                                    self.insert_property(PropertyTarget::Rule(rule_id), property);
                                 }
                             }
                         }
@@ -893,7 +1020,7 @@ impl Style {
 
                                 self.add_keyframe(
                                     animation_id,
-                                    time,
+                                    &KeyframeTiming::new(time),
                                     &keyframes.declarations.declarations,
                                 );
                             }
@@ -1203,277 +1330,282 @@ impl Style {
         }
     }
 
-    fn insert_property(&mut self, rule_id: Rule, property: Property) {
+    fn insert_property(&mut self, target: PropertyTarget, property: Property) {
         match property {
             // Display
             Property::Display(display) => {
-                self.display.insert_rule(rule_id, display);
+                self.display.insert_target(target, display);
             }
 
             // Visibility
             Property::Visibility(visibility) => {
-                self.visibility.insert_rule(rule_id, visibility);
+                self.visibility.insert_target(target, visibility);
             }
 
             // Opacity
             Property::Opacity(opacity) => {
-                self.opacity.insert_rule(rule_id, opacity);
+                self.opacity.insert_target(target, opacity);
             }
 
             // Clipping
             Property::ClipPath(clip) => {
-                self.clip_path.insert_rule(rule_id, clip);
+                self.clip_path.insert_target(target, clip);
             }
 
             // Filters
             Property::BackdropFilter(filter) => {
-                self.backdrop_filter.insert_rule(rule_id, filter);
+                self.backdrop_filter.insert_target(target, filter);
             }
 
             // Blend Mode
             Property::BlendMode(blend_mode) => {
-                self.blend_mode.insert_rule(rule_id, blend_mode);
+                self.blend_mode.insert_target(target, blend_mode);
             }
 
             // Layout Type
             Property::LayoutType(layout_type) => {
-                self.layout_type.insert_rule(rule_id, layout_type);
+                self.layout_type.insert_target(target, layout_type);
             }
 
             // Position Type
             Property::PositionType(position_type) => {
-                self.position_type.insert_rule(rule_id, position_type);
+                self.position_type.insert_target(target, position_type);
+            }
+
+            // Direction
+            Property::Direction(direction) => {
+                self.direction.insert_target(target, direction);
             }
 
             // Space
             Property::Space(space) => {
-                self.left.insert_rule(rule_id, space);
-                self.right.insert_rule(rule_id, space);
-                self.top.insert_rule(rule_id, space);
-                self.bottom.insert_rule(rule_id, space);
+                self.left.insert_target(target, space);
+                self.right.insert_target(target, space);
+                self.top.insert_target(target, space);
+                self.bottom.insert_target(target, space);
             }
 
             Property::Left(left) => {
-                self.left.insert_rule(rule_id, left);
+                self.left.insert_target(target, left);
             }
 
             Property::Right(right) => {
-                self.right.insert_rule(rule_id, right);
+                self.right.insert_target(target, right);
             }
 
             Property::Top(top) => {
-                self.top.insert_rule(rule_id, top);
+                self.top.insert_target(target, top);
             }
 
             Property::Bottom(bottom) => {
-                self.bottom.insert_rule(rule_id, bottom);
+                self.bottom.insert_target(target, bottom);
             }
 
             // Size
             Property::Size(size) => {
-                self.width.insert_rule(rule_id, size);
-                self.height.insert_rule(rule_id, size);
+                self.width.insert_target(target, size);
+                self.height.insert_target(target, size);
             }
 
             Property::Width(width) => {
-                self.width.insert_rule(rule_id, width);
+                self.width.insert_target(target, width);
             }
 
             Property::Height(height) => {
-                self.height.insert_rule(rule_id, height);
+                self.height.insert_target(target, height);
             }
 
             // Child Space
             Property::ChildSpace(child_space) => {
-                self.child_left.insert_rule(rule_id, child_space);
-                self.child_right.insert_rule(rule_id, child_space);
-                self.child_top.insert_rule(rule_id, child_space);
-                self.child_bottom.insert_rule(rule_id, child_space);
+                self.child_left.insert_target(target, child_space);
+                self.child_right.insert_target(target, child_space);
+                self.child_top.insert_target(target, child_space);
+                self.child_bottom.insert_target(target, child_space);
             }
 
             Property::ChildLeft(child_left) => {
-                self.child_left.insert_rule(rule_id, child_left);
+                self.child_left.insert_target(target, child_left);
             }
 
             Property::ChildRight(child_right) => {
-                self.child_right.insert_rule(rule_id, child_right);
+                self.child_right.insert_target(target, child_right);
             }
 
             Property::ChildTop(child_top) => {
-                self.child_top.insert_rule(rule_id, child_top);
+                self.child_top.insert_target(target, child_top);
             }
 
             Property::ChildBottom(child_bottom) => {
-                self.child_bottom.insert_rule(rule_id, child_bottom);
+                self.child_bottom.insert_target(target, child_bottom);
             }
 
             Property::RowBetween(row_between) => {
-                self.row_between.insert_rule(rule_id, row_between);
+                self.row_between.insert_target(target, row_between);
             }
 
             Property::ColBetween(col_between) => {
-                self.col_between.insert_rule(rule_id, col_between);
+                self.col_between.insert_target(target, col_between);
             }
 
             // Space Constraints
             Property::MinSpace(min_space) => {
-                self.min_left.insert_rule(rule_id, min_space);
-                self.min_right.insert_rule(rule_id, min_space);
-                self.min_top.insert_rule(rule_id, min_space);
-                self.min_bottom.insert_rule(rule_id, min_space);
+                self.min_left.insert_target(target, min_space);
+                self.min_right.insert_target(target, min_space);
+                self.min_top.insert_target(target, min_space);
+                self.min_bottom.insert_target(target, min_space);
             }
 
             Property::MinLeft(min_left) => {
-                self.min_left.insert_rule(rule_id, min_left);
+                self.min_left.insert_target(target, min_left);
             }
 
             Property::MinRight(min_right) => {
-                self.min_right.insert_rule(rule_id, min_right);
+                self.min_right.insert_target(target, min_right);
             }
 
             Property::MinTop(min_top) => {
-                self.min_top.insert_rule(rule_id, min_top);
+                self.min_top.insert_target(target, min_top);
             }
 
             Property::MinBottom(min_bottom) => {
-                self.min_bottom.insert_rule(rule_id, min_bottom);
+                self.min_bottom.insert_target(target, min_bottom);
             }
 
             Property::MaxSpace(max_space) => {
-                self.max_left.insert_rule(rule_id, max_space);
-                self.max_right.insert_rule(rule_id, max_space);
-                self.max_top.insert_rule(rule_id, max_space);
-                self.max_bottom.insert_rule(rule_id, max_space);
+                self.max_left.insert_target(target, max_space);
+                self.max_right.insert_target(target, max_space);
+                self.max_top.insert_target(target, max_space);
+                self.max_bottom.insert_target(target, max_space);
             }
 
             Property::MaxLeft(max_left) => {
-                self.max_left.insert_rule(rule_id, max_left);
+                self.max_left.insert_target(target, max_left);
             }
 
             Property::MaxRight(max_right) => {
-                self.max_right.insert_rule(rule_id, max_right);
+                self.max_right.insert_target(target, max_right);
             }
 
             Property::MaxTop(max_top) => {
-                self.max_top.insert_rule(rule_id, max_top);
+                self.max_top.insert_target(target, max_top);
             }
 
             Property::MaxBottom(max_bottom) => {
-                self.max_bottom.insert_rule(rule_id, max_bottom);
+                self.max_bottom.insert_target(target, max_bottom);
             }
 
             // Size Constraints
             Property::MinSize(min_size) => {
-                self.min_width.insert_rule(rule_id, min_size);
-                self.min_height.insert_rule(rule_id, min_size);
+                self.min_width.insert_target(target, min_size);
+                self.min_height.insert_target(target, min_size);
             }
 
             Property::MinWidth(min_width) => {
-                self.min_width.insert_rule(rule_id, min_width);
+                self.min_width.insert_target(target, min_width);
             }
 
             Property::MinHeight(min_height) => {
-                self.min_height.insert_rule(rule_id, min_height);
+                self.min_height.insert_target(target, min_height);
             }
 
             Property::MaxSize(max_size) => {
-                self.max_width.insert_rule(rule_id, max_size);
-                self.max_height.insert_rule(rule_id, max_size);
+                self.max_width.insert_target(target, max_size);
+                self.max_height.insert_target(target, max_size);
             }
 
             Property::MaxWidth(max_width) => {
-                self.max_width.insert_rule(rule_id, max_width);
+                self.max_width.insert_target(target, max_width);
             }
 
             Property::MaxHeight(max_height) => {
-                self.max_height.insert_rule(rule_id, max_height);
+                self.max_height.insert_target(target, max_height);
             }
 
             // Background Colour
             Property::BackgroundColor(color) => {
-                self.background_color.insert_rule(rule_id, color);
+                self.background_color.insert_target(target, color);
             }
 
             // Border
             Property::Border(border) => {
                 if let Some(border_color) = border.color {
-                    self.border_color.insert_rule(rule_id, border_color);
+                    self.border_color.insert_target(target, border_color);
                 }
 
                 if let Some(border_width) = border.width {
-                    self.border_width.insert_rule(rule_id, border_width.into());
+                    self.border_width.insert_target(target, border_width.into());
                 }
 
                 if let Some(border_style) = border.style {
-                    self.border_style.insert_rule(rule_id, border_style.top);
+                    self.border_style.insert_target(target, border_style.top);
                 }
             }
 
             // Border
             Property::BorderWidth(border_width) => {
-                self.border_width.insert_rule(rule_id, border_width.top.0);
+                self.border_width.insert_target(target, border_width.top.0);
             }
 
             Property::BorderColor(color) => {
-                self.border_color.insert_rule(rule_id, color);
+                self.border_color.insert_target(target, color);
             }
 
             Property::BorderStyle(style) => {
-                self.border_style.insert_rule(rule_id, style.top);
+                self.border_style.insert_target(target, style.top);
             }
 
             // Border Radius
             Property::CornerRadius(corner_radius) => {
-                self.corner_bottom_left_radius.insert_rule(rule_id, corner_radius.bottom_left);
-                self.corner_bottom_right_radius.insert_rule(rule_id, corner_radius.bottom_right);
-                self.corner_top_left_radius.insert_rule(rule_id, corner_radius.top_left);
-                self.corner_top_right_radius.insert_rule(rule_id, corner_radius.top_right);
+                self.corner_bottom_left_radius.insert_target(target, corner_radius.bottom_left);
+                self.corner_bottom_right_radius.insert_target(target, corner_radius.bottom_right);
+                self.corner_top_left_radius.insert_target(target, corner_radius.top_left);
+                self.corner_top_right_radius.insert_target(target, corner_radius.top_right);
             }
 
             Property::CornerBottomLeftRadius(corner_radius) => {
-                self.corner_bottom_left_radius.insert_rule(rule_id, corner_radius);
+                self.corner_bottom_left_radius.insert_target(target, corner_radius);
             }
 
             Property::CornerTopLeftRadius(corner_radius) => {
-                self.corner_top_left_radius.insert_rule(rule_id, corner_radius);
+                self.corner_top_left_radius.insert_target(target, corner_radius);
             }
 
             Property::CornerBottomRightRadius(corner_radius) => {
-                self.corner_bottom_right_radius.insert_rule(rule_id, corner_radius);
+                self.corner_bottom_right_radius.insert_target(target, corner_radius);
             }
 
             Property::CornerTopRightRadius(corner_radius) => {
-                self.corner_top_right_radius.insert_rule(rule_id, corner_radius);
+                self.corner_top_right_radius.insert_target(target, corner_radius);
             }
 
             // Corner Shape
             Property::CornerShape(corner_shape) => {
-                self.corner_top_left_shape.insert_rule(rule_id, corner_shape.0);
-                self.corner_top_right_shape.insert_rule(rule_id, corner_shape.1);
-                self.corner_bottom_right_shape.insert_rule(rule_id, corner_shape.2);
-                self.corner_bottom_left_shape.insert_rule(rule_id, corner_shape.3);
+                self.corner_top_left_shape.insert_target(target, corner_shape.0);
+                self.corner_top_right_shape.insert_target(target, corner_shape.1);
+                self.corner_bottom_right_shape.insert_target(target, corner_shape.2);
+                self.corner_bottom_left_shape.insert_target(target, corner_shape.3);
             }
 
             Property::CornerTopLeftShape(corner_shape) => {
-                self.corner_top_left_shape.insert_rule(rule_id, corner_shape);
+                self.corner_top_left_shape.insert_target(target, corner_shape);
             }
 
             Property::CornerTopRightShape(corner_shape) => {
-                self.corner_top_right_shape.insert_rule(rule_id, corner_shape);
+                self.corner_top_right_shape.insert_target(target, corner_shape);
             }
 
             Property::CornerBottomLeftShape(corner_shape) => {
-                self.corner_bottom_left_shape.insert_rule(rule_id, corner_shape);
+                self.corner_bottom_left_shape.insert_target(target, corner_shape);
             }
 
             Property::CornerBottomRightShape(corner_shape) => {
-                self.corner_bottom_right_shape.insert_rule(rule_id, corner_shape);
+                self.corner_bottom_right_shape.insert_target(target, corner_shape);
             }
 
             // Font Family
             Property::FontFamily(font_family) => {
-                self.font_family.insert_rule(
-                    rule_id,
+                self.font_family.insert_target(
+                    target,
                     font_family
                         .iter()
                         .map(|family| match family {
@@ -1486,105 +1618,105 @@ impl Style {
 
             // Font Color
             Property::FontColor(font_color) => {
-                self.font_color.insert_rule(rule_id, font_color);
+                self.font_color.insert_target(target, font_color);
             }
 
             // Font Size
             Property::FontSize(font_size) => {
-                self.font_size.insert_rule(rule_id, font_size);
+                self.font_size.insert_target(target, font_size);
             }
 
             // Font Weight
             Property::FontWeight(font_weight) => {
-                self.font_weight.insert_rule(rule_id, font_weight);
+                self.font_weight.insert_target(target, font_weight);
             }
 
             // Font Slant
             Property::FontSlant(font_slant) => {
-                self.font_slant.insert_rule(rule_id, font_slant);
+                self.font_slant.insert_target(target, font_slant);
             }
 
             // Font Width
             Property::FontWidth(font_width) => {
-                self.font_width.insert_rule(rule_id, font_width);
+                self.font_width.insert_target(target, font_width);
             }
 
             // Font Variation Settings
             Property::FontVariationSettings(font_variation_settings) => {
-                self.font_variation_settings.insert_rule(rule_id, font_variation_settings);
+                self.font_variation_settings.insert_target(target, font_variation_settings);
             }
 
             // Caret Color
             Property::CaretColor(caret_color) => {
-                self.caret_color.insert_rule(rule_id, caret_color);
+                self.caret_color.insert_target(target, caret_color);
             }
 
             // Selection Color
             Property::SelectionColor(selection_color) => {
-                self.selection_color.insert_rule(rule_id, selection_color);
+                self.selection_color.insert_target(target, selection_color);
             }
 
             // Transform
             Property::Transform(transforms) => {
-                self.transform.insert_rule(rule_id, transforms);
+                self.transform.insert_target(target, transforms);
             }
 
             Property::TransformOrigin(transform_origin) => {
                 let x = transform_origin.x.to_length_or_percentage();
                 let y = transform_origin.y.to_length_or_percentage();
-                self.transform_origin.insert_rule(rule_id, Translate { x, y });
+                self.transform_origin.insert_target(target, Translate { x, y });
             }
 
             Property::Translate(translate) => {
-                self.translate.insert_rule(rule_id, translate);
+                self.translate.insert_target(target, translate);
             }
 
             Property::Rotate(rotate) => {
-                self.rotate.insert_rule(rule_id, rotate);
+                self.rotate.insert_target(target, rotate);
             }
 
             Property::Scale(scale) => {
-                self.scale.insert_rule(rule_id, scale);
+                self.scale.insert_target(target, scale);
             }
 
             // Overflow
             Property::Overflow(overflow) => {
-                self.overflowx.insert_rule(rule_id, overflow);
-                self.overflowy.insert_rule(rule_id, overflow);
+                self.overflowx.insert_target(target, overflow);
+                self.overflowy.insert_target(target, overflow);
             }
 
             Property::OverflowX(overflow) => {
-                self.overflowx.insert_rule(rule_id, overflow);
+                self.overflowx.insert_target(target, overflow);
             }
 
             Property::OverflowY(overflow) => {
-                self.overflowy.insert_rule(rule_id, overflow);
+                self.overflowy.insert_target(target, overflow);
             }
 
             // Z Index
-            Property::ZIndex(z_index) => self.z_index.insert_rule(rule_id, z_index),
+            Property::ZIndex(z_index) => self.z_index.insert_target(target, z_index),
 
             // Outline
             Property::Outline(outline) => {
                 if let Some(outline_color) = outline.color {
-                    self.outline_color.insert_rule(rule_id, outline_color);
+                    self.outline_color.insert_target(target, outline_color);
                 }
 
                 if let Some(outline_width) = outline.width {
-                    self.outline_width.insert_rule(rule_id, outline_width.into());
+                    self.outline_width.insert_target(target, outline_width.into());
                 }
             }
 
             Property::OutlineColor(outline_color) => {
-                self.outline_color.insert_rule(rule_id, outline_color);
+                self.outline_color.insert_target(target, outline_color);
             }
 
             Property::OutlineWidth(outline_width) => {
-                self.outline_width.insert_rule(rule_id, outline_width.left.0);
+                self.outline_width.insert_target(target, outline_width.left.0);
             }
 
             Property::OutlineOffset(outline_offset) => {
-                self.outline_offset.insert_rule(rule_id, outline_offset);
+                self.outline_offset.insert_target(target, outline_offset);
             }
 
             // Background Images & Gradients
@@ -1602,36 +1734,36 @@ impl Style {
                     })
                     .collect::<Vec<_>>();
 
-                self.background_image.insert_rule(rule_id, images);
+                self.background_image.insert_target(target, images);
             }
 
             // Background Size
             Property::BackgroundSize(sizes) => {
-                self.background_size.insert_rule(rule_id, sizes);
+                self.background_size.insert_target(target, sizes);
             }
 
             // Text Wrapping
             Property::TextWrap(text_wrap) => {
-                self.text_wrap.insert_rule(rule_id, text_wrap);
+                self.text_wrap.insert_target(target, text_wrap);
             }
 
             // Text Alignment
             Property::TextAlign(text_align) => {
-                self.text_align.insert_rule(rule_id, text_align);
+                self.text_align.insert_target(target, text_align);
             }
 
             // Box Shadows
             Property::Shadow(shadows) => {
-                self.shadow.insert_rule(rule_id, shadows);
+                self.shadow.insert_target(target, shadows);
             }
 
             // Cursor Icon
             Property::Cursor(cursor) => {
-                self.cursor.insert_rule(rule_id, cursor);
+                self.cursor.insert_target(target, cursor);
             }
 
             Property::PointerEvents(pointer_events) => {
-                self.pointer_events.insert_rule(rule_id, pointer_events);
+                self.pointer_events.insert_target(target, pointer_events);
             }
 
             // Unparsed. TODO: Log the error.
@@ -1639,42 +1771,439 @@ impl Style {
                 warn!("Unparsed: {}", unparsed.name);
             }
 
-            // TODO: Custom property support
+            // Custom properties are only used to resolve variables when parsing stylesheets.
             Property::Custom(custom) => {
                 warn!("Custom Property: {}", custom.name);
             }
             Property::TextOverflow(text_overflow) => {
-                self.text_overflow.insert_rule(rule_id, text_overflow);
+                self.text_overflow.insert_target(target, text_overflow);
             }
             Property::LineClamp(line_clamp) => {
-                self.line_clamp.insert_rule(rule_id, line_clamp);
+                self.line_clamp.insert_target(target, line_clamp);
+            }
+            Property::LineHeight(line_height) => {
+                self.line_height.insert_target(target, line_height);
+            }
+            Property::LetterSpacing(letter_spacing) => {
+                self.letter_spacing.insert_target(target, letter_spacing);
             }
             Property::TextDecorationLine(line) => {
-                self.text_decoration_line.insert_rule(rule_id, line);
+                self.text_decoration_line.insert_target(target, line);
             }
             Property::Fill(fill) => {
-                self.fill.insert_rule(rule_id, fill);
+                self.fill.insert_target(target, fill);
             }
             _ => {}
         }
     }
 
+    /// Sets a property inline on an entity, overriding the value of the property from any stylesheets.
+    pub(crate) fn insert_inline_property(&mut self, entity: Entity, property: Property) {
+        self.insert_property(PropertyTarget::Entity(entity), property);
+    }
+
+    /// Removes a property set inline on an entity, by its CSS name, so that the value from any stylesheets is used.
+    /// Returns false if the name is not that of a property which can be set inline.
+    pub(crate) fn remove_inline_property(&mut self, entity: Entity, name: &str) -> bool {
+        match name {
+            "display" => {
+                self.display.remove(entity);
+            }
+
+            "visibility" => {
+                self.visibility.remove(entity);
+            }
+
+            "overflow" => {
+                self.overflowx.remove(entity);
+                self.overflowy.remove(entity);
+            }
+
+            "overflow-x" => {
+                self.overflowx.remove(entity);
+            }
+
+            "overflow-y" => {
+                self.overflowy.remove(entity);
+            }
+
+            "clip-path" => {
+                self.clip_path.remove(entity);
+            }
+
+            "opacity" => {
+                self.opacity.remove(entity);
+            }
+
+            "z-index" => {
+                self.z_index.remove(entity);
+            }
+
+            "blend-mode" => {
+                self.blend_mode.remove(entity);
+            }
+
+            "layout-type" => {
+                self.layout_type.remove(entity);
+            }
+
+            "position-type" => {
+                self.position_type.remove(entity);
+            }
+
+            "direction" => {
+                self.direction.remove(entity);
+            }
+
+            "space" => {
+                self.left.remove(entity);
+                self.right.remove(entity);
+                self.top.remove(entity);
+                self.bottom.remove(entity);
+            }
+
+            "left" => {
+                self.left.remove(entity);
+            }
+
+            "width" => {
+                self.width.remove(entity);
+            }
+
+            "right" => {
+                self.right.remove(entity);
+            }
+
+            "top" => {
+                self.top.remove(entity);
+            }
+
+            "size" => {
+                self.width.remove(entity);
+                self.height.remove(entity);
+            }
+
+            "height" => {
+                self.height.remove(entity);
+            }
+
+            "bottom" => {
+                self.bottom.remove(entity);
+            }
+
+            "min-space" => {
+                self.min_left.remove(entity);
+                self.min_right.remove(entity);
+                self.min_top.remove(entity);
+                self.min_bottom.remove(entity);
+            }
+
+            "min-left" => {
+                self.min_left.remove(entity);
+            }
+
+            "min-right" => {
+                self.min_right.remove(entity);
+            }
+
+            "min-top" => {
+                self.min_top.remove(entity);
+            }
+
+            "min-bottom" => {
+                self.min_bottom.remove(entity);
+            }
+
+            "max-space" => {
+                self.max_left.remove(entity);
+                self.max_right.remove(entity);
+                self.max_top.remove(entity);
+                self.max_bottom.remove(entity);
+            }
+
+            "max-left" => {
+                self.max_left.remove(entity);
+            }
+
+            "max-right" => {
+                self.max_right.remove(entity);
+            }
+
+            "max-top" => {
+                self.max_top.remove(entity);
+            }
+
+            "max-bottom" => {
+                self.max_bottom.remove(entity);
+            }
+
+            "min-size" => {
+                self.min_width.remove(entity);
+                self.min_height.remove(entity);
+            }
+
+            "min-width" => {
+                self.min_width.remove(entity);
+            }
+
+            "min-height" => {
+                self.min_height.remove(entity);
+            }
+
+            "max-size" => {
+                self.max_width.remove(entity);
+                self.max_height.remove(entity);
+            }
+
+            "max-width" => {
+                self.max_width.remove(entity);
+            }
+
+            "max-height" => {
+                self.max_height.remove(entity);
+            }
+
+            "child-space" => {
+                self.child_left.remove(entity);
+                self.child_right.remove(entity);
+                self.child_top.remove(entity);
+                self.child_bottom.remove(entity);
+            }
+
+            "child-left" => {
+                self.child_left.remove(entity);
+            }
+
+            "child-right" => {
+                self.child_right.remove(entity);
+            }
+
+            "child-top" => {
+                self.child_top.remove(entity);
+            }
+
+            "child-bottom" => {
+                self.child_bottom.remove(entity);
+            }
+
+            "row-between" => {
+                self.row_between.remove(entity);
+            }
+
+            "col-between" => {
+                self.col_between.remove(entity);
+            }
+
+            "border" => {
+                self.border_color.remove(entity);
+                self.border_width.remove(entity);
+                self.border_style.remove(entity);
+            }
+
+            "border-color" => {
+                self.border_color.remove(entity);
+            }
+
+            "corner-shape" => {
+                self.corner_top_left_shape.remove(entity);
+                self.corner_top_right_shape.remove(entity);
+                self.corner_bottom_right_shape.remove(entity);
+                self.corner_bottom_left_shape.remove(entity);
+            }
+
+            "corner-top-left-shape" => {
+                self.corner_top_left_shape.remove(entity);
+            }
+
+            "corner-top-right-shape" => {
+                self.corner_top_right_shape.remove(entity);
+            }
+
+            "corner-bottom-left-shape" => {
+                self.corner_bottom_left_shape.remove(entity);
+            }
+
+            "corner-bottom-right-shape" => {
+                self.corner_bottom_right_shape.remove(entity);
+            }
+
+            "corner-radius" => {
+                self.corner_bottom_left_radius.remove(entity);
+                self.corner_bottom_right_radius.remove(entity);
+                self.corner_top_left_radius.remove(entity);
+                self.corner_top_right_radius.remove(entity);
+            }
+
+            "corner-top-left-radius" => {
+                self.corner_top_left_radius.remove(entity);
+            }
+
+            "corner-top-right-radius" => {
+                self.corner_top_right_radius.remove(entity);
+            }
+
+            "corner-bottom-left-radius" => {
+                self.corner_bottom_left_radius.remove(entity);
+            }
+
+            "corner-bottom-right-radius" => {
+                self.corner_bottom_right_radius.remove(entity);
+            }
+
+            "border-style" => {
+                self.border_style.remove(entity);
+            }
+
+            "border-width" => {
+                self.border_width.remove(entity);
+            }
+
+            "outline" => {
+                self.outline_color.remove(entity);
+                self.outline_width.remove(entity);
+            }
+
+            "outline-color" => {
+                self.outline_color.remove(entity);
+            }
+
+            "outline-width" => {
+                self.outline_width.remove(entity);
+            }
+
+            "outline-offset" => {
+                self.outline_offset.remove(entity);
+            }
+
+            "background-color" => {
+                self.background_color.remove(entity);
+            }
+
+            "background-image" => {
+                self.background_image.remove(entity);
+            }
+
+            "background-size" => {
+                self.background_size.remove(entity);
+            }
+
+            "fill" => {
+                self.fill.remove(entity);
+            }
+
+            "font-size" => {
+                self.font_size.remove(entity);
+            }
+
+            "color" => {
+                self.font_color.remove(entity);
+            }
+
+            "font-family" => {
+                self.font_family.remove(entity);
+            }
+
+            "font-variation-settings" => {
+                self.font_variation_settings.remove(entity);
+            }
+
+            "font-weight" => {
+                self.font_weight.remove(entity);
+            }
+
+            "font-slant" => {
+                self.font_slant.remove(entity);
+            }
+
+            "font-width" => {
+                self.font_width.remove(entity);
+            }
+
+            "selection-color" => {
+                self.selection_color.remove(entity);
+            }
+
+            "caret-color" => {
+                self.caret_color.remove(entity);
+            }
+
+            "text-wrap" => {
+                self.text_wrap.remove(entity);
+            }
+
+            "text-align" => {
+                self.text_align.remove(entity);
+            }
+
+            "text-overflow" => {
+                self.text_overflow.remove(entity);
+            }
+
+            "line-clamp" => {
+                self.line_clamp.remove(entity);
+            }
+
+            "line-height" => {
+                self.line_height.remove(entity);
+            }
+
+            "letter-spacing" => {
+                self.letter_spacing.remove(entity);
+            }
+
+            "text-decoration-line" => {
+                self.text_decoration_line.remove(entity);
+            }
+
+            "shadow" => {
+                self.shadow.remove(entity);
+            }
+
+            "backdrop-filter" => {
+                self.backdrop_filter.remove(entity);
+            }
+
+            "transform" => {
+                self.transform.remove(entity);
+            }
+
+            "transform-origin" => {
+                self.transform_origin.remove(entity);
+            }
+
+            "translate" => {
+                self.translate.remove(entity);
+            }
+
+            "rotate" => {
+                self.rotate.remove(entity);
+            }
+
+            "scale" => {
+                self.scale.remove(entity);
+            }
+
+            "cursor" => {
+                self.cursor.remove(entity);
+            }
+
+            "pointer-events" => {
+                self.pointer_events.remove(entity);
+            }
+
+            _ => return false,
+        }
+
+        true
+    }
+
     // Helper function for generating AnimationState from a transition definition.
     fn add_transition<T: Default + Interpolator>(
         &self,
         transition: &Transition,
     ) -> AnimationState<T> {
-        let timing_function = transition
-            .timing_function
-            .map(|easing| match easing {
-                EasingFunction::Linear => TimingFunction::linear(),
-                EasingFunction::Ease => TimingFunction::ease(),
-                EasingFunction::EaseIn => TimingFunction::ease_in(),
-                EasingFunction::EaseOut => TimingFunction::ease_out(),
-                EasingFunction::EaseInOut => TimingFunction::ease_in_out(),
-                EasingFunction::CubicBezier(x1, y1, x2, y2) => TimingFunction::new(x1, y1, x2, y2),
-            })
-            .unwrap_or_default();
+        let timing_function =
+            transition.timing_function.map(TimingFunction::from).unwrap_or_default();
 
         AnimationState::new(Animation::null())
             .with_duration(transition.duration)
@@ -1700,6 +2229,7 @@ impl Style {
         self.pseudo_classes.remove(entity);
         self.disabled.remove(entity);
         self.abilities.remove(entity);
+        self.focus_order.remove(entity);
 
         self.name.remove(entity);
         self.role.remove(entity);
@@ -1708,6 +2238,7 @@ impl Style {
         self.labelled_by.remove(entity);
         self.hidden.remove(entity);
         self.text_value.remove(entity);
+        self.description.remove(entity);
         self.numeric_value.remove(entity);
 
         // Display
@@ -1779,6 +2310,8 @@ impl Style {
         self.text_overflow.remove(entity);
         self.line_clamp.remove(entity);
         self.text_align.remove(entity);
+        self.line_height.remove(entity);
+        self.letter_spacing.remove(entity);
         self.font_family.remove(entity);
         self.font_color.remove(entity);
         self.font_size.remove(entity);
@@ -1801,6 +2334,9 @@ impl Style {
         // Position Type
         self.position_type.remove(entity);
 
+        // Direction
+        self.direction.remove(entity);
+
         // Space
         self.left.remove(entity);
         self.right.remove(entity);
@@ -1935,6 +2471,7 @@ impl Style {
 
         self.layout_type.clear_rules();
         self.position_type.clear_rules();
+        self.direction.clear_rules();
 
         // Space
         self.left.clear_rules();
@@ -1975,6 +2512,8 @@ impl Style {
         self.text_overflow.clear_rules();
         self.line_clamp.clear_rules();
         self.text_align.clear_rules();
+        self.line_height.clear_rules();
+        self.letter_spacing.clear_rules();
         self.font_family.clear_rules();
         self.font_weight.clear_rules();
         self.font_slant.clear_rules();
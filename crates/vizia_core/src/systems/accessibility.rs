@@ -114,6 +114,10 @@ pub(crate) fn get_access_node(
         node_builder.set_name(name.clone().into_boxed_str());
     }
 
+    if let Some(description) = cx.style.description.get(entity) {
+        node_builder.set_description(description.clone().into_boxed_str());
+    }
+
     if let Some(numeric_value) = cx.style.numeric_value.get(entity) {
         node_builder.set_numeric_value(*numeric_value);
     }
@@ -152,7 +156,10 @@ pub(crate) fn get_access_node(
             .get(entity)
             .map(|pseudoclass| pseudoclass.contains(PseudoClassFlags::CHECKED))
         {
-            if checked {
+            // The checked state of a list box option is its selection, rather than a toggle.
+            if cx.style.role.get(entity) == Some(&Role::ListBoxOption) {
+                node_builder.set_selected(checked);
+            } else if checked {
                 node_builder.set_toggled(Toggled::True);
             } else {
                 node_builder.set_toggled(Toggled::False);
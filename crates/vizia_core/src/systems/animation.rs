@@ -1,11 +1,13 @@
 use crate::prelude::*;
 
-pub(crate) fn animation_system(cx: &mut Context) -> bool {
-    cx.style.play_pending_animations();
+pub(crate) fn animation_system(cx: &mut Context, time: Instant) -> bool {
+    cx.style.play_pending_animations(time);
 
     // Tick all animations
 
-    let time = Instant::now();
+    // When reduced motion is requested, every animation and transition completes on this frame, including those
+    // which repeat indefinitely.
+    let reduce_motion = cx.style.should_reduce_motion();
 
     let mut redraw_entities = Vec::new();
     let mut reflow_entities = Vec::new();
@@ -13,74 +15,81 @@ pub(crate) fn animation_system(cx: &mut Context) -> bool {
 
     // Properties which affect rendering
     // Opacity
-    redraw_entities.extend(cx.style.opacity.tick(time));
+    redraw_entities.extend(cx.style.opacity.tick(time, reduce_motion));
     // Corner Colour
-    redraw_entities.extend(cx.style.border_color.tick(time));
+    redraw_entities.extend(cx.style.border_color.tick(time, reduce_motion));
     // Corner Radius
-    redraw_entities.extend(cx.style.corner_top_left_radius.tick(time));
-    redraw_entities.extend(cx.style.corner_top_right_radius.tick(time));
-    redraw_entities.extend(cx.style.corner_bottom_left_radius.tick(time));
-    redraw_entities.extend(cx.style.corner_bottom_right_radius.tick(time));
+    redraw_entities.extend(cx.style.corner_top_left_radius.tick(time, reduce_motion));
+    redraw_entities.extend(cx.style.corner_top_right_radius.tick(time, reduce_motion));
+    redraw_entities.extend(cx.style.corner_bottom_left_radius.tick(time, reduce_motion));
+    redraw_entities.extend(cx.style.corner_bottom_right_radius.tick(time, reduce_motion));
     // Background
-    redraw_entities.extend(cx.style.background_color.tick(time));
-    redraw_entities.extend(cx.style.background_image.tick(time));
-    redraw_entities.extend(cx.style.background_size.tick(time));
+    redraw_entities.extend(cx.style.background_color.tick(time, reduce_motion));
+    redraw_entities.extend(cx.style.background_image.tick(time, reduce_motion));
+    redraw_entities.extend(cx.style.background_size.tick(time, reduce_motion));
     // Box Shadow
-    redraw_entities.extend(cx.style.shadow.tick(time));
+    redraw_entities.extend(cx.style.shadow.tick(time, reduce_motion));
     // Transform
-    redraw_entities.extend(cx.style.transform.tick(time));
-    redraw_entities.extend(cx.style.transform_origin.tick(time));
-    redraw_entities.extend(cx.style.translate.tick(time));
-    redraw_entities.extend(cx.style.rotate.tick(time));
-    redraw_entities.extend(cx.style.scale.tick(time));
+    redraw_entities.extend(cx.style.transform.tick(time, reduce_motion));
+    redraw_entities.extend(cx.style.transform_origin.tick(time, reduce_motion));
+    redraw_entities.extend(cx.style.translate.tick(time, reduce_motion));
+    redraw_entities.extend(cx.style.rotate.tick(time, reduce_motion));
+    redraw_entities.extend(cx.style.scale.tick(time, reduce_motion));
     // Outline
-    redraw_entities.extend(cx.style.outline_color.tick(time));
-    redraw_entities.extend(cx.style.outline_offset.tick(time));
-    redraw_entities.extend(cx.style.outline_width.tick(time));
+    redraw_entities.extend(cx.style.outline_color.tick(time, reduce_motion));
+    redraw_entities.extend(cx.style.outline_offset.tick(time, reduce_motion));
+    redraw_entities.extend(cx.style.outline_width.tick(time, reduce_motion));
     // Clip Path
-    redraw_entities.extend(cx.style.clip_path.tick(time));
+    redraw_entities.extend(cx.style.clip_path.tick(time, reduce_motion));
 
-    redraw_entities.extend(cx.style.fill.tick(time));
+    redraw_entities.extend(cx.style.fill.tick(time, reduce_motion));
 
     // Font Color
-    reflow_entities.extend(cx.style.font_color.tick(time));
+    reflow_entities.extend(cx.style.font_color.tick(time, reduce_motion));
     // Font Size
-    reflow_entities.extend(cx.style.font_size.tick(time));
+    reflow_entities.extend(cx.style.font_size.tick(time, reduce_motion));
 
     // Properties which affect layout
-    relayout_entities.extend(cx.style.display.tick(time));
+    relayout_entities.extend(cx.style.display.tick(time, reduce_motion));
     // Border Width
-    relayout_entities.extend(cx.style.border_width.tick(time));
+    relayout_entities.extend(cx.style.border_width.tick(time, reduce_motion));
     // Space
-    relayout_entities.extend(cx.style.left.tick(time));
-    relayout_entities.extend(cx.style.right.tick(time));
-    relayout_entities.extend(cx.style.top.tick(time));
-    relayout_entities.extend(cx.style.bottom.tick(time));
+    relayout_entities.extend(cx.style.left.tick(time, reduce_motion));
+    relayout_entities.extend(cx.style.right.tick(time, reduce_motion));
+    relayout_entities.extend(cx.style.top.tick(time, reduce_motion));
+    relayout_entities.extend(cx.style.bottom.tick(time, reduce_motion));
     // Size
-    relayout_entities.extend(cx.style.width.tick(time));
-    relayout_entities.extend(cx.style.height.tick(time));
+    relayout_entities.extend(cx.style.width.tick(time, reduce_motion));
+    relayout_entities.extend(cx.style.height.tick(time, reduce_motion));
     // Min/Max Size
-    relayout_entities.extend(cx.style.max_width.tick(time));
-    relayout_entities.extend(cx.style.max_height.tick(time));
-    relayout_entities.extend(cx.style.min_width.tick(time));
-    relayout_entities.extend(cx.style.min_height.tick(time));
+    relayout_entities.extend(cx.style.max_width.tick(time, reduce_motion));
+    relayout_entities.extend(cx.style.max_height.tick(time, reduce_motion));
+    relayout_entities.extend(cx.style.min_width.tick(time, reduce_motion));
+    relayout_entities.extend(cx.style.min_height.tick(time, reduce_motion));
     // Min/Max Space
-    relayout_entities.extend(cx.style.min_left.tick(time));
-    relayout_entities.extend(cx.style.max_left.tick(time));
-    relayout_entities.extend(cx.style.min_right.tick(time));
-    relayout_entities.extend(cx.style.max_right.tick(time));
-    relayout_entities.extend(cx.style.min_top.tick(time));
-    relayout_entities.extend(cx.style.max_top.tick(time));
-    relayout_entities.extend(cx.style.min_bottom.tick(time));
-    relayout_entities.extend(cx.style.max_bottom.tick(time));
+    relayout_entities.extend(cx.style.min_left.tick(time, reduce_motion));
+    relayout_entities.extend(cx.style.max_left.tick(time, reduce_motion));
+    relayout_entities.extend(cx.style.min_right.tick(time, reduce_motion));
+    relayout_entities.extend(cx.style.max_right.tick(time, reduce_motion));
+    relayout_entities.extend(cx.style.min_top.tick(time, reduce_motion));
+    relayout_entities.extend(cx.style.max_top.tick(time, reduce_motion));
+    relayout_entities.extend(cx.style.min_bottom.tick(time, reduce_motion));
+    relayout_entities.extend(cx.style.max_bottom.tick(time, reduce_motion));
     // Row/Col Between
-    relayout_entities.extend(cx.style.row_between.tick(time));
-    relayout_entities.extend(cx.style.col_between.tick(time));
+    relayout_entities.extend(cx.style.row_between.tick(time, reduce_motion));
+    relayout_entities.extend(cx.style.col_between.tick(time, reduce_motion));
     // Child Space
-    relayout_entities.extend(cx.style.child_left.tick(time));
-    relayout_entities.extend(cx.style.child_right.tick(time));
-    relayout_entities.extend(cx.style.child_top.tick(time));
-    relayout_entities.extend(cx.style.child_bottom.tick(time));
+    relayout_entities.extend(cx.style.child_left.tick(time, reduce_motion));
+    relayout_entities.extend(cx.style.child_right.tick(time, reduce_motion));
+    relayout_entities.extend(cx.style.child_top.tick(time, reduce_motion));
+    relayout_entities.extend(cx.style.child_bottom.tick(time, reduce_motion));
+
+    let springs_playing = tick_springs(cx, time);
+
+    for (entity, animation) in cx.style.take_finished_animations() {
+        cx.event_queue
+            .push_back(Event::new(WindowEvent::AnimationFinished(animation)).direct(entity));
+    }
 
     if !relayout_entities.is_empty() {
         cx.style.system_flags.set(SystemFlags::RELAYOUT, true);
@@ -94,5 +103,48 @@ pub(crate) fn animation_system(cx: &mut Context) -> bool {
         cx.style.text_construction.insert(*entity).unwrap();
     }
 
-    !redraw_entities.is_empty() | !relayout_entities.is_empty() | !reflow_entities.is_empty()
+    springs_playing
+        | !redraw_entities.is_empty()
+        | !relayout_entities.is_empty()
+        | !reflow_entities.is_empty()
+}
+
+// Advances all playing springs by the time elapsed since they were last advanced. Returns true if any springs are
+// still playing.
+fn tick_springs(cx: &mut Context, now: Instant) -> bool {
+    let reduce_motion = cx.style.should_reduce_motion();
+
+    // Callbacks may play, modify or stop springs, so each spring is looked up again by its handle.
+    let ids = cx.springs.iter().map(|spring_state| spring_state.id).collect::<Vec<_>>();
+    for id in ids {
+        let Some(spring_state) = cx.springs.iter_mut().find(|spring_state| spring_state.id == id)
+        else {
+            continue;
+        };
+
+        let spring = &mut spring_state.spring;
+        if reduce_motion {
+            spring.set_value(spring.current_target());
+            spring.set_velocity(0.0);
+        }
+
+        let value = spring.step(now.saturating_duration_since(spring_state.time));
+        spring_state.time = now;
+
+        let action = if spring.is_settled() {
+            SpringAction::Settled(value)
+        } else {
+            SpringAction::Update(value)
+        };
+        let entity = spring_state.entity;
+        let callback = spring_state.callback.clone();
+
+        if let SpringAction::Settled(_) = action {
+            cx.springs.retain(|spring_state| spring_state.id != id);
+        }
+
+        (callback)(&mut EventContext::new_with_current(cx, entity), action);
+    }
+
+    !cx.springs.is_empty()
 }
@@ -1,7 +1,8 @@
 use crate::{animation::Interpolator, cache::CachedData, prelude::*};
 use morphorm::Node;
 use skia_safe::{
-    canvas::SaveLayerRec, ClipOp, ImageFilter, Matrix, Paint, Rect, SamplingOptions, Surface,
+    canvas::SaveLayerRec, ClipOp, ColorFilter, Data, ImageFilter, Matrix, Paint, Rect,
+    RuntimeEffect, SamplingOptions, Surface,
 };
 use std::cmp::Ordering;
 use std::collections::BinaryHeap;
@@ -142,13 +143,13 @@ pub(crate) fn draw_system(
     window_entity: Entity,
     surface: &mut Surface,
     dirty_surface: &mut Surface,
-) -> bool {
+) -> Option<BoundingBox> {
     if cx.windows.is_empty() {
-        return false;
+        return None;
     }
 
     if !cx.entity_manager.is_alive(window_entity) {
-        return false;
+        return None;
     }
 
     transform_system(cx);
@@ -159,7 +160,7 @@ pub(crate) fn draw_system(
     let redraw_list = std::mem::take(&mut window.redraw_list);
 
     if redraw_list.is_empty() {
-        return false;
+        return None;
     }
 
     for &entity in &redraw_list {
@@ -195,9 +196,15 @@ pub(crate) fn draw_system(
         }
     }
 
-    if dirty_rect.is_none() {
-        return false;
-    }
+    // Views can draw outside of the window, so clamp the dirty rect to the bounds of the surface to
+    // avoid negative or out of range coordinates.
+    let surface_bounds =
+        BoundingBox::from_min_max(0.0, 0.0, surface.width() as f32, surface.height() as f32);
+    let dirty_rect = dirty_rect
+        .map(|dirty_rect| dirty_rect.intersection(&surface_bounds))
+        .filter(|dirty_rect| dirty_rect.w > 0.0 && dirty_rect.h > 0.0);
+
+    let damage = dirty_rect?;
 
     let canvas = dirty_surface.canvas();
 
@@ -239,8 +246,43 @@ pub(crate) fn draw_system(
 
     canvas.restore();
 
+    // Copy the contents of the dirty surface to the window surface, converting to straight alpha if required.
+    // The whole surface is composited rather than just the dirty rect, as the backend doesn't track the age of the
+    // window's back buffer, so the returned damage only tells the compositor which part of the frame to update.
+    let mut composite_paint = Paint::default();
+    composite_paint.set_blend_mode(skia_safe::BlendMode::Src);
+    if window.window_description.transparent {
+        let opacity = window.window_description.opacity.clamp(0.0, 1.0);
+        if window.window_description.alpha_mode == AlphaMode::Unpremultiplied {
+            composite_paint.set_color_filter(unpremultiply_color_filter(opacity));
+        } else {
+            composite_paint.set_alpha_f(opacity);
+        }
+    }
+
     surface.canvas().clear(Color::transparent());
-    dirty_surface.draw(surface.canvas(), (0, 0), SamplingOptions::default(), None);
+
+    if let Some(post_process) = &cx.post_process {
+        let info = PostProcessInfo {
+            window: window_entity,
+            width: surface.width() as u32,
+            height: surface.height() as u32,
+            scale_factor: cx.style.scale_factor(),
+        };
+
+        let image = dirty_surface.image_snapshot();
+
+        surface.canvas().save_layer(&SaveLayerRec::default().paint(&composite_paint));
+        (post_process)(surface.canvas(), &image, &info);
+        surface.canvas().restore();
+    } else {
+        dirty_surface.draw(
+            surface.canvas(),
+            (0, 0),
+            SamplingOptions::default(),
+            Some(&composite_paint),
+        );
+    }
 
     // Debug draw dirty rect
     // if let Some(rect) = dirty_rect.map(Rect::from) {
@@ -254,7 +296,27 @@ pub(crate) fn draw_system(
     window.redraw_list.clear();
     window.dirty_rect = None;
 
-    true
+    // Draw the inspector overlay on top of the composited frame.
+    inspector_system(cx, window_entity, surface.canvas());
+
+    // Post-processing and the inspector overlay can change any part of the frame.
+    if cx.post_process.is_some() || cx.inspector.enabled {
+        Some(surface_bounds)
+    } else {
+        Some(damage)
+    }
+}
+
+// Returns a color filter which converts premultiplied colors to straight alpha.
+// The opacity is applied before converting to straight alpha, as the paint alpha would otherwise be
+// applied to the already unpremultiplied color channels.
+fn unpremultiply_color_filter(opacity: f32) -> Option<ColorFilter> {
+    RuntimeEffect::make_for_color_filter(
+        "uniform half opacity; half4 main(half4 color) { return unpremul(color * opacity); }",
+        None,
+    )
+    .ok()
+    .and_then(|effect| effect.make_color_filter(Data::new_copy(&opacity.to_ne_bytes()), None))
 }
 
 fn draw_entity(
@@ -15,29 +15,8 @@ pub fn hover_system(cx: &mut Context, window_entity: Entity) {
         }
     }
 
-    let mut queue = BinaryHeap::new();
-    let pointer_events: bool =
-        cx.style.pointer_events.get(window_entity).copied().unwrap_or_default().into();
-    queue.push(ZEntity { index: 0, pointer_events, entity: window_entity });
-    let mut hovered = window_entity;
-    let transform = Matrix::new_identity();
-    // let clip_bounds = cx.cache.get_bounds(window_entity);
-    let clip_bounds: BoundingBox =
-        BoundingBox { x: -f32::MAX / 2.0, y: -f32::MAX / 2.0, w: f32::MAX, h: f32::MAX };
-    while !queue.is_empty() {
-        let zentity = queue.pop().unwrap();
-        cx.with_current(zentity.entity, |cx| {
-            hover_entity(
-                &mut EventContext::new(cx),
-                zentity.index,
-                zentity.pointer_events,
-                &mut queue,
-                &mut hovered,
-                transform,
-                &clip_bounds,
-            );
-        });
-    }
+    let point = (cx.mouse.cursor_x, cx.mouse.cursor_y);
+    let hovered = pick_entity(cx, window_entity, point, true);
 
     // Set hover state for hovered view and ancestors
     let parent_iter = LayoutParentIterator::new(&cx.tree, hovered);
@@ -85,8 +64,47 @@ pub fn hover_system(cx: &mut Context, window_entity: Entity) {
     }
 }
 
+// Returns the topmost entity under the given physical position within a window, without changing any hover state.
+pub(crate) fn hit_test(cx: &mut Context, window_entity: Entity, x: f32, y: f32) -> Entity {
+    pick_entity(cx, window_entity, (x, y), false)
+}
+
+// Finds the topmost entity under a point, updating the over pseudo-class of visited entities if `update` is true.
+fn pick_entity(cx: &mut Context, window_entity: Entity, point: (f32, f32), update: bool) -> Entity {
+    let mut queue = BinaryHeap::new();
+    let pointer_events: bool =
+        cx.style.pointer_events.get(window_entity).copied().unwrap_or_default().into();
+    queue.push(ZEntity { index: 0, pointer_events, entity: window_entity });
+    let mut hovered = window_entity;
+    let transform = Matrix::new_identity();
+    // let clip_bounds = cx.cache.get_bounds(window_entity);
+    let clip_bounds: BoundingBox =
+        BoundingBox { x: -f32::MAX / 2.0, y: -f32::MAX / 2.0, w: f32::MAX, h: f32::MAX };
+    while !queue.is_empty() {
+        let zentity = queue.pop().unwrap();
+        cx.with_current(zentity.entity, |cx| {
+            hover_entity(
+                &mut EventContext::new(cx),
+                point,
+                update,
+                zentity.index,
+                zentity.pointer_events,
+                &mut queue,
+                &mut hovered,
+                transform,
+                &clip_bounds,
+            );
+        });
+    }
+
+    hovered
+}
+
+#[allow(clippy::too_many_arguments)]
 fn hover_entity(
     cx: &mut EventContext,
+    point: (f32, f32),
+    update: bool,
     current_z: i32,
     parent_pointer_events: bool,
     queue: &mut BinaryHeap<ZEntity>,
@@ -134,8 +152,7 @@ fn hover_entity(
 
     let bounds = cx.bounds();
 
-    let cursor_x = cx.mouse.cursor_x;
-    let cursor_y = cx.mouse.cursor_y;
+    let (cursor_x, cursor_y) = point;
 
     if cursor_x < 0.0 || cursor_y < 0.0 {
         return;
@@ -154,21 +171,24 @@ fn hover_entity(
     let b = bounds.intersection(&clipping);
     // let b = bounds;
 
-    if let Some(pseudo_classes) = cx.style.pseudo_classes.get_mut(cx.current) {
-        pseudo_classes.set(PseudoClassFlags::HOVER, false);
+    if update {
+        if let Some(pseudo_classes) = cx.style.pseudo_classes.get_mut(cx.current) {
+            pseudo_classes.set(PseudoClassFlags::HOVER, false);
+        }
     }
 
     if pointer_events {
         if tx >= b.left() && tx < b.right() && ty >= b.top() && ty < b.bottom() {
             *hovered = cx.current;
 
-            if !cx
-                .style
-                .pseudo_classes
-                .get(cx.current)
-                .copied()
-                .unwrap_or_default()
-                .contains(PseudoClassFlags::OVER)
+            if update
+                && !cx
+                    .style
+                    .pseudo_classes
+                    .get(cx.current)
+                    .copied()
+                    .unwrap_or_default()
+                    .contains(PseudoClassFlags::OVER)
             {
                 if let Some(pseudo_class) = cx.style.pseudo_classes.get_mut(cx.current) {
                     pseudo_class.set(PseudoClassFlags::OVER, true);
@@ -176,13 +196,14 @@ fn hover_entity(
                     cx.needs_restyle();
                 }
             }
-        } else if cx
-            .style
-            .pseudo_classes
-            .get(cx.current)
-            .copied()
-            .unwrap_or_default()
-            .contains(PseudoClassFlags::OVER)
+        } else if update
+            && cx
+                .style
+                .pseudo_classes
+                .get(cx.current)
+                .copied()
+                .unwrap_or_default()
+                .contains(PseudoClassFlags::OVER)
         {
             if let Some(pseudo_class) = cx.style.pseudo_classes.get_mut(cx.current) {
                 pseudo_class.set(PseudoClassFlags::OVER, false);
@@ -195,7 +216,17 @@ fn hover_entity(
     let child_iter = DrawChildIterator::new(cx.tree, cx.current);
     for child in child_iter {
         cx.current = child;
-        hover_entity(cx, current_z, pointer_events, queue, hovered, transform, &clipping);
+        hover_entity(
+            cx,
+            point,
+            update,
+            current_z,
+            pointer_events,
+            queue,
+            hovered,
+            transform,
+            &clipping,
+        );
     }
 }
 
@@ -0,0 +1,165 @@
+use crate::prelude::*;
+use skia_safe::{Font, FontStyle, Paint, PaintStyle, Rect};
+
+const INSPECTOR_FONT_SIZE: f32 = 12.0;
+const INSPECTOR_LINE_HEIGHT: f32 = 16.0;
+const INSPECTOR_PADDING: f32 = 6.0;
+
+/// State of the built-in layout inspector overlay.
+#[derive(Default, Debug, Clone, Copy)]
+pub(crate) struct Inspector {
+    /// Whether the inspector overlay is shown.
+    pub enabled: bool,
+    /// A view which has been pinned by clicking on it. When `None` the hovered view is inspected.
+    pub pinned: Option<Entity>,
+}
+
+impl Inspector {
+    /// Returns the entity which should be highlighted by the inspector.
+    pub fn target(&self, hovered: Entity) -> Entity {
+        self.pinned.unwrap_or(hovered)
+    }
+}
+
+// Builds the lines of text shown in the inspector panel for the given entity.
+fn inspector_lines(cx: &Context, entity: Entity) -> Vec<String> {
+    let bounds = cx.cache.get_bounds(entity);
+
+    let mut selector = cx
+        .views
+        .get(&entity)
+        .map_or("<None>", |view| view.element().unwrap_or("<Unnamed>"))
+        .to_string();
+
+    if let Some(id) = cx.style.ids.get(entity) {
+        selector += &format!("#{}", id);
+    }
+
+    if let Some(classes) = cx.style.classes.get(entity) {
+        for class in classes.iter() {
+            selector += &format!(".{}", class);
+        }
+    }
+
+    let style = &cx.style;
+
+    vec![
+        format!("{} {}", entity, selector),
+        format!("x: {} y: {} w: {} h: {}", bounds.x, bounds.y, bounds.w, bounds.h),
+        format!(
+            "width: {:?} height: {:?}",
+            style.width.get(entity).copied().unwrap_or_default(),
+            style.height.get(entity).copied().unwrap_or_default()
+        ),
+        format!(
+            "layout-type: {:?} position-type: {:?}",
+            style.layout_type.get(entity).copied().unwrap_or_default(),
+            style.position_type.get(entity).copied().unwrap_or_default()
+        ),
+        format!(
+            "child-space: {:?} {:?} {:?} {:?}",
+            style.child_left.get(entity).copied().unwrap_or_default(),
+            style.child_top.get(entity).copied().unwrap_or_default(),
+            style.child_right.get(entity).copied().unwrap_or_default(),
+            style.child_bottom.get(entity).copied().unwrap_or_default()
+        ),
+        format!(
+            "background-color: {:?}",
+            style.background_color.get(entity).copied().unwrap_or_default()
+        ),
+    ]
+}
+
+/// Draws the inspector overlay on top of the rendered contents of a window.
+pub(crate) fn inspector_system(cx: &Context, window_entity: Entity, canvas: &Canvas) {
+    if !cx.inspector.enabled {
+        return;
+    }
+
+    let target = cx.inspector.target(cx.hovered);
+
+    if !cx.entity_manager.is_alive(target) {
+        return;
+    }
+
+    let parent_window = if cx.tree.is_window(target) {
+        target
+    } else {
+        cx.tree.get_parent_window(target).unwrap_or(Entity::root())
+    };
+
+    if parent_window != window_entity {
+        return;
+    }
+
+    let bounds = cx.cache.get_bounds(target);
+    let rect: Rect = bounds.into();
+
+    canvas.save();
+    canvas.reset_matrix();
+
+    // Highlight the bounds of the inspected view.
+    let mut fill = Paint::default();
+    fill.set_anti_alias(true);
+    fill.set_color(Color::rgba(111, 168, 220, 96));
+    canvas.draw_rect(rect, &fill);
+
+    let mut stroke = Paint::default();
+    stroke.set_anti_alias(true);
+    stroke.set_style(PaintStyle::Stroke);
+    stroke.set_stroke_width(1.0);
+    stroke.set_color(if cx.inspector.pinned.is_some() {
+        Color::rgb(220, 96, 96)
+    } else {
+        Color::rgb(111, 168, 220)
+    });
+    canvas.draw_rect(rect, &stroke);
+
+    // Draw an info panel below the view, or above it if there is no room below.
+    let lines = inspector_lines(cx, target);
+    let scale = cx.scale_factor();
+    let font_size = INSPECTOR_FONT_SIZE * scale;
+    let line_height = INSPECTOR_LINE_HEIGHT * scale;
+    let padding = INSPECTOR_PADDING * scale;
+
+    let Some(typeface) =
+        cx.text_context.default_font_manager.legacy_make_typeface(None, FontStyle::default())
+    else {
+        canvas.restore();
+        return;
+    };
+
+    let font = Font::from_typeface(typeface, font_size);
+
+    let text_paint = {
+        let mut paint = Paint::default();
+        paint.set_anti_alias(true);
+        paint.set_color(Color::white());
+        paint
+    };
+
+    let panel_width =
+        lines.iter().map(|line| font.measure_str(line, Some(&text_paint)).0).fold(0.0f32, f32::max)
+            + 2.0 * padding;
+    let panel_height = lines.len() as f32 * line_height + 2.0 * padding;
+
+    let window_bounds = cx.cache.get_bounds(window_entity);
+
+    let panel_x = bounds.x.min(window_bounds.right() - panel_width).max(window_bounds.x);
+    let panel_y = if bounds.bottom() + panel_height <= window_bounds.bottom() {
+        bounds.bottom()
+    } else {
+        (bounds.y - panel_height).max(window_bounds.y)
+    };
+
+    let mut background = Paint::default();
+    background.set_color(Color::rgba(32, 32, 32, 220));
+    canvas.draw_rect(Rect::from_xywh(panel_x, panel_y, panel_width, panel_height), &background);
+
+    for (index, line) in lines.iter().enumerate() {
+        let baseline = panel_y + padding + (index as f32 + 1.0) * line_height - 0.25 * line_height;
+        canvas.draw_str(line, (panel_x + padding, baseline), &font, &text_paint);
+    }
+
+    canvas.restore();
+}
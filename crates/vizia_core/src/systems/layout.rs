@@ -38,9 +38,18 @@ pub(crate) fn layout_system(cx: &mut Context) {
             // Morphorm produces relative positions so convert to absolute.
             if let Some(parent) = cx.tree.get_layout_parent(entity) {
                 let parent_bounds = cx.cache.get_bounds(parent);
+                // Children of a right-to-left parent are mirrored horizontally within it.
+                let is_rtl =
+                    cx.style.direction.get(parent).copied().unwrap_or_default() == Direction::Rtl;
                 if let Some(bounds) = cx.cache.bounds.get_mut(entity) {
                     if let Some(relative_bounds) = cx.cache.relative_bounds.get(entity) {
-                        let x = relative_bounds.x + parent_bounds.x;
+                        let x = if is_rtl {
+                            parent_bounds.x + parent_bounds.w
+                                - relative_bounds.x
+                                - relative_bounds.w
+                        } else {
+                            relative_bounds.x + parent_bounds.x
+                        };
                         let y = relative_bounds.y + parent_bounds.y;
                         let w = relative_bounds.w;
                         let h = relative_bounds.h;
@@ -4,6 +4,7 @@ pub(crate) mod binding;
 pub(crate) mod draw;
 pub mod hover;
 pub(crate) mod image;
+pub(crate) mod inspector;
 pub(crate) mod layout;
 pub(crate) mod style;
 pub(crate) mod text;
@@ -13,6 +14,7 @@ pub(crate) use animation::*;
 pub(crate) use binding::*;
 pub(crate) use draw::*;
 pub use hover::*;
+pub(crate) use inspector::*;
 pub(crate) use layout::*;
 pub(crate) use style::*;
 pub(crate) use text::*;
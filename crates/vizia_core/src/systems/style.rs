@@ -221,7 +221,9 @@ impl<'s, 't, 'v> Element for Node<'s, 't, 'v> {
                     psudeo_class_flag.contains(PseudoClassFlags::USER_INVALID)
                 }
                 PseudoClass::Lang(_) => todo!(),
-                PseudoClass::Dir(_) => todo!(),
+                PseudoClass::Dir(direction) => {
+                    self.store.direction.get(self.entity).copied().unwrap_or_default() == *direction
+                }
                 PseudoClass::Custom(name) => {
                     println!("custom: {}", name);
                     todo!()
@@ -251,10 +253,18 @@ pub(crate) fn inline_inheritance_system(cx: &mut Context, redraw_entities: &mut
                 | cx.style.font_slant.inherit_inline(entity, parent)
                 | cx.style.font_width.inherit_inline(entity, parent)
                 | cx.style.text_decoration_line.inherit_inline(entity, parent)
+                | cx.style.line_height.inherit_inline(entity, parent)
+                | cx.style.letter_spacing.inherit_inline(entity, parent)
                 | cx.style.font_variation_settings.inherit_inline(entity, parent)
             {
                 cx.style.needs_text_update(entity);
             }
+
+            if cx.style.direction.inherit_inline(entity, parent) {
+                cx.style.needs_text_update(entity);
+                cx.style.needs_relayout();
+                redraw_entities.push(entity);
+            }
         }
     }
 }
@@ -270,11 +280,19 @@ pub(crate) fn shared_inheritance_system(cx: &mut Context, redraw_entities: &mut
                 | cx.style.font_slant.inherit_shared(entity, parent)
                 | cx.style.font_width.inherit_shared(entity, parent)
                 | cx.style.text_decoration_line.inherit_shared(entity, parent)
+                | cx.style.line_height.inherit_shared(entity, parent)
+                | cx.style.letter_spacing.inherit_shared(entity, parent)
                 | cx.style.font_variation_settings.inherit_shared(entity, parent)
             {
                 cx.style.needs_text_update(entity);
             }
 
+            if cx.style.direction.inherit_shared(entity, parent) {
+                cx.style.needs_text_update(entity);
+                cx.style.needs_relayout();
+                redraw_entities.push(entity);
+            }
+
             if cx.style.caret_color.inherit_shared(entity, parent)
                 | cx.style.selection_color.inherit_shared(entity, parent)
             {
@@ -497,6 +515,12 @@ fn link_style_data(
         should_redraw = true;
     }
 
+    if style.direction.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+        should_reflow = true;
+    }
+
     // Background
     if style.background_color.link(entity, matched_rules) {
         should_redraw = true;
@@ -573,6 +597,18 @@ fn link_style_data(
         should_reflow = true;
     }
 
+    if style.line_height.link(entity, matched_rules) {
+        should_redraw = true;
+        should_relayout = true;
+        should_reflow = true;
+    }
+
+    if style.letter_spacing.link(entity, matched_rules) {
+        should_redraw = true;
+        should_relayout = true;
+        should_reflow = true;
+    }
+
     if style.selection_color.link(entity, matched_rules) {
         should_redraw = true;
     }
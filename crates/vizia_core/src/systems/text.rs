@@ -2,7 +2,7 @@ use skia_safe::{
     font_arguments::VariationPosition,
     textlayout::{
         FontCollection, Paragraph, ParagraphBuilder, ParagraphStyle, RectHeightStyle,
-        RectWidthStyle, TextStyle,
+        RectWidthStyle, TextDirection, TextStyle,
     },
     BlendMode, FontArguments, FontStyle, Paint,
 };
@@ -177,6 +177,12 @@ pub fn build_paragraph(
         }
     }
 
+    // Text Direction
+    let is_rtl = style.direction.get(entity).copied().unwrap_or_default() == Direction::Rtl;
+    if is_rtl {
+        paragraph_style.set_text_direction(TextDirection::RTL);
+    }
+
     // Text Align
     paragraph_style.set_text_align(
         if let Some(text_align) = style.text_align.get(entity) {
@@ -184,9 +190,13 @@ pub fn build_paragraph(
         } else if let Some(Units::Stretch(_)) = style.child_left.get(entity) {
             if let Some(Units::Stretch(_)) = style.child_right.get(entity) {
                 TextAlign::Center
+            } else if is_rtl {
+                TextAlign::Left
             } else {
                 TextAlign::Right
             }
+        } else if is_rtl {
+            TextAlign::Right
         } else {
             TextAlign::Left
         }
@@ -271,6 +281,23 @@ fn add_block(
                 }
             }
 
+            // Line Height
+            if let Some(multiplier) = style
+                .line_height
+                .get(entity)
+                .and_then(|line_height| line_height.to_multiplier(font_size))
+            {
+                text_style.set_height_override(true);
+                text_style.set_height(multiplier);
+                // Distribute the extra leading evenly above and below the text of each line.
+                text_style.set_half_leading(true);
+            }
+
+            // Letter Spacing
+            if let Some(letter_spacing) = style.letter_spacing.get(entity).and_then(Length::to_px) {
+                text_style.set_letter_spacing(letter_spacing * style.scale_factor());
+            }
+
             // Font Variations
             if let Some(coordinates) = style.font_variation_settings.get(entity) {
                 let coordinates = coordinates.iter().map(|c| c.0).collect::<Vec<_>>();
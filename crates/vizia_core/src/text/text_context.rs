@@ -1,8 +1,14 @@
-use skia_safe::textlayout::{Paragraph, TypefaceFontProvider};
+use skia_safe::textlayout::{
+    Paragraph, ParagraphBuilder, ParagraphStyle, TextStyle, TypefaceFontProvider,
+};
 use skia_safe::{textlayout::FontCollection, FontMgr};
 use vizia_storage::SparseSet;
 
-use crate::{entity::Entity, layout::BoundingBox};
+use crate::{
+    entity::Entity,
+    layout::BoundingBox,
+    style::{FamilyOwned, GenericFontFamily},
+};
 
 pub struct TextContext {
     pub font_collection: FontCollection,
@@ -18,6 +24,39 @@ impl TextContext {
         &self.font_collection
     }
 
+    /// Shapes the text with the given font families and size, wrapping it to the maximum width if one is given,
+    /// and returns the width, height and number of lines of the laid out text. Sizes are in physical pixels.
+    /// Without any font families the default sans-serif font is used, as when drawing text.
+    pub(crate) fn measure_text(
+        &self,
+        text: &str,
+        font_families: &[FamilyOwned],
+        font_size: f32,
+        max_width: Option<f32>,
+    ) -> (f32, f32, usize) {
+        let font_families = if font_families.is_empty() {
+            &[FamilyOwned::Generic(GenericFontFamily::SansSerif)]
+        } else {
+            font_families
+        };
+
+        let mut text_style = TextStyle::new();
+        text_style.set_font_families(font_families);
+        text_style.set_font_size(font_size);
+
+        let mut paragraph_style = ParagraphStyle::default();
+        paragraph_style.set_text_style(&text_style);
+
+        let mut paragraph_builder = ParagraphBuilder::new(&paragraph_style, &self.font_collection);
+        paragraph_builder.push_style(&text_style);
+        paragraph_builder.add_text(text);
+
+        let mut paragraph = paragraph_builder.build();
+        paragraph.layout(max_width.unwrap_or(f32::MAX));
+
+        (paragraph.longest_line(), paragraph.height(), paragraph.line_number())
+    }
+
     pub(crate) fn set_text_bounds(&mut self, entity: Entity, bounds: BoundingBox) {
         self.text_bounds.insert(entity, bounds);
     }
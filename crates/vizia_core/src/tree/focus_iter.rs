@@ -3,7 +3,7 @@ use crate::prelude::Style;
 use crate::style::{Abilities, Display};
 use vizia_id::GenerationalId;
 use vizia_storage::{
-    DoubleEndedTreeTour, FocusTreeIterator, TourDirection, Tree, TreeExt, TreeTour,
+    DoubleEndedTreeTour, FocusTreeIterator, TourDirection, Tree, TreeExt, TreeIterator, TreeTour,
 };
 
 /// Should the user be able to navigate to the entity with tab?
@@ -38,6 +38,11 @@ pub(crate) fn is_navigatable(
         return false;
     }
 
+    // Skip widgets removed from the focus order
+    if style.focus_order.get(node).is_some_and(|order| *order < 0) {
+        return false;
+    }
+
     style
         .abilities
         .get(node)
@@ -92,3 +97,73 @@ pub(crate) fn focus_backward(
     iter.next_back();
     iter.filter(|node| is_navigatable(tree, style, *node, lock_focus_to)).next_back()
 }
+
+/// Get the entity to be focused when moving keyboard focus forward or backward from the focused entity,
+/// wrapping around at either end of the focus order.
+///
+/// Navigation is restricted to the subtree of the nearest ancestor which locks focus within itself.
+pub(crate) fn next_focus(
+    tree: &Tree<Entity>,
+    style: &Style,
+    focused: Entity,
+    forward: bool,
+) -> Entity {
+    let lock_focus_to = tree.lock_focus_within(focused);
+
+    if !style.focus_order.is_empty() {
+        return ordered_next_focus(tree, style, focused, forward, lock_focus_to);
+    }
+
+    let next = if forward {
+        focus_forward(tree, style, focused, lock_focus_to)
+    } else {
+        focus_backward(tree, style, focused, lock_focus_to)
+    };
+
+    next.or_else(|| {
+        let mut iter = TreeIterator::full(tree)
+            .filter(|node| is_navigatable(tree, style, *node, lock_focus_to));
+        if forward {
+            iter.next()
+        } else {
+            iter.next_back()
+        }
+    })
+    .unwrap_or(Entity::root())
+}
+
+// Views with a positive focus order are visited first, in ascending order, followed by the remaining views in tree
+// order.
+fn ordered_next_focus(
+    tree: &Tree<Entity>,
+    style: &Style,
+    focused: Entity,
+    forward: bool,
+    lock_focus_to: Entity,
+) -> Entity {
+    let mut nodes = TreeIterator::full(tree)
+        .filter(|node| {
+            is_navigatable(tree, style, *node, lock_focus_to)
+                && !node.parent_iter(tree).any(|ancestor| {
+                    style.display.get(ancestor).copied().unwrap_or_default() == Display::None
+                })
+        })
+        .collect::<Vec<_>>();
+
+    nodes.sort_by_key(|node| match style.focus_order.get(*node).copied() {
+        Some(order) if order > 0 => (0, order),
+        _ => (1, 0),
+    });
+
+    let position = nodes.iter().position(|node| *node == focused);
+    let next = match (position, forward) {
+        (Some(index), true) => nodes.get(index + 1).or(nodes.first()),
+        (Some(index), false) => {
+            index.checked_sub(1).and_then(|index| nodes.get(index)).or(nodes.last())
+        }
+        (None, true) => nodes.first(),
+        (None, false) => nodes.last(),
+    };
+
+    next.copied().unwrap_or(Entity::root())
+}
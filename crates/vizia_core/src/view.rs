@@ -182,6 +182,14 @@ pub trait View: 'static + Sized {
         None
     }
 
+    /// Returns true if the view takes text input while it has keyboard focus, such as a textbox.
+    ///
+    /// Hotkeys without a control, alt or super modifier are not triggered while such a view has focus, so that the
+    /// keys can be typed.
+    fn accepts_text_input(&self) -> bool {
+        false
+    }
+
     /// Handles any events received by the view.
     ///
     /// # Example
@@ -213,6 +221,25 @@ pub trait View: 'static + Sized {
     #[allow(unused_variables)]
     fn event(&mut self, cx: &mut EventContext, event: &mut Event) {}
 
+    /// Called when the view is removed from the tree, before any of its state is torn down.
+    ///
+    /// Views which hold resources such as GPU textures or file handles can release them here.
+    /// This is called for every view in a removed subtree, starting with the deepest descendants.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// pub struct CustomView{}
+    ///
+    /// impl View for CustomView {
+    ///     fn on_remove(&mut self, cx: &mut EventContext) {
+    ///         // Release any resources held by the view here.
+    ///     }
+    /// }
+    /// ```
+    #[allow(unused_variables)]
+    fn on_remove(&mut self, cx: &mut EventContext) {}
+
     /// Provides custom drawing for the view.
     ///
     /// Usually the look of a view is determined by the style and layout properties of the view. However, the `draw` method of
@@ -280,10 +307,18 @@ where
         <T as View>::element(self)
     }
 
+    fn accepts_text_input(&self) -> bool {
+        <T as View>::accepts_text_input(self)
+    }
+
     fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
         <T as View>::event(self, cx, event);
     }
 
+    fn on_remove(&mut self, cx: &mut EventContext) {
+        <T as View>::on_remove(self, cx);
+    }
+
     fn draw(&self, cx: &mut DrawContext, canvas: &Canvas) {
         <T as View>::draw(self, cx, canvas);
     }
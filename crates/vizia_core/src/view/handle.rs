@@ -78,7 +78,7 @@ impl<'a, V> Handle<'a, V> {
                         Entity::root(),
                     )
                 })
-                .unwrap_or(self.cx.focus_stack.pop().unwrap());
+                .unwrap_or_else(|| self.cx.focus_stack.pop().unwrap());
             self.cx.with_current(new_focus, |cx| cx.focus());
         }
         self
@@ -121,6 +121,16 @@ impl View for Button {
                 }
             }
 
+            // Buttons are also activated by the space key when focused.
+            WindowEvent::KeyUp(Code::Space, _) => {
+                if meta.target == cx.current {
+                    if let Some(action) = &self.action {
+                        (action)(cx);
+                    }
+                    meta.consume();
+                }
+            }
+
             WindowEvent::ActionRequest(action) => match action.action {
                 Action::Default => {
                     if let Some(action) = &self.action {
@@ -260,6 +260,21 @@ impl Handle<'_, Checkbox> {
     }
 }
 
+impl Editable for Checkbox {
+    type Value = bool;
+
+    fn on_edit_value<'a, F>(handle: Handle<'a, Self>, callback: F) -> Handle<'a, Self>
+    where
+        F: 'static + Fn(&mut EventContext, bool) + Send + Sync,
+    {
+        // The view is toggled, so the new value is the opposite of its current checked state.
+        handle.on_toggle(move |cx| {
+            let checked = cx.is_checked();
+            (callback)(cx, !checked);
+        })
+    }
+}
+
 impl View for Checkbox {
     fn element(&self) -> Option<&'static str> {
         Some("checkbox")
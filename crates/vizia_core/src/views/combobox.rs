@@ -268,7 +268,7 @@ where
                 }
             }
 
-            WindowEvent::KeyDown(code, _) => match code {
+            WindowEvent::KeyDown(code, _, _) => match code {
                 Code::ArrowDown => {
                     if !self.is_open {
                         self.is_open = true;
@@ -6,6 +6,9 @@ use crate::prelude::*;
 /// from one of several discrete options. The dropdown takes two closures, one which shows the current state
 /// regardless of whether the dropdown is open or closed, and one which shows the contents while it is open.
 ///
+/// While the dropdown is open, keyboard focus is kept within the popup so that its contents can be navigated
+/// with the tab key. Closing the dropdown returns focus to the view which was focused before it opened.
+///
 /// ## Basic Dropdown
 ///
 /// A basic dropdown displaying five options that the user can choose from.
@@ -148,7 +151,7 @@ impl Dropdown {
                         }
                     }
 
-                    WindowEvent::KeyDown(code, _) => {
+                    WindowEvent::KeyDown(code, _, _) => {
                         if *code == Code::Escape {
                             cx.emit(PopupEvent::Close);
                         }
@@ -171,7 +174,8 @@ impl Dropdown {
                     Popup::new(cx, |cx| {
                         (content)(cx);
                     })
-                    .arrow_size(Pixels(4.0));
+                    .arrow_size(Pixels(4.0))
+                    .lock_focus_to_within();
                 }
             })
         })
@@ -5,6 +5,7 @@ use crate::vg;
 use morphorm::Units;
 
 use crate::prelude::*;
+use crate::views::scrollview::SCROLL_SENSITIVITY;
 
 static DEFAULT_DRAG_SCALAR: f32 = 0.0042;
 static DEFAULT_WHEEL_SCALAR: f32 = 0.005;
@@ -122,6 +123,17 @@ impl<'a, L: Lens<Target = f32>> Handle<'a, Knob<L>> {
     }
 }
 
+impl<L: Lens<Target = f32>> Editable for Knob<L> {
+    type Value = f32;
+
+    fn on_edit_value<'a, F>(handle: Handle<'a, Self>, callback: F) -> Handle<'a, Self>
+    where
+        F: 'static + Fn(&mut EventContext, f32) + Send + Sync,
+    {
+        handle.on_changing(callback)
+    }
+}
+
 impl<L: Lens<Target = f32>> View for Knob<L> {
     fn element(&self) -> Option<&'static str> {
         Some("knob")
@@ -156,7 +168,7 @@ impl<L: Lens<Target = f32>> View for Knob<L> {
             }
 
             WindowEvent::MouseMove(_, y) => {
-                if self.is_dragging && !cx.is_disabled() {
+                if self.is_dragging && cx.drag_threshold_exceeded() && !cx.is_disabled() {
                     let mut delta_normal = (*y - self.prev_drag_y) * self.drag_scalar;
 
                     self.prev_drag_y = *y;
@@ -181,18 +193,28 @@ impl<L: Lens<Target = f32>> View for Knob<L> {
                 }
             }
 
+            WindowEvent::MouseScrollPixels(_, y) => {
+                if *y != 0.0 {
+                    let delta_normal = -*y / SCROLL_SENSITIVITY * self.wheel_scalar;
+
+                    let new_normal = self.continuous_normal - delta_normal;
+
+                    move_virtual_slider(self, cx, new_normal);
+                }
+            }
+
             WindowEvent::MouseDoubleClick(button) if *button == MouseButton::Left => {
                 self.is_dragging = false;
 
                 move_virtual_slider(self, cx, self.default_normal);
             }
 
-            WindowEvent::KeyDown(Code::ArrowUp | Code::ArrowRight, _) => {
+            WindowEvent::KeyDown(Code::ArrowUp | Code::ArrowRight, _, _) => {
                 self.continuous_normal = self.lens.get(cx);
                 move_virtual_slider(self, cx, self.continuous_normal + self.arrow_scalar);
             }
 
-            WindowEvent::KeyDown(Code::ArrowDown | Code::ArrowLeft, _) => {
+            WindowEvent::KeyDown(Code::ArrowDown | Code::ArrowLeft, _, _) => {
                 self.continuous_normal = self.lens.get(cx);
                 move_virtual_slider(self, cx, self.continuous_normal - self.arrow_scalar);
             }
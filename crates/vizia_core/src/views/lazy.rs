@@ -0,0 +1,142 @@
+use crate::prelude::*;
+
+/// Events used by the [`Lazy`] view to build and tear down its content.
+pub(crate) enum LazyEvent {
+    Show,
+    Hide,
+}
+
+/// A view which defers building its content until it is first shown.
+///
+/// The content of a lazy view is built from a closure the first time the bound boolean becomes true,
+/// which avoids building large, rarely visible sections of the UI, such as a settings page, upfront.
+///
+/// By default the content is torn down again when hidden, removing the views and any state they hold.
+/// Use [`Lazy::retained`] to keep the content alive while hidden, preserving its state across hide and show.
+///
+/// # Examples
+///
+/// ## Lazy content
+///
+/// The content is built when `AppData::show_settings` becomes true and removed when it becomes false.
+///
+/// ```
+/// # use vizia_core::prelude::*;
+/// #
+/// # #[derive(Lens)]
+/// # struct AppData {
+/// #     show_settings: bool,
+/// # }
+/// #
+/// # impl Model for AppData {}
+/// #
+/// # let cx = &mut Context::default();
+/// #
+/// # AppData { show_settings: false }.build(cx);
+/// #
+/// Lazy::new(cx, AppData::show_settings, |cx| {
+///     Label::new(cx, "Settings");
+/// });
+/// ```
+///
+/// ## Retained content
+///
+/// The content is built the first time `AppData::show_settings` becomes true and is hidden, rather than removed,
+/// when it becomes false.
+///
+/// ```
+/// # use vizia_core::prelude::*;
+/// #
+/// # #[derive(Lens)]
+/// # struct AppData {
+/// #     show_settings: bool,
+/// # }
+/// #
+/// # impl Model for AppData {}
+/// #
+/// # let cx = &mut Context::default();
+/// #
+/// # AppData { show_settings: false }.build(cx);
+/// #
+/// Lazy::retained(cx, AppData::show_settings, |cx| {
+///     Label::new(cx, "Settings");
+/// });
+/// ```
+#[derive(Lens)]
+pub struct Lazy {
+    built: bool,
+    retain: bool,
+}
+
+impl Lazy {
+    /// Creates a new lazy view which builds its content when `shown` becomes true and removes it when
+    /// `shown` becomes false.
+    pub fn new<L>(
+        cx: &mut Context,
+        shown: L,
+        content: impl 'static + Fn(&mut Context),
+    ) -> Handle<Self>
+    where
+        L: Lens<Source: 'static, Target = bool>,
+    {
+        Self::with_retain(cx, shown, false, content)
+    }
+
+    /// Creates a new lazy view which builds its content the first time `shown` becomes true and keeps it,
+    /// along with any state it holds, while `shown` is false.
+    pub fn retained<L>(
+        cx: &mut Context,
+        shown: L,
+        content: impl 'static + Fn(&mut Context),
+    ) -> Handle<Self>
+    where
+        L: Lens<Source: 'static, Target = bool>,
+    {
+        Self::with_retain(cx, shown, true, content)
+    }
+
+    fn with_retain<L>(
+        cx: &mut Context,
+        shown: L,
+        retain: bool,
+        content: impl 'static + Fn(&mut Context),
+    ) -> Handle<Self>
+    where
+        L: Lens<Source: 'static, Target = bool>,
+    {
+        Self { built: shown.get(cx), retain }
+            .build(cx, move |cx| {
+                Binding::new(cx, shown, |cx, shown| {
+                    cx.emit(if shown.get(cx) { LazyEvent::Show } else { LazyEvent::Hide });
+                });
+
+                Binding::new(cx, Lazy::built, move |cx, built| {
+                    if built.get(cx) {
+                        (content)(cx);
+                    }
+                });
+            })
+            .display(shown)
+    }
+}
+
+impl View for Lazy {
+    fn element(&self) -> Option<&'static str> {
+        Some("lazy")
+    }
+
+    fn event(&mut self, _cx: &mut EventContext, event: &mut Event) {
+        event.map(|lazy_event, meta| {
+            match lazy_event {
+                LazyEvent::Show => self.built = true,
+                LazyEvent::Hide => {
+                    if !self.retain {
+                        self.built = false;
+                    }
+                }
+            }
+
+            meta.consume();
+        });
+    }
+}
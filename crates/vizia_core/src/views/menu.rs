@@ -79,7 +79,7 @@ impl Submenu {
         content: impl Fn(&mut Context) -> Handle<V> + 'static,
         menu: impl Fn(&mut Context) + 'static,
     ) -> Handle<Self> {
-        let is_submenu = cx.data::<Submenu>().is_some();
+        let is_submenu = Self::is_in_menu_popup(cx);
 
         let handle = Self { is_open: false, open_on_hover: is_submenu, is_submenu }
             .build(cx, |cx| {
@@ -147,6 +147,27 @@ impl Submenu {
             handle
         }
     }
+
+    // Returns true if the current view is within the popup of a submenu or context menu, rather than within the
+    // content those views wrap.
+    fn is_in_menu_popup(cx: &Context) -> bool {
+        let mut in_popup = false;
+        for entity in cx.current().parent_iter(&cx.tree) {
+            let Some(view) = cx.views.get(&entity) else {
+                continue;
+            };
+
+            if view.downcast_ref::<Popup>().is_some() {
+                in_popup = true;
+            } else if view.downcast_ref::<Submenu>().is_some()
+                || view.downcast_ref::<ContextMenu>().is_some()
+            {
+                return in_popup;
+            }
+        }
+
+        false
+    }
 }
 
 impl View for Submenu {
@@ -175,7 +196,7 @@ impl View for Submenu {
                 }
             }
 
-            WindowEvent::KeyDown(code, _) => match code {
+            WindowEvent::KeyDown(code, _, _) => match code {
                 Code::ArrowLeft => {
                     // if cx.is_focused() {
                     if self.is_open {
@@ -274,3 +295,146 @@ impl View for MenuButton {
         });
     }
 }
+
+/// A horizontal line used to separate groups of items within a menu.
+pub struct MenuDivider {}
+
+impl MenuDivider {
+    /// Creates a new menu divider.
+    pub fn new(cx: &mut Context) -> Handle<Self> {
+        Self {}.build(cx, |cx| {
+            Element::new(cx).class("line");
+        })
+    }
+}
+
+impl View for MenuDivider {
+    fn element(&self) -> Option<&'static str> {
+        Some("menu-divider")
+    }
+}
+
+/// A view which opens a menu at the pointer when its content is right-clicked.
+///
+/// The menu is shown in a [`Popup`] and can contain [`MenuButton`], [`Submenu`] and [`MenuDivider`] views.
+/// It is closed when a menu button is pressed, when the pointer is pressed outside of the menu, or when the
+/// escape key is pressed. While the menu is open the up and down arrow keys move focus between its items.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// #
+/// # let cx = &mut Context::default();
+/// #
+/// ContextMenu::new(
+///     cx,
+///     |cx| Label::new(cx, "Right-click me"),
+///     |cx| {
+///         MenuButton::new(cx, |_| debug!("Cut"), |cx| Label::new(cx, "Cut"));
+///         MenuButton::new(cx, |_| debug!("Copy"), |cx| Label::new(cx, "Copy"));
+///         MenuDivider::new(cx);
+///         Submenu::new(
+///             cx,
+///             |cx| Label::new(cx, "Paste Special"),
+///             |cx| {
+///                 MenuButton::new(cx, |_| debug!("Paste Text"), |cx| Label::new(cx, "Paste Text"));
+///             },
+///         );
+///     },
+/// );
+/// ```
+#[derive(Lens)]
+pub struct ContextMenu {
+    is_open: bool,
+    position: (f32, f32),
+}
+
+impl ContextMenu {
+    /// Creates a new context menu which wraps the views built by `content` and shows the views built by `menu`
+    /// when right-clicked.
+    pub fn new<V: View>(
+        cx: &mut Context,
+        content: impl FnOnce(&mut Context) -> Handle<V>,
+        menu: impl Fn(&mut Context) + 'static,
+    ) -> Handle<Self> {
+        Self { is_open: false, position: (0.0, 0.0) }.build(cx, |cx| {
+            (content)(cx);
+            Binding::new(cx, ContextMenu::is_open, move |cx, is_open| {
+                if is_open.get(cx) {
+                    Popup::new(cx, |cx| {
+                        (menu)(cx);
+                    })
+                    .placement(Placement::Cursor)
+                    .arrow(false)
+                    .role(Role::Menu)
+                    .bind(ContextMenu::position, |handle, position| {
+                        let (x, y) = position.get(&handle);
+                        handle.left(Pixels(x)).top(Pixels(y));
+                    })
+                    .lock_focus_to_within()
+                    .on_blur(|cx| cx.emit(MenuEvent::CloseAll));
+                }
+            });
+        })
+    }
+
+    // Returns true if the hovered view is part of the open menu rather than the content.
+    fn is_over_menu(cx: &EventContext) -> bool {
+        cx.hovered.parent_iter(cx.tree).take_while(|entity| *entity != cx.current).any(|entity| {
+            cx.views.get(&entity).and_then(|view| view.downcast_ref::<Popup>()).is_some()
+        })
+    }
+}
+
+impl View for ContextMenu {
+    fn element(&self) -> Option<&'static str> {
+        Some("context-menu")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::MouseDown(MouseButton::Right) => {
+                if !Self::is_over_menu(cx) {
+                    let bounds = cx.bounds();
+                    let scale = cx.scale_factor();
+                    self.position = (
+                        (cx.mouse().cursor_x - bounds.x) / scale,
+                        (cx.mouse().cursor_y - bounds.y) / scale,
+                    );
+                    self.is_open = true;
+                }
+                meta.consume();
+            }
+
+            WindowEvent::KeyDown(Code::ContextMenu, _, _) => {
+                if !self.is_open {
+                    self.position = (0.0, 0.0);
+                    self.is_open = true;
+                    meta.consume();
+                }
+            }
+
+            WindowEvent::KeyDown(Code::ArrowDown, _, _) => {
+                if self.is_open {
+                    cx.focus_next();
+                    meta.consume();
+                }
+            }
+
+            WindowEvent::KeyDown(Code::ArrowUp, _, _) => {
+                if self.is_open {
+                    cx.focus_prev();
+                    meta.consume();
+                }
+            }
+
+            _ => {}
+        });
+
+        event.map(|menu_event, _| {
+            if let MenuEvent::CloseAll = menu_event {
+                self.is_open = false;
+            }
+        });
+    }
+}
@@ -14,9 +14,11 @@ mod element;
 mod image;
 mod knob;
 mod label;
+mod lazy;
 mod list;
 mod markdown;
 mod menu;
+mod modal;
 pub mod normalized_map;
 mod picklist;
 mod popup;
@@ -51,9 +53,11 @@ pub use element::Element;
 pub use image::*;
 pub use knob::{ArcTrack, Knob, KnobMode, TickKnob, Ticks};
 pub use label::Label;
+pub use lazy::Lazy;
 pub use list::*;
 pub use markdown::*;
 pub use menu::*;
+pub use modal::Modal;
 pub use picklist::{PickList, ScrollList};
 pub use popup::*;
 pub use progressbar::ProgressBar;
@@ -68,11 +72,11 @@ pub use switch::Switch;
 pub use tabview::{TabEvent, TabPair, TabView};
 pub use textbox::{TextEvent, Textbox};
 pub use toggle_button::{ToggleButton, ToggleButtonModifiers};
-pub use tooltip::Tooltip;
+pub use tooltip::{Tooltip, TooltipConfig};
 pub use virtual_list::*;
 pub use xypad::XYPad;
 
-use crate::prelude::Data;
+use crate::prelude::{Data, EmitContext, EventContext, Handle, View};
 
 /// The orientation of a widget, such as a slider or scrollbar
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Data)]
@@ -81,3 +85,55 @@ pub enum Orientation {
     Horizontal,
     Vertical,
 }
+
+/// A form control which shows a value read from a lens and reports when the user edits it, allowing it to be
+/// bound to model state in both directions with [`bind_mut`](Handle::bind_mut).
+pub trait Editable: View + Sized {
+    /// The type of the value edited with the view.
+    type Value;
+
+    /// Sets the callback triggered when the user edits the value of the view.
+    fn on_edit_value<'a, F>(handle: Handle<'a, Self>, callback: F) -> Handle<'a, Self>
+    where
+        F: 'static + Fn(&mut EventContext, Self::Value) + Send + Sync;
+}
+
+impl<V: Editable> Handle<'_, V> {
+    /// Writes edits of the view back to model state by emitting the event returned by `setter`, completing a
+    /// two-way binding with the lens the view was created with.
+    ///
+    /// Edits are sent as events rather than written to the model directly, so they are still handled by
+    /// [`Model::event`](crate::model::Model::event), and any undo or validation logic there applies to them.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// #
+    /// # #[derive(Lens)]
+    /// # struct AppData {
+    /// #     name: String,
+    /// #     volume: f32,
+    /// # }
+    /// #
+    /// # impl Model for AppData {}
+    /// #
+    /// # enum AppEvent {
+    /// #     SetName(String),
+    /// #     SetVolume(f32),
+    /// # }
+    /// #
+    /// # let cx = &mut Context::default();
+    /// #
+    /// # AppData { name: String::new(), volume: 0.5 }.build(cx);
+    /// #
+    /// Textbox::new(cx, AppData::name).bind_mut(AppEvent::SetName);
+    /// Slider::new(cx, AppData::volume).bind_mut(AppEvent::SetVolume);
+    /// ```
+    pub fn bind_mut<E, F>(self, setter: F) -> Self
+    where
+        E: 'static + Send,
+        F: 'static + Fn(V::Value) -> E + Send + Sync,
+    {
+        V::on_edit_value(self, move |cx, value| cx.emit(setter(value)))
+    }
+}
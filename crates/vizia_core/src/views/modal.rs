@@ -0,0 +1,104 @@
+use crate::context::TreeProps;
+use crate::prelude::*;
+
+/// A layer which blocks interaction with the content of a window while it is presented.
+///
+/// A modal fills its window with a scrim, drawn above all other views, which dims the content behind it and
+/// receives any pointer input outside of the modal content. Keyboard focus is trapped within the modal, and is
+/// returned to the previously focused view when the modal is dismissed.
+///
+/// Modals are created with [`Context::present_modal`] and closed with [`Context::dismiss_modal`],
+/// [`EventContext::dismiss_modal`], or by pressing the escape key. The entity returned by `present_modal` can be
+/// passed to [`EventContext::dismiss_modal_entity`] to close a specific modal.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// #
+/// # let cx = &mut Context::default();
+/// #
+/// cx.present_modal(|cx| {
+///     VStack::new(cx, |cx| {
+///         Label::new(cx, "Discard unsaved changes?");
+///         Button::new(cx, |cx| Label::new(cx, "Discard"))
+///             .on_press(|cx| cx.dismiss_modal());
+///     })
+///     .class("modal-content");
+/// });
+/// ```
+pub struct Modal {}
+
+impl Modal {
+    pub(crate) fn new(cx: &mut Context, content: impl FnOnce(&mut Context)) -> Handle<Self> {
+        Self {}
+            .build(cx, |cx| {
+                (content)(cx);
+            })
+            .role(Role::Dialog)
+            .lock_focus_to_within()
+    }
+}
+
+impl View for Modal {
+    fn element(&self) -> Option<&'static str> {
+        Some("modal")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::KeyDown(Code::Escape, _, _) => {
+                cx.dismiss_modal();
+                meta.consume();
+            }
+
+            // Stop pointer input on the scrim from reaching the content behind the modal.
+            WindowEvent::MouseDown(_)
+            | WindowEvent::MouseUp(_)
+            | WindowEvent::MouseDoubleClick(_)
+            | WindowEvent::MouseTripleClick(_)
+            | WindowEvent::MouseScroll(_, _)
+            | WindowEvent::MouseScrollPixels(_, _)
+            | WindowEvent::Zoom(_)
+            | WindowEvent::Rotate(_)
+            | WindowEvent::Touch { .. } => {
+                meta.consume();
+            }
+
+            _ => {}
+        });
+    }
+
+    fn on_remove(&mut self, cx: &mut EventContext) {
+        let modal = cx.current();
+        if let Some(window_state) =
+            cx.parent_window().and_then(|window_entity| cx.windows.get_mut(&window_entity))
+        {
+            window_state.modals.retain(|entity| *entity != modal);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::context::WindowState;
+    use crate::prelude::*;
+
+    #[test]
+    fn dismissing_modal_returns_focus() {
+        let cx = &mut Context::default();
+        cx.windows.insert(Entity::root(), WindowState::default());
+
+        let previous = Element::new(cx).navigable(true).entity();
+        cx.focus_entity(previous);
+        assert_eq!(cx.focused, previous);
+
+        let mut child = Entity::null();
+        let modal = cx.present_modal(|cx| {
+            child = Element::new(cx).navigable(true).entity();
+        });
+        assert_eq!(cx.focused, child);
+
+        cx.dismiss_modal_entity(modal);
+        assert_eq!(cx.focused, previous);
+    }
+}
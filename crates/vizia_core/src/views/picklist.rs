@@ -1,45 +1,134 @@
 use std::ops::Deref;
+use std::rc::Rc;
 
 use crate::icons::ICON_CHEVRON_DOWN;
 use crate::prelude::*;
 
-pub struct PickList {
+#[derive(Lens)]
+pub struct PickList<L1: Lens, L2: Lens, T: 'static> {
+    #[lens(ignore)]
     on_select: Option<Box<dyn Fn(&mut EventContext, usize)>>,
+    // Returns the text of each option, used to match options against the search text.
+    #[lens(ignore)]
+    option_text: Box<dyn Fn(&mut EventContext) -> Vec<String>>,
+    // Lens to the list of options.
+    list_lens: L1,
+    // Lens to the index of the selected option, if any.
+    selected: L2,
+    // Text shown when no option is selected.
+    placeholder: String,
+    // Builds the content of each option.
+    item_content: Rc<dyn Fn(&mut Context, MapRef<L1, T>, usize)>,
+    // Builds the content of the picklist button for the selected option.
+    selected_content: Rc<dyn Fn(&mut Context, MapRef<L1, T>, usize)>,
+    // Converts an option to its text, if set, instead of its localized string.
+    format: Option<Rc<dyn Fn(&T) -> String>>,
+    // Changed when the content of the picklist is replaced, to rebuild it.
+    content_version: usize,
+    // Whether the options are open.
+    is_open: bool,
+    // Whether typing while the options are open filters them.
+    searchable: bool,
+    // Text typed to filter the options.
+    search_text: String,
+    // Index of the option highlighted with the arrow keys.
+    highlighted: usize,
 }
 
 pub enum PickListEvent {
     SetOption(usize),
 }
 
-impl PickList {
-    pub fn new<L1, L2, T>(
-        cx: &mut Context,
-        list_lens: L1,
-        selected: L2,
-        show_handle: bool,
-    ) -> Handle<Self>
-    where
-        L1: Lens,
-        L1::Target: Deref<Target = [T]> + Data,
-        T: 'static + Data + ToStringLocalized,
-        L2: Lens<Target = usize>,
-    {
-        Self { on_select: None }.build(cx, |cx| {
+enum PickListEventInternal {
+    // Sent by the dropdown of the picklist when its options are opened or closed.
+    SetOpen(bool),
+    // Sent by an option of the picklist when it gains keyboard focus.
+    SetHighlighted(usize),
+}
+
+// Returns true if the text of an option matches the search text.
+fn matches_search(text: &str, search_text: &str) -> bool {
+    text.to_lowercase().starts_with(&search_text.to_lowercase())
+}
+
+impl<L1, L2, T> PickList<L1, L2, T>
+where
+    L1: Lens,
+    L1::Target: Deref<Target = [T]> + Data,
+    L2: Lens,
+    L2::Target: Data + Into<Option<usize>>,
+    T: 'static + Data + ToStringLocalized,
+{
+    /// Creates a new picklist with the given list of options and the index of the selected option.
+    ///
+    /// The selected index may be a `usize`, or an `Option<usize>` where `None` shows the
+    /// [`placeholder`](Handle::placeholder) text until an option is selected. The placeholder is also shown
+    /// while the selected index is out of bounds of the list, for example when the list shrinks before the
+    /// selection is updated, so the selection doesn't need to be cleared in the same event as the list.
+    ///
+    /// The lens to each option passed to the content closures yields no data, rather than panicking, once its
+    /// index is out of bounds, so the content should read it with [`get_ref`](ResGet::get_ref) when the list
+    /// can shrink.
+    ///
+    /// By default each option, and the selected option within the picklist button, is shown as text. Use
+    /// [`item_content`](Handle::item_content) and [`selected_content`](Handle::selected_content) to build
+    /// custom views instead.
+    pub fn new(cx: &mut Context, list_lens: L1, selected: L2, show_handle: bool) -> Handle<Self> {
+        Self {
+            on_select: None,
+            option_text: Box::new(move |cx| {
+                list_lens.get(cx).iter().map(|item| item.to_string_local(cx)).collect()
+            }),
+            list_lens,
+            selected,
+            placeholder: String::new(),
+            item_content: Rc::new(|cx, item, _| {
+                Label::new(cx, item).hoverable(false);
+            }),
+            selected_content: Rc::new(|cx, item, _| {
+                Label::new(cx, item).hoverable(false);
+            }),
+            format: None,
+            content_version: 0,
+            is_open: false,
+            searchable: false,
+            search_text: String::new(),
+            highlighted: Into::<Option<usize>>::into(selected.get(cx)).unwrap_or_default(),
+        }
+        .build(cx, |cx| {
+            let picklist = cx.current();
+
             // Dropdown List
             Dropdown::new(
                 cx,
                 move |cx| {
                     Button::new(cx, |cx| {
-                        // A Label and an optional Icon
+                        // The selected content and an optional Icon
                         HStack::new(cx, move |cx| {
-                            Label::new(cx, "")
-                                .bind(list_lens, move |handle, list| {
-                                    handle.bind(selected, move |handle, sel| {
-                                        let selected_index = sel.get(&handle);
-                                        handle.text(list.idx(selected_index));
+                            Binding::new(cx, Self::content_version, move |cx, _| {
+                                // Rebuilt when the list changes length so that a selected index which is
+                                // now out of bounds shows the placeholder.
+                                let list_len = list_lens.map(|list| list.len());
+                                Binding::new(cx, list_len, move |cx, list_len| {
+                                    Binding::new(cx, selected, move |cx, selected| {
+                                        let index: Option<usize> = selected.get(cx).into();
+                                        let index = index.filter(|index| {
+                                            list_len.get_ref(cx).is_some_and(|len| *index < *len)
+                                        });
+
+                                        if let Some(index) = index {
+                                            let selected_content = Self::selected_content.get(cx);
+                                            let item =
+                                                list_lens.try_map_ref(move |list| list.get(index));
+                                            (selected_content)(cx, item, index);
+                                        } else {
+                                            Label::new(cx, Self::placeholder)
+                                                .class("placeholder")
+                                                .hoverable(false);
+                                        }
                                     });
-                                })
-                                .hoverable(false);
+                                });
+                            });
                             if show_handle {
                                 Svg::new(cx, ICON_CHEVRON_DOWN)
                                     .class("icon")
@@ -53,31 +142,123 @@ impl PickList {
                         .col_between(Stretch(1.0))
                     })
                     .width(Stretch(1.0))
-                    .on_press(|cx| cx.emit(PopupEvent::Switch));
+                    .on_press(|cx| cx.emit(PopupEvent::Switch))
+                    .bind(PopupData::is_open, move |handle, is_open| {
+                        let is_open = is_open.get(&handle);
+                        handle.cx.emit_to(picklist, PickListEventInternal::SetOpen(is_open));
+                    });
                 },
                 move |cx| {
+                    if Self::searchable.get(cx) {
+                        Label::new(cx, Self::search_text)
+                            .class("search")
+                            .display(Self::search_text.map(|text| !text.is_empty()));
+                    }
+
+                    let item_content = Self::item_content.get(cx);
+                    let format = Self::format.get(cx);
                     ScrollView::new(cx, 0.0, 0.0, false, true, move |cx| {
                         List::new(cx, list_lens, move |cx, index, item| {
-                            Label::new(cx, item)
-                                .child_top(Stretch(1.0))
-                                .child_bottom(Stretch(1.0))
-                                .checked(selected.map(move |selected| *selected == index))
+                            let text = match &format {
+                                Some(format) => (format)(&item.get(cx)),
+                                None => item.get(cx).to_string_local(cx),
+                            };
+                            let item_content = item_content.clone();
+                            HStack::new(cx, move |cx| (item_content)(cx, item, index))
+                                .class("option")
+                                .checked(selected.map(move |selected| {
+                                    Into::<Option<usize>>::into(selected.clone()) == Some(index)
+                                }))
+                                .toggle_class(
+                                    "nav",
+                                    Self::highlighted.map(move |highlighted| *highlighted == index),
+                                )
+                                .display(
+                                    Self::search_text
+                                        .map(move |search_text| matches_search(&text, search_text)),
+                                )
                                 .navigable(true)
+                                .role(Role::ListBoxOption)
+                                // Keep keyboard focus on the highlighted option so that it is announced.
+                                .bind(Self::highlighted, move |handle, highlighted| {
+                                    if highlighted.get(&handle) == index {
+                                        let entity = handle.entity();
+                                        handle.cx.with_current(entity, |cx| cx.focus());
+                                    }
+                                })
+                                .on_focus_in(move |cx| {
+                                    cx.emit(PickListEventInternal::SetHighlighted(index))
+                                })
                                 .on_press(move |cx| {
                                     cx.emit(PickListEvent::SetOption(index));
                                     cx.emit(PopupEvent::Close);
                                 });
                         });
                     })
-                    .height(Auto);
+                    .height(Auto)
+                    .role(Role::ListBox);
                 },
             )
             .width(Stretch(1.0));
         })
+        .role(Role::ComboBox)
+        .bind(Self::content_version, move |handle, _| {
+            let format = Self::format.get(&handle);
+            handle.bind(list_lens, move |handle, list| {
+                let format = format.clone();
+                handle.bind(selected, move |handle, sel| {
+                    let selected_index: Option<usize> = sel.get(&handle).into();
+                    // A stale index which is out of bounds of the list shows the placeholder.
+                    let text = selected_index.and_then(|index| {
+                        list.idx(index).get_ref(&handle).map(|item| match &format {
+                            Some(format) => (format)(item.deref()),
+                            None => item.to_string_local(&handle),
+                        })
+                    });
+                    match text {
+                        Some(text) => handle.text_value(text),
+                        None => handle.text_value(Self::placeholder),
+                    };
+                });
+            });
+        })
+    }
+}
+
+impl<L1, L2, T> PickList<L1, L2, T>
+where
+    L1: Lens,
+    L2: Lens,
+    L2::Target: Clone + Into<Option<usize>>,
+    T: 'static,
+{
+    /// Returns the bounding box of the selected option of the picklist with the given entity.
+    ///
+    /// The bounds are in physical window coordinates and reflect the current scroll position of the options.
+    /// Returns `None` if the dropdown of the picklist is closed.
+    pub fn selected_option_bounds(cx: &EventContext, picklist: Entity) -> Option<BoundingBox> {
+        picklist
+            .branch_iter(cx.tree)
+            .skip(1)
+            .find(|entity| {
+                cx.style.classes.get(*entity).is_some_and(|classes| classes.contains("option"))
+                    && cx
+                        .style
+                        .pseudo_classes
+                        .get(*entity)
+                        .is_some_and(|classes| classes.contains(PseudoClassFlags::CHECKED))
+            })
+            .map(|entity| cx.cache.get_bounds(entity))
     }
 }
 
-impl View for PickList {
+impl<L1, L2, T> View for PickList<L1, L2, T>
+where
+    L1: Lens,
+    L2: Lens,
+    L2::Target: Clone + Into<Option<usize>>,
+    T: 'static,
+{
     fn element(&self) -> Option<&'static str> {
         Some("picklist")
     }
@@ -90,15 +271,219 @@ impl View for PickList {
                 }
             }
         });
+
+        event.map(|picklist_event, _| match picklist_event {
+            PickListEventInternal::SetOpen(is_open) => {
+                self.is_open = *is_open;
+                self.search_text.clear();
+                self.highlighted = self.selected_index(cx).unwrap_or_default();
+                cx.style.needs_access_update(cx.current());
+            }
+
+            PickListEventInternal::SetHighlighted(index) => {
+                self.highlighted = *index;
+            }
+        });
+
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::CharInput(c) if self.is_open && self.searchable && !c.is_control() => {
+                self.search_text.push(*c);
+                self.highlight_first_match(cx);
+                meta.consume();
+            }
+
+            WindowEvent::KeyDown(Code::Backspace, _, _) if self.is_open && self.searchable => {
+                self.search_text.pop();
+                self.highlight_first_match(cx);
+                meta.consume();
+            }
+
+            WindowEvent::KeyDown(Code::Enter | Code::NumpadEnter, _, _) if self.is_open => {
+                if self.matching_options(cx).contains(&self.highlighted) {
+                    cx.emit(PickListEvent::SetOption(self.highlighted));
+                    // Close the dropdown, which is a descendant of the picklist.
+                    cx.emit_custom(
+                        Event::new(PopupEvent::Close)
+                            .target(cx.current())
+                            .propagate(Propagation::Subtree),
+                    );
+                }
+                meta.consume();
+            }
+
+            // Move the highlight between the (matching) options.
+            WindowEvent::KeyDown(code, _, _) if self.is_open => {
+                let matches = self.matching_options(cx);
+                let next = match code {
+                    Code::ArrowDown => matches.iter().find(|index| **index > self.highlighted),
+                    Code::ArrowUp => matches.iter().rev().find(|index| **index < self.highlighted),
+                    Code::Home => matches.first(),
+                    Code::End => matches.last(),
+                    _ => return,
+                };
+
+                if let Some(index) = next {
+                    self.highlighted = *index;
+                }
+                meta.consume();
+            }
+
+            // Change the selected option in place while closed, like a native dropdown.
+            WindowEvent::KeyDown(code, _, _) => {
+                let len = (self.option_text)(cx).len();
+                let selected = self.selected_index(cx);
+                let next = match code {
+                    Code::ArrowDown => selected.map_or(0, |selected| selected + 1),
+                    Code::ArrowUp => selected.map_or(0, |selected| selected.saturating_sub(1)),
+                    Code::Home => 0,
+                    Code::End => len.saturating_sub(1),
+                    _ => return,
+                };
+
+                if next < len && Some(next) != selected {
+                    cx.emit(PickListEvent::SetOption(next));
+                }
+                meta.consume();
+            }
+
+            _ => {}
+        });
+    }
+
+    fn accessibility(&self, _cx: &mut AccessContext, node: &mut AccessNode) {
+        node.set_expanded(self.is_open);
+    }
+}
+
+impl<L1, L2, T> PickList<L1, L2, T>
+where
+    L1: Lens,
+    L2: Lens,
+    L2::Target: Clone + Into<Option<usize>>,
+    T: 'static,
+{
+    // Returns the index of the selected option, if any.
+    fn selected_index(&self, cx: &mut EventContext) -> Option<usize> {
+        self.selected.get(cx).into()
+    }
+
+    // Returns the indices of the options which match the search text.
+    fn matching_options(&self, cx: &mut EventContext) -> Vec<usize> {
+        (self.option_text)(cx)
+            .iter()
+            .enumerate()
+            .filter(|(_, text)| matches_search(text, &self.search_text))
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    // Highlights the first option which matches the search text, if any.
+    fn highlight_first_match(&mut self, cx: &mut EventContext) {
+        if let Some(index) = self.matching_options(cx).first() {
+            self.highlighted = *index;
+        }
     }
 }
 
-impl<'v> Handle<'v, PickList> {
+impl<'v, L1, L2, T> Handle<'v, PickList<L1, L2, T>>
+where
+    L1: Lens,
+    L2: Lens,
+    L2::Target: Clone + Into<Option<usize>>,
+    T: 'static,
+{
+    /// Sets the callback triggered when an option is selected, either with the pointer or the keyboard.
+    ///
+    /// While the options are open, the arrow, home and end keys move the highlight between the options and the
+    /// enter key selects the highlighted one. While closed, the same keys change the selected option in place.
     pub fn on_select<F>(self, callback: F) -> Self
     where
         F: 'static + Fn(&mut EventContext, usize),
     {
-        self.modify(|picklist: &mut PickList| picklist.on_select = Some(Box::new(callback)))
+        self.modify(|picklist| picklist.on_select = Some(Box::new(callback)))
+    }
+
+    /// Sets whether typing while the options are open filters them to those starting with the typed text.
+    ///
+    /// While searching, the arrow keys move the highlight between the matching options only. Defaults to `false`.
+    pub fn searchable(self, searchable: bool) -> Self {
+        self.modify(|picklist| picklist.searchable = searchable)
+    }
+
+    /// Sets the text shown in place of the selected option when the selected index is `None` or out of range.
+    pub fn placeholder(self, placeholder: impl Into<String>) -> Self {
+        self.modify(|picklist| picklist.placeholder = placeholder.into())
+    }
+
+    /// Sets the closure used to convert each option to the text shown for it and matched against when searching.
+    ///
+    /// This allows binding to a list of any [`Data`] type, such as an enum, without converting it to strings first.
+    /// The text replaces any content previously set with [`item_content`](Self::item_content) or
+    /// [`selected_content`](Self::selected_content).
+    ///
+    /// # Example
+    /// ```ignore
+    /// PickList::new(cx, AppData::units, AppData::selected_unit, true)
+    ///     .format(|unit: &Unit| unit.symbol().to_string())
+    ///     .on_select(|cx, index| cx.emit(AppEvent::SetUnit(index)));
+    /// ```
+    pub fn format<F>(self, format: F) -> Self
+    where
+        F: 'static + Fn(&T) -> String,
+        L1::Target: Deref<Target = [T]> + Data,
+        T: Data,
+    {
+        self.modify(|picklist| {
+            let format: Rc<dyn Fn(&T) -> String> = Rc::new(format);
+            let list_lens = picklist.list_lens;
+
+            let option_format = format.clone();
+            picklist.option_text = Box::new(move |cx| {
+                list_lens.get(cx).iter().map(|item| (option_format)(item)).collect()
+            });
+
+            let item_format = format.clone();
+            let text_content: Rc<dyn Fn(&mut Context, MapRef<L1, T>, usize)> =
+                Rc::new(move |cx, item, _| {
+                    let item_format = item_format.clone();
+                    Label::new(cx, item.map(move |item| (item_format)(item))).hoverable(false);
+                });
+            picklist.item_content = text_content.clone();
+            picklist.selected_content = text_content;
+
+            picklist.format = Some(format);
+            picklist.content_version += 1;
+        })
+    }
+
+    /// Sets the closure used to build the content of each option, given a lens to the option and its index.
+    ///
+    /// # Example
+    /// ```ignore
+    /// PickList::new(cx, AppData::colors, AppData::selected_color, true).item_content(|cx, item, _| {
+    ///     HStack::new(cx, |cx| {
+    ///         Element::new(cx).size(Pixels(16.0)).background_color(item.map(|color| color.value));
+    ///         Label::new(cx, item.map(|color| color.name.clone()));
+    ///     });
+    /// });
+    /// ```
+    pub fn item_content<F>(self, content: F) -> Self
+    where
+        F: 'static + Fn(&mut Context, MapRef<L1, T>, usize),
+    {
+        self.modify(|picklist| picklist.item_content = Rc::new(content))
+    }
+
+    /// Sets the closure used to build the content shown within the picklist for the selected option, given a lens
+    /// to the option and its index.
+    pub fn selected_content<F>(self, content: F) -> Self
+    where
+        F: 'static + Fn(&mut Context, MapRef<L1, T>, usize),
+    {
+        self.modify(|picklist| {
+            picklist.selected_content = Rc::new(content);
+            picklist.content_version += 1;
+        })
     }
 }
 
@@ -269,6 +269,19 @@ impl View for Popup {
                         (parent_bounds.width() / scale) + arrow_size,
                         -(bounds.height() - parent_bounds.height()) / scale,
                     ),
+                    // Open towards the top or left of the cursor if there isn't enough room.
+                    Placement::Cursor => (
+                        if bounds.right() > window_bounds.right() {
+                            -bounds.width() / scale
+                        } else {
+                            0.0
+                        },
+                        if bounds.bottom() > window_bounds.bottom() {
+                            -bounds.height() / scale
+                        } else {
+                            0.0
+                        },
+                    ),
 
                     _ => (0.0, 0.0),
                 };
@@ -458,7 +471,7 @@ impl<'a> Handle<'a, Popup> {
                         }
                     }
 
-                    WindowEvent::KeyDown(code, _) => {
+                    WindowEvent::KeyDown(code, _, _) => {
                         if *code == Code::Escape {
                             (focus_event)(cx);
                         }
@@ -67,7 +67,7 @@ impl View for Rating {
         });
 
         event.map(|window_event, _| match window_event {
-            WindowEvent::KeyDown(code, _) => match code {
+            WindowEvent::KeyDown(code, _, _) => match code {
                 Code::ArrowLeft => {
                     cx.emit(RatingEvent::Decrement);
                 }
@@ -94,6 +94,13 @@ impl<L1: Lens<Target = f32>> Scrollbar<L1> {
         }
     }
 
+    // Returns true if the thumb of a horizontal scrollbar is mirrored by a right-to-left direction, in which case
+    // moving the cursor to the right decreases the value.
+    fn is_mirrored(&self, cx: &EventContext) -> bool {
+        self.orientation == Orientation::Horizontal
+            && cx.style.direction.get(cx.current()).copied().unwrap_or_default() == Direction::Rtl
+    }
+
     fn change(&mut self, cx: &mut EventContext, new_val: f32) {
         if let Some(callback) = &self.on_changing {
             callback(cx, new_val.clamp(0.0, 1.0));
@@ -114,7 +121,9 @@ impl<L1: 'static + Lens<Target = f32>> View for Scrollbar<L1> {
         });
 
         event.map(|window_event, meta| {
+            let mirrored = self.is_mirrored(cx);
             let pos = match &self.orientation {
+                Orientation::Horizontal if mirrored => -cx.mouse.cursor_x,
                 Orientation::Horizontal => cx.mouse.cursor_x,
                 Orientation::Vertical => cx.mouse.cursor_y,
             };
@@ -143,6 +152,7 @@ impl<L1: 'static + Lens<Target = f32>> View for Scrollbar<L1> {
                             Orientation::Horizontal => {
                                 let px = cx.mouse.cursor_x - cx.bounds().x - thumb_bounds.w / 2.0;
                                 let x = (px / sx).clamp(0.0, 1.0);
+                                let x = if mirrored { 1.0 - x } else { x };
                                 if let Some(callback) = &self.on_changing {
                                     (callback)(cx, x);
                                 }
@@ -162,6 +172,7 @@ impl<L1: 'static + Lens<Target = f32>> View for Scrollbar<L1> {
                         let t = self.thumb_bounds(cx);
                         let physical_delta = match &self.orientation {
                             Orientation::Horizontal => {
+                                let jump = if mirrored { -jump } else { jump };
                                 if cx.mouse.cursor_x < t.x {
                                     -jump
                                 } else if cx.mouse.cursor_x >= t.x + t.w {
@@ -213,6 +224,7 @@ impl<L1: 'static + Lens<Target = f32>> View for Scrollbar<L1> {
                                     let px =
                                         cx.mouse.cursor_x - cx.bounds().x - thumb_bounds.w / 2.0;
                                     let x = (px / sx).clamp(0.0, 1.0);
+                                    let x = if mirrored { 1.0 - x } else { x };
                                     if let Some(callback) = &self.on_changing {
                                         (callback)(cx, x);
                                     }
@@ -112,6 +112,31 @@ impl ScrollView {
         )
     }
 
+    fn scroll_by(&mut self, cx: &mut EventContext, meta: &mut EventMeta, x: f32, y: f32) {
+        cx.set_active(true);
+        let (x, y) = if cx.modifiers.shift() { (-y, -x) } else { (-x, -y) };
+
+        // What percentage of the negative space does this cross?
+        if x != 0.0 && self.inner_width > self.container_width {
+            let negative_space = self.inner_width - self.container_width;
+            if negative_space != 0.0 {
+                let logical_delta = x / negative_space;
+                cx.emit(ScrollEvent::ScrollX(logical_delta));
+            }
+            // Prevent event propagating to ancestor scrollviews.
+            meta.consume();
+        }
+        if y != 0.0 && self.inner_height > self.container_height {
+            let negative_space = self.inner_height - self.container_height;
+            if negative_space != 0.0 {
+                let logical_delta = y / negative_space;
+                cx.emit(ScrollEvent::ScrollY(logical_delta));
+            }
+            // Prevent event propagating to ancestor scrollviews.
+            meta.consume();
+        }
+    }
+
     fn reset(&mut self) {
         if self.inner_width == self.container_width {
             self.scroll_x = 0.0;
@@ -200,28 +225,11 @@ impl View for ScrollView {
             }
 
             WindowEvent::MouseScroll(x, y) => {
-                cx.set_active(true);
-                let (x, y) = if cx.modifiers.shift() { (-*y, -*x) } else { (-*x, -*y) };
-
-                // What percentage of the negative space does this cross?
-                if x != 0.0 && self.inner_width > self.container_width {
-                    let negative_space = self.inner_width - self.container_width;
-                    if negative_space != 0.0 {
-                        let logical_delta = x * SCROLL_SENSITIVITY / negative_space;
-                        cx.emit(ScrollEvent::ScrollX(logical_delta));
-                    }
-                    // Prevent event propagating to ancestor scrollviews.
-                    meta.consume();
-                }
-                if y != 0.0 && self.inner_height > self.container_height {
-                    let negative_space = self.inner_height - self.container_height;
-                    if negative_space != 0.0 {
-                        let logical_delta = y * SCROLL_SENSITIVITY / negative_space;
-                        cx.emit(ScrollEvent::ScrollY(logical_delta));
-                    }
-                    // Prevent event propagating to ancestor scrollviews.
-                    meta.consume();
-                }
+                self.scroll_by(cx, meta, *x * SCROLL_SENSITIVITY, *y * SCROLL_SENSITIVITY);
+            }
+
+            WindowEvent::MouseScrollPixels(x, y) => {
+                self.scroll_by(cx, meta, *x, *y);
             }
 
             WindowEvent::MouseOut => {
@@ -213,6 +213,24 @@ where
         })
         .navigable(true)
     }
+
+    // Returns true if a horizontal slider is mirrored by a right-to-left direction, in which case the minimum
+    // value is on the right.
+    fn is_mirrored(&self, cx: &EventContext) -> bool {
+        self.internal.orientation == Orientation::Horizontal
+            && cx.style.direction.get(cx.current()).copied().unwrap_or_default() == Direction::Rtl
+    }
+}
+
+impl<L: Lens<Target = f32>> Editable for Slider<L> {
+    type Value = f32;
+
+    fn on_edit_value<'a, F>(handle: Handle<'a, Self>, callback: F) -> Handle<'a, Self>
+    where
+        F: 'static + Fn(&mut EventContext, f32) + Send + Sync,
+    {
+        handle.on_changing(callback)
+    }
 }
 
 impl<L: Lens<Target = f32>> View for Slider<L> {
@@ -295,6 +313,9 @@ impl<L: Lens<Target = f32>> View for Slider<L> {
                     };
 
                     dx = dx.clamp(0.0, 1.0);
+                    if self.is_mirrored(cx) {
+                        dx = 1.0 - dx;
+                    }
 
                     let mut val = min + dx * (max - min);
 
@@ -319,7 +340,7 @@ impl<L: Lens<Target = f32>> View for Slider<L> {
             }
 
             WindowEvent::MouseMove(x, y) => {
-                if self.is_dragging {
+                if self.is_dragging && cx.drag_threshold_exceeded() {
                     let thumb_size = self.internal.thumb_size;
 
                     let min = self.internal.range.start;
@@ -343,6 +364,9 @@ impl<L: Lens<Target = f32>> View for Slider<L> {
                     };
 
                     dx = dx.clamp(0.0, 1.0);
+                    if self.is_mirrored(cx) {
+                        dx = 1.0 - dx;
+                    }
 
                     let mut val = min + dx * (max - min);
 
@@ -355,7 +379,7 @@ impl<L: Lens<Target = f32>> View for Slider<L> {
                 }
             }
 
-            WindowEvent::KeyDown(Code::ArrowUp | Code::ArrowRight, _) => {
+            WindowEvent::KeyDown(Code::ArrowUp | Code::ArrowRight, _, _) => {
                 let min = self.internal.range.start;
                 let max = self.internal.range.end;
                 let step = self.internal.step;
@@ -367,7 +391,7 @@ impl<L: Lens<Target = f32>> View for Slider<L> {
                 }
             }
 
-            WindowEvent::KeyDown(Code::ArrowDown | Code::ArrowLeft, _) => {
+            WindowEvent::KeyDown(Code::ArrowDown | Code::ArrowLeft, _, _) => {
                 let min = self.internal.range.start;
                 let max = self.internal.range.end;
                 let step = self.internal.step;
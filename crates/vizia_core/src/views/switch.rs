@@ -154,6 +154,21 @@ impl Handle<'_, Switch> {
     }
 }
 
+impl Editable for Switch {
+    type Value = bool;
+
+    fn on_edit_value<'a, F>(handle: Handle<'a, Self>, callback: F) -> Handle<'a, Self>
+    where
+        F: 'static + Fn(&mut EventContext, bool) + Send + Sync,
+    {
+        // The view is toggled, so the new value is the opposite of its current checked state.
+        handle.on_toggle(move |cx| {
+            let checked = cx.is_checked();
+            (callback)(cx, !checked);
+        })
+    }
+}
+
 impl View for Switch {
     fn element(&self) -> Option<&'static str> {
         Some("switch")
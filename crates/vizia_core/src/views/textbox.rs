@@ -5,7 +5,7 @@ use crate::text::{
     apply_movement, offset_for_delete_backwards, Direction, EditableText, Movement, Selection,
     VerticalMovement,
 };
-// use crate::views::scrollview::SCROLL_SENSITIVITY;
+use crate::views::scrollview::SCROLL_SENSITIVITY;
 use accesskit::{ActionData, ActionRequest};
 use skia_safe::textlayout::{RectHeightStyle, RectWidthStyle};
 use skia_safe::{Paint, PaintStyle, Rect};
@@ -71,10 +71,15 @@ pub struct Textbox<L: Lens> {
     on_blur: Option<Box<dyn Fn(&mut EventContext) + Send + Sync>>,
     on_cancel: Option<Box<dyn Fn(&mut EventContext) + Send + Sync>>,
     validate: Option<Box<dyn Fn(&L::Target) -> bool>>,
+    format: Option<Box<dyn Fn(&L::Target) -> String>>,
+    clamp: Option<Box<dyn Fn(L::Target) -> L::Target>>,
     placeholder: String,
     show_caret: bool,
     caret_timer: Timer,
     selection: Selection,
+    // The byte range of the input method composition text within the text, if composing.
+    #[lens(ignore)]
+    preedit: Option<std::ops::Range<usize>>,
 }
 
 // Determines whether the enter key submits the text or inserts a new line.
@@ -85,6 +90,16 @@ enum TextboxKind {
     MultiLineWrapped,
 }
 
+// Returns true if the modifier used to move the caret, or delete, by whole words is held. This is the option key
+// on macOS and the control key on other platforms.
+fn is_word_modifier(modifiers: &Modifiers) -> bool {
+    if cfg!(target_os = "macos") {
+        modifiers.alt()
+    } else {
+        modifiers.ctrl()
+    }
+}
+
 impl<L> Textbox<L>
 where
     L: Lens<Target: Data + Clone + ToStringLocalized + std::str::FromStr>,
@@ -156,10 +171,13 @@ where
             on_blur: None,
             on_cancel: None,
             validate: None,
+            format: None,
+            clamp: None,
             placeholder: String::from(""),
             show_caret: true,
             caret_timer,
             selection: Selection::new(0, 0),
+            preedit: None,
         }
         .build(cx, move |cx| {
             cx.add_listener(move |textbox: &mut Self, cx, event| {
@@ -199,7 +217,23 @@ where
         .text_value(lens)
         .default_action_verb(DefaultActionVerb::Focus)
         .toggle_class("caret", Self::show_caret)
-        .text(lens)
+        .bind(lens, |handle, lens| {
+            let value = lens.get(&handle);
+            let text = Self::format_value(handle.cx, handle.entity(), &value);
+            handle.text(text);
+        })
+    }
+
+    // Formats a value for display using the format closure of the textbox, if any.
+    fn format_value(cx: &mut Context, entity: Entity, value: &L::Target) -> String {
+        let formatted = cx
+            .views
+            .get(&entity)
+            .and_then(|view| view.downcast_ref::<Self>())
+            .and_then(|textbox| textbox.format.as_ref())
+            .map(|format| (format)(value));
+
+        formatted.unwrap_or_else(|| value.to_string_local(cx))
     }
 
     fn insert_text(&mut self, cx: &mut EventContext, txt: &str) {
@@ -210,6 +244,34 @@ where
         }
     }
 
+    // Replaces the current composition text, or the selection if not composing, with new composition text.
+    fn set_preedit(&mut self, cx: &mut EventContext, txt: &str, cursor: Option<(usize, usize)>) {
+        // Clearing the composition when not composing must leave the selection intact.
+        if txt.is_empty() && self.preedit.is_none() {
+            return;
+        }
+
+        if let Some(text) = cx.style.text.get_mut(cx.current) {
+            // The composition range is discarded if the text has been replaced since it was set.
+            let range = self
+                .preedit
+                .take()
+                .filter(|range| text.get(range.clone()).is_some())
+                .unwrap_or_else(|| self.selection.range());
+            text.edit(range.clone(), txt);
+
+            if !txt.is_empty() {
+                self.preedit = Some(range.start..range.start + txt.len());
+            }
+
+            // Select the part of the composition text being converted, if any.
+            let (anchor, active) = cursor.unwrap_or((txt.len(), txt.len()));
+            self.selection = Selection::new(range.start + anchor, range.start + active);
+
+            cx.style.needs_text_update(cx.current);
+        }
+    }
+
     fn delete_text(&mut self, cx: &mut EventContext, movement: Movement) {
         if self.selection.is_caret() {
             if movement == Movement::Grapheme(Direction::Upstream) {
@@ -262,9 +324,33 @@ where
         }
     }
 
+    // Selects the word under the caret, or the grapheme after the caret if it is not within a word.
     fn select_word(&mut self, cx: &mut EventContext) {
-        self.move_cursor(cx, Movement::Word(Direction::Upstream), false);
-        self.move_cursor(cx, Movement::Word(Direction::Downstream), true);
+        if let Some(text) = cx.style.text.get(cx.current) {
+            let is_word =
+                |grapheme: &str| grapheme.chars().next().is_some_and(char::is_alphanumeric);
+            let caret = self.selection.active.min(text.len());
+
+            let start = text[..caret]
+                .grapheme_indices(true)
+                .rev()
+                .take_while(|(_, grapheme)| is_word(*grapheme))
+                .last()
+                .map_or(caret, |(index, _)| index);
+
+            let mut end = text[caret..]
+                .grapheme_indices(true)
+                .take_while(|(_, grapheme)| is_word(*grapheme))
+                .last()
+                .map_or(caret, |(index, grapheme)| caret + index + grapheme.len());
+
+            if start == end {
+                end = text.next_grapheme_offset(caret).unwrap_or(caret);
+            }
+
+            self.selection = Selection::new(start, end);
+            cx.needs_redraw();
+        }
     }
 
     fn select_paragraph(&mut self, cx: &mut EventContext) {
@@ -377,6 +463,37 @@ where
         }
     }
 
+    // Returns the bounds of the caret at the given offset in physical window coordinates, or the bounds of the
+    // textbox if the text has not been laid out.
+    fn caret_bounds(&self, cx: &EventContext, offset: usize) -> BoundingBox {
+        let bounds = cx.bounds();
+        if let Some(text) = cx.style.text.get(cx.current) {
+            if let Some(paragraph) = cx.text_context.text_paragraphs.get(cx.current) {
+                let current = text.current_grapheme_offset(offset.min(text.len()));
+                let rects = paragraph.get_rects_for_range(
+                    current..current + 1,
+                    RectHeightStyle::Tight,
+                    RectWidthStyle::Tight,
+                );
+
+                if let Some(rect) = rects.first() {
+                    // The text coordinates of the origin of the textbox are the negated offset of the text.
+                    let (x, y) = self.coordinates_global_to_text(cx, bounds.x, bounds.y);
+                    let left = bounds.x - x + rect.rect.left;
+                    let top = bounds.y - y + rect.rect.top;
+                    return BoundingBox::from_min_max(
+                        left,
+                        top,
+                        left + 1.0,
+                        top + rect.rect.height(),
+                    );
+                }
+            }
+        }
+
+        bounds
+    }
+
     fn reset_caret_timer(&mut self, cx: &mut EventContext) {
         cx.stop_timer(self.caret_timer);
         if !cx.is_read_only() {
@@ -545,7 +662,8 @@ where
                 let x = (bounds.x + padding_left + cursor_rect.rect.left).round();
                 let y = (bounds.y + padding_top + cursor_rect.rect.top).round();
 
-                let x2 = x + 1.0;
+                // Keep the caret one logical pixel wide on high DPI displays.
+                let x2 = x + cx.logical_to_physical(1.0).round().max(1.0);
                 let y2 = y + (cursor_rect.rect.bottom - cursor_rect.rect.top);
 
                 let mut paint = Paint::default();
@@ -607,6 +725,71 @@ impl<'a, L: Lens> Handle<'a, Textbox<L>> {
         self.modify(|textbox| textbox.validate = Some(Box::new(is_valid)))
     }
 
+    /// Sets a closure used to format the bound value for display, in place of its `ToString` implementation.
+    ///
+    /// This allows the textbox to be bound directly to a numeric value, which is parsed when the textbox is edited,
+    /// while controlling how the value is shown.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use vizia_core::prelude::*;
+    /// #
+    /// # #[derive(Lens)]
+    /// # struct AppData {
+    /// #     gain: f32,
+    /// # }
+    /// #
+    /// # impl Model for AppData {}
+    /// #
+    /// # let cx = &mut Context::default();
+    /// #
+    /// # AppData { gain: 0.5 }.build(cx);
+    /// #
+    /// Textbox::new(cx, AppData::gain).format(|gain| format!("{:.2}", gain));
+    /// ```
+    pub fn format<F>(self, format: F) -> Self
+    where
+        F: 'static + Fn(&L::Target) -> String + Send + Sync,
+        L: Lens<Target: Data + Clone + ToStringLocalized + std::str::FromStr>,
+    {
+        let mut lens = None;
+        let handle = self.modify(|textbox| {
+            textbox.format = Some(Box::new(format));
+            lens = Some(textbox.lens);
+        });
+
+        // Reformat the current value, which was displayed before the format closure was set.
+        if let Some(lens) = lens {
+            let value = lens.get(handle.cx);
+            let text = Textbox::<L>::format_value(handle.cx, handle.entity, &value);
+            return handle.text(text);
+        }
+
+        handle
+    }
+
+    /// Clamps submitted values to the given inclusive range.
+    ///
+    /// Values parsed from the text of the textbox which fall outside of the range are clamped before being passed
+    /// to the `on_submit` callback.
+    pub fn range(self, range: std::ops::RangeInclusive<L::Target>) -> Self
+    where
+        L::Target: 'static + PartialOrd + Clone + Send + Sync,
+    {
+        let (min, max) = range.into_inner();
+        self.modify(|textbox| {
+            textbox.clamp = Some(Box::new(move |value| {
+                if value < min {
+                    min.clone()
+                } else if value > max {
+                    max.clone()
+                } else {
+                    value
+                }
+            }))
+        })
+    }
+
     /// Sets the placeholder text that appears when the textbox has no value.
     pub fn placeholder<P: ToStringLocalized>(self, text: impl Res<P>) -> Self {
         text.set_or_bind(self.cx, self.entity, move |cx, val| {
@@ -621,6 +804,20 @@ impl<'a, L: Lens> Handle<'a, Textbox<L>> {
     }
 }
 
+impl<L> Editable for Textbox<L>
+where
+    L: Lens<Target: Data + ToStringLocalized + std::str::FromStr>,
+{
+    type Value = String;
+
+    fn on_edit_value<'a, F>(handle: Handle<'a, Self>, callback: F) -> Handle<'a, Self>
+    where
+        F: 'static + Fn(&mut EventContext, String) + Send + Sync,
+    {
+        handle.on_edit(callback)
+    }
+}
+
 impl<L> View for Textbox<L>
 where
     L: Lens<Target: Data + ToStringLocalized + std::str::FromStr>,
@@ -629,6 +826,10 @@ where
         Some("textbox")
     }
 
+    fn accepts_text_input(&self) -> bool {
+        true
+    }
+
     fn accessibility(&self, cx: &mut AccessContext, node: &mut AccessNode) {
         let _bounds = cx.bounds();
 
@@ -828,12 +1029,29 @@ where
                 cx.emit(TextEvent::EndEdit);
             }
 
+            // The caret stops blinking while the window is in the background. Editing and the selection are kept.
+            WindowEvent::WindowFocused(focused) => {
+                if self.edit {
+                    if *focused {
+                        self.reset_caret_timer(cx);
+                    } else {
+                        cx.stop_timer(self.caret_timer);
+                        self.show_caret = false;
+                    }
+                    cx.needs_redraw();
+                }
+            }
+
             WindowEvent::MouseDoubleClick(MouseButton::Left) => {
                 cx.emit(TextEvent::SelectWord);
             }
 
             WindowEvent::MouseTripleClick(MouseButton::Left) => {
-                cx.emit(TextEvent::SelectParagraph);
+                if self.kind == TextboxKind::SingleLine {
+                    cx.emit(TextEvent::SelectAll);
+                } else {
+                    cx.emit(TextEvent::SelectParagraph);
+                }
             }
 
             WindowEvent::MouseUp(MouseButton::Left) => {
@@ -859,6 +1077,10 @@ where
                 cx.emit(TextEvent::Scroll(*x, *y));
             }
 
+            WindowEvent::MouseScrollPixels(x, y) => {
+                cx.emit(TextEvent::Scroll(*x / SCROLL_SENSITIVITY, *y / SCROLL_SENSITIVITY));
+            }
+
             WindowEvent::CharInput(c) => {
                 if *c != '\u{1b}' && // Escape
                     *c != '\u{8}' && // Backspace
@@ -875,7 +1097,27 @@ where
                 }
             }
 
-            WindowEvent::KeyDown(code, _) => match code {
+            WindowEvent::ImePreedit(text, cursor) => {
+                if self.edit && !cx.is_read_only() {
+                    self.reset_caret_timer(cx);
+                    // The candidate window is placed at the start of the composition text.
+                    let start =
+                        self.preedit.as_ref().map_or(self.selection.min(), |range| range.start);
+                    let area = self.caret_bounds(cx, start);
+                    self.set_preedit(cx, text, *cursor);
+                    cx.set_ime_cursor_area(area);
+                }
+            }
+
+            WindowEvent::ImeCommit(text) => {
+                if self.edit && !cx.is_read_only() {
+                    self.set_preedit(cx, "", None);
+                    self.reset_caret_timer(cx);
+                    cx.emit(TextEvent::InsertText(text.clone()));
+                }
+            }
+
+            WindowEvent::KeyDown(code, _, _) => match code {
                 Code::Enter => {
                     if matches!(self.kind, TextboxKind::SingleLine) {
                         cx.emit(TextEvent::Submit(true));
@@ -891,7 +1133,7 @@ where
 
                 Code::ArrowLeft => {
                     self.reset_caret_timer(cx);
-                    let movement = if cx.modifiers.ctrl() {
+                    let movement = if is_word_modifier(cx.modifiers) {
                         Movement::Word(Direction::Left)
                     } else {
                         Movement::Grapheme(Direction::Left)
@@ -903,7 +1145,7 @@ where
                 Code::ArrowRight => {
                     self.reset_caret_timer(cx);
 
-                    let movement = if cx.modifiers.ctrl() {
+                    let movement = if is_word_modifier(cx.modifiers) {
                         Movement::Word(Direction::Right)
                     } else {
                         Movement::Grapheme(Direction::Right)
@@ -935,7 +1177,7 @@ where
                 Code::Backspace => {
                     self.reset_caret_timer(cx);
                     if !cx.is_read_only() {
-                        if cx.modifiers.ctrl() {
+                        if is_word_modifier(cx.modifiers) {
                             cx.emit(TextEvent::DeleteText(Movement::Word(Direction::Upstream)));
                         } else {
                             cx.emit(TextEvent::DeleteText(Movement::Grapheme(Direction::Upstream)));
@@ -946,7 +1188,7 @@ where
                 Code::Delete => {
                     self.reset_caret_timer(cx);
                     if !cx.is_read_only() {
-                        if cx.modifiers.ctrl() {
+                        if is_word_modifier(cx.modifiers) {
                             cx.emit(TextEvent::DeleteText(Movement::Word(Direction::Downstream)));
                         } else {
                             cx.emit(TextEvent::DeleteText(Movement::Grapheme(
@@ -1162,6 +1404,7 @@ where
                     cx.focus_with_visibility(false);
                     cx.capture();
                     cx.set_checked(true);
+                    cx.set_ime_cursor_area(self.caret_bounds(cx, self.selection.active));
                     self.reset_caret_timer(cx);
 
                     let text = self.lens.get(cx);
@@ -1182,6 +1425,8 @@ where
             }
 
             TextEvent::EndEdit => {
+                // Uncommitted composition text is discarded.
+                self.set_preedit(cx, "", None);
                 self.deselect();
                 self.edit = false;
                 cx.set_checked(false);
@@ -1205,6 +1450,7 @@ where
             }
 
             TextEvent::Blur => {
+                self.set_preedit(cx, "", None);
                 cx.set_checked(false);
                 if let Some(callback) = &self.on_blur {
                     (callback)(cx);
@@ -1219,6 +1465,10 @@ where
                     if cx.is_valid() {
                         let text = self.clone_text(cx);
                         if let Ok(value) = text.parse::<L::Target>() {
+                            let value = match &self.clamp {
+                                Some(clamp) => (clamp)(value),
+                                None => value,
+                            };
                             (callback)(cx, value, *reason);
                         }
                     }
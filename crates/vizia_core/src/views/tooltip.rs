@@ -2,6 +2,35 @@ use crate::context::TreeProps;
 use crate::vg;
 use crate::{modifiers::ModalModel, prelude::*};
 
+/// Global settings which control when tooltips are shown and hidden.
+///
+/// Set with [`Context::set_tooltip_config`].
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// #
+/// # let cx = &mut Context::default();
+/// #
+/// cx.set_tooltip_config(TooltipConfig {
+///     show_delay: Duration::from_millis(250),
+///     hide_delay: Duration::from_millis(100),
+/// });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TooltipConfig {
+    /// The time the pointer must rest over a view before its tooltip is shown. Defaults to 500ms.
+    pub show_delay: Duration,
+    /// The time a tooltip remains visible after the pointer leaves its view. Defaults to zero.
+    pub hide_delay: Duration,
+}
+
+impl Default for TooltipConfig {
+    fn default() -> Self {
+        Self { show_delay: Duration::from_millis(500), hide_delay: Duration::ZERO }
+    }
+}
+
 /// A tooltip view.
 ///
 /// Should be used with the [tooltip](crate::modifiers::ActionModifiers::tooltip) modifier.
@@ -88,10 +117,26 @@ impl Tooltip {
                             let parent = ex.parent();
                             let parent_bounds = ex.cache.get_bounds(parent);
                             if parent_bounds.contains_point(*x, *y) {
-                                ex.set_left(Pixels(
-                                    ((*x - parent_bounds.x) - ex.bounds().width() / 2.0) / scale,
-                                ));
-                                ex.set_top(Pixels((*y - parent_bounds.y) / scale));
+                                let bounds = ex.bounds();
+                                let window_bounds = ex
+                                    .cache
+                                    .get_bounds(ex.parent_window().unwrap_or(Entity::root()));
+
+                                // Keep the tooltip within the window, flipping above the cursor if
+                                // there isn't enough room below it.
+                                let left = (*x - bounds.width() / 2.0).clamp(
+                                    window_bounds.left(),
+                                    (window_bounds.right() - bounds.width())
+                                        .max(window_bounds.left()),
+                                );
+                                let top = if *y + bounds.height() > window_bounds.bottom() {
+                                    *y - bounds.height()
+                                } else {
+                                    *y
+                                };
+
+                                ex.set_left(Pixels((left - parent_bounds.x) / scale));
+                                ex.set_top(Pixels((top - parent_bounds.y) / scale));
                             }
                         }
                     }
@@ -2,14 +2,59 @@ use std::ops::{Deref, Range};
 
 use crate::prelude::*;
 
+// The number of items built above and below the visible area, so that rows are ready before they scroll into view.
+const OVERSCAN: usize = 2;
+
+/// A view for efficiently displaying a large list of items with a fixed height.
+///
+/// Only the items which are visible, plus a small buffer, are built. As the list is scrolled the views for items
+/// which leave the visible area are rebuilt for the items which enter it.
+///
+/// Selection and keyboard focus are tracked by item index rather than by view, so they are preserved as views are
+/// recycled. Use [`selectable`](Handle::selectable) to enable selection, after which the arrow keys move the
+/// focused item and the space or enter keys select it.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// #
+/// # #[derive(Lens)]
+/// # struct AppData {
+/// #     items: Vec<String>,
+/// # }
+/// #
+/// # impl Model for AppData {}
+/// #
+/// # let cx = &mut Context::default();
+/// #
+/// # AppData { items: (0..10000).map(|i| format!("Item {}", i)).collect() }.build(cx);
+/// #
+/// VirtualList::new(cx, AppData::items, 24.0, |cx, _, item| Label::new(cx, item))
+///     .selectable(Selectable::Single)
+///     .on_select(|_, index| debug!("Selected item {}", index));
+/// ```
 #[derive(Lens)]
 pub struct VirtualList {
     scroll_to_cursor: bool,
     on_change: Option<Box<dyn Fn(&mut EventContext, Range<usize>)>>,
+    selectable: Selectable,
+    selected: Vec<usize>,
+    focused: Option<usize>,
+    on_select: Option<Box<dyn Fn(&mut EventContext, usize)>>,
 }
 
 pub enum VirtualListEvent {
     SetScrollY(f32),
+    /// Selects the item with the given index.
+    Select(usize),
+    /// Selects the focused item.
+    SelectFocused,
+    /// Moves the focus to the next item.
+    FocusNext,
+    /// Moves the focus to the previous item.
+    FocusPrev,
+    /// Scrolls the list so that the item with the given index is visible.
+    ScrollToItem(usize),
 }
 
 #[derive(Lens)]
@@ -62,7 +107,40 @@ impl VirtualListData {
         let start_index = (visible_start / item_height).trunc() as usize;
         let end_index = 1 + (visible_end / item_height).trunc() as usize;
 
-        self.visible_range = start_index..end_index.min(self.num_items);
+        self.visible_range =
+            start_index.saturating_sub(OVERSCAN)..(end_index + OVERSCAN).min(self.num_items);
+    }
+
+    fn scroll_to_item(&mut self, cx: &mut EventContext, index: usize) {
+        let current_height = cx.cache.get_height(cx.current());
+        if current_height == f32::MAX {
+            return;
+        }
+
+        let visible_height = current_height / cx.scale_factor();
+        let total_height = self.item_height * (self.num_items as f32);
+        let max_offset = total_height - visible_height;
+        if max_offset <= 0.0 {
+            return;
+        }
+
+        let offset = self.scroll_y * max_offset;
+        let item_top = self.item_height * (index as f32);
+        let item_bottom = item_top + self.item_height;
+
+        let new_offset = if item_top < offset {
+            item_top
+        } else if item_bottom > offset + visible_height {
+            item_bottom - visible_height
+        } else {
+            return;
+        };
+
+        cx.emit_custom(
+            Event::new(ScrollEvent::SetY((new_offset / max_offset).clamp(0.0, 1.0)))
+                .target(cx.current())
+                .propagate(Propagation::Subtree),
+        );
     }
 }
 
@@ -73,6 +151,12 @@ impl Model for VirtualListData {
                 self.scroll_y = *scroll_y;
                 self.recalc(cx);
             }
+
+            VirtualListEvent::ScrollToItem(index) => {
+                self.scroll_to_item(cx, *index);
+            }
+
+            _ => {}
         });
 
         event.map(|window_event, _| match window_event {
@@ -117,7 +201,15 @@ impl VirtualList {
     ) -> Handle<Self> {
         let vl = cx.current;
         let num_items = list.map(list_len);
-        Self { scroll_to_cursor: true, on_change: None }.build(cx, |cx| {
+        Self {
+            scroll_to_cursor: true,
+            on_change: None,
+            selectable: Selectable::None,
+            selected: Vec::new(),
+            focused: None,
+            on_select: None,
+        }
+        .build(cx, |cx| {
             Binding::new(cx, num_items, move |cx, lens| {
                 let num_items = lens.get(cx);
 
@@ -152,6 +244,17 @@ impl VirtualList {
                                     })
                                     .height(Pixels(item_height))
                                     .position_type(PositionType::SelfDirected)
+                                    .class("row")
+                                    .checked(
+                                        VirtualList::selected
+                                            .map(move |selected| selected.contains(&index)),
+                                    )
+                                    .toggle_class(
+                                        "nav",
+                                        VirtualList::focused
+                                            .map(move |focused| *focused == Some(index)),
+                                    )
+                                    .on_press(move |cx| cx.emit(VirtualListEvent::Select(index)))
                                     .bind(
                                         item_index,
                                         move |handle, lens| {
@@ -173,6 +276,37 @@ impl VirtualList {
                 }
             });
         })
+        .navigable(true)
+        .role(Role::List)
+    }
+
+    fn select(&mut self, cx: &mut EventContext, index: usize) {
+        match self.selectable {
+            Selectable::None => return,
+
+            Selectable::Single => {
+                self.selected = vec![index];
+            }
+
+            Selectable::Multi => {
+                if let Some(position) = self.selected.iter().position(|item| *item == index) {
+                    self.selected.remove(position);
+                } else {
+                    self.selected.push(index);
+                }
+            }
+        }
+
+        self.focused = Some(index);
+
+        if let Some(callback) = &self.on_select {
+            (callback)(cx, index);
+        }
+    }
+
+    fn set_focused(&mut self, cx: &mut EventContext, index: usize) {
+        self.focused = Some(index);
+        cx.emit(VirtualListEvent::ScrollToItem(index));
     }
 }
 
@@ -180,6 +314,56 @@ impl View for VirtualList {
     fn element(&self) -> Option<&'static str> {
         Some("virtual-list")
     }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::KeyDown(code, _, _) if self.selectable != Selectable::None => {
+                match code {
+                    Code::ArrowDown => cx.emit(VirtualListEvent::FocusNext),
+                    Code::ArrowUp => cx.emit(VirtualListEvent::FocusPrev),
+                    Code::Space | Code::Enter => cx.emit(VirtualListEvent::SelectFocused),
+                    _ => return,
+                }
+                meta.consume();
+            }
+
+            _ => {}
+        });
+
+        event.map(|virtual_list_event, meta| match virtual_list_event {
+            VirtualListEvent::Select(index) => {
+                self.select(cx, *index);
+                meta.consume();
+            }
+
+            VirtualListEvent::SelectFocused => {
+                if let Some(index) = self.focused {
+                    self.select(cx, index);
+                }
+                meta.consume();
+            }
+
+            VirtualListEvent::FocusNext => {
+                let num_items = VirtualListData::num_items.get(cx);
+                if num_items > 0 {
+                    let index = self.focused.map_or(0, |index| (index + 1).min(num_items - 1));
+                    self.set_focused(cx, index);
+                }
+                meta.consume();
+            }
+
+            VirtualListEvent::FocusPrev => {
+                let num_items = VirtualListData::num_items.get(cx);
+                if num_items > 0 {
+                    let index = self.focused.map_or(0, |index| index.saturating_sub(1));
+                    self.set_focused(cx, index.min(num_items - 1));
+                }
+                meta.consume();
+            }
+
+            _ => {}
+        });
+    }
 }
 
 impl<'a> Handle<'a, VirtualList> {
@@ -188,6 +372,35 @@ impl<'a> Handle<'a, VirtualList> {
             virtual_list.scroll_to_cursor = flag;
         })
     }
+
+    /// Sets whether items can be selected, and whether one or many items can be selected at once.
+    /// Defaults to `Selectable::None`.
+    pub fn selectable(self, selectable: impl Res<Selectable>) -> Self {
+        self.bind(selectable, |handle, selectable| {
+            let selectable = selectable.get(&handle);
+            handle.modify(|virtual_list: &mut VirtualList| virtual_list.selectable = selectable);
+        })
+    }
+
+    /// Binds the indices of the selected items to the given lens.
+    ///
+    /// Without a binding the list keeps track of its own selection.
+    pub fn selected(self, selected: impl Lens<Target = Vec<usize>>) -> Self {
+        self.bind(selected, |handle, selected| {
+            let selected = selected.get(&handle);
+            handle.modify(|virtual_list: &mut VirtualList| virtual_list.selected = selected);
+        })
+    }
+
+    /// Sets a callback which is called with the index of an item when it is selected.
+    pub fn on_select<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, usize),
+    {
+        self.modify(|virtual_list: &mut VirtualList| {
+            virtual_list.on_select = Some(Box::new(callback))
+        })
+    }
 }
 
 #[cfg(test)]
@@ -1,9 +1,15 @@
 use std::path::PathBuf;
 
-use crate::{entity::Entity, environment::ThemeMode, layout::cache::GeoChanged};
-use vizia_input::{Code, Key, MouseButton};
+use crate::{
+    animation::Animation, entity::Entity, environment::ThemeMode, layout::cache::GeoChanged,
+    layout::BoundingBox,
+};
+use vizia_input::{Code, Key, MouseButton, TouchPhase};
 use vizia_style::CursorIcon;
-use vizia_window::{WindowPosition, WindowSize};
+use vizia_window::{
+    CursorGrabMode, FullscreenMode, PresentMode, ResizeDirection, UrgencyLevel, WindowLevel,
+    WindowPosition, WindowSize,
+};
 
 #[derive(Debug, Clone)]
 pub enum DropData {
@@ -23,18 +29,67 @@ impl From<PathBuf> for DropData {
     }
 }
 
+/// A frame captured from a window with [`EventContext::capture_frame`](crate::context::EventContext::capture_frame).
+#[derive(Debug, Clone)]
+pub struct CapturedFrame {
+    /// The width of the frame in physical pixels.
+    pub width: u32,
+    /// The height of the frame in physical pixels.
+    pub height: u32,
+    /// The unpremultiplied RGBA8 pixels of the frame, row by row from the top left.
+    pub pixels: Vec<u8>,
+}
+
 /// Events generated by the application in response to OS events as well as events that can be used
 /// to set properties of the window.
 #[derive(Debug, Clone)]
 pub enum WindowEvent {
     /// Emitted when a window is closed. Can also be emitted by a view or model to close the window.
     WindowClose,
+    /// Emitted when the user requests that a window be closed, such as by pressing the close button of the window.
+    ///
+    /// The window is closed once the event has been handled, unless a view or model handling the event calls
+    /// [`EventContext::prevent_close`](crate::context::EventContext::prevent_close).
+    WindowCloseRequested,
     /// Emitted when a file is dragged and then dropped onto the window.
     Drop(DropData),
+    /// Emitted when a file is dragged over the window. Sent to the hovered view and its ancestors.
+    FileHover(PathBuf),
+    /// Emitted when a file dragged over the window leaves the window, or the drag is cancelled. Sent to the hovered
+    /// view and its ancestors.
+    FileHoverCancelled,
+    /// Emitted when a file is dropped onto the window. Sent to the hovered view and its ancestors, along with the
+    /// physical position of the cursor within the window.
+    ///
+    /// The position and target are only approximate. The platform doesn't report where the file was dropped, and
+    /// may not report cursor movement during the drag, so both come from the last cursor position reported to the
+    /// window.
+    FileDrop {
+        path: PathBuf,
+        x: f32,
+        y: f32,
+    },
     /// Emitted when a mouse button is double clicked.
     MouseDoubleClick(MouseButton),
     /// Emitted when a mouse button is triple clicked
     MouseTripleClick(MouseButton),
+    /// Emitted when a finger touches, moves on, or is lifted from a touchscreen, with the physical position of the
+    /// touch point. The `id` is stable for the lifetime of a touch, so multiple fingers can be tracked at once.
+    Touch {
+        id: u64,
+        phase: TouchPhase,
+        x: f32,
+        y: f32,
+    },
+    /// Emitted when a trackpad pinch gesture is performed, with the change in magnification. Positive values zoom in.
+    /// The gesture is centered on the mouse cursor.
+    Zoom(f32),
+    /// Emitted when a trackpad rotation gesture is performed, with the change in rotation in degrees. Positive values
+    /// rotate counterclockwise. The gesture is centered on the mouse cursor.
+    Rotate(f32),
+    /// Emitted when the left mouse button is held down for the long press duration without the cursor moving further
+    /// than the long press distance, with the physical position of the cursor when the button was pressed.
+    LongPress(f32, f32),
     /// Emitted when a mouse button is pressed
     MouseDown(MouseButton),
     /// Emitted when a mouse button is released.
@@ -49,8 +104,18 @@ pub enum WindowEvent {
     },
     /// Emitted when the mouse cursor is moved
     MouseMove(f32, f32),
-    /// Emitted when the mouse scroll wheel is scrolled.
+    /// Emitted when the mouse scroll wheel is scrolled, with the scroll delta in lines.
+    ///
+    /// This is not emitted for devices with precise scrolling, such as trackpads, which emit
+    /// [`MouseScrollPixels`](WindowEvent::MouseScrollPixels) instead. Views which previously relied on this event
+    /// for all scrolling should also handle `MouseScrollPixels`, for example by dividing the pixel delta by a line
+    /// height.
     MouseScroll(f32, f32),
+    /// Emitted when a device with precise scrolling, such as a trackpad, is scrolled, with the scroll delta in
+    /// physical pixels.
+    ///
+    /// No [`MouseScroll`](WindowEvent::MouseScroll) event is emitted for the same scroll.
+    MouseScrollPixels(f32, f32),
     /// Emitted when the mouse cursor enters the bounding box of an entity.
     MouseOver,
     /// Emitted when the mouse cursor leaves the bounding box of an entity.
@@ -65,16 +130,43 @@ pub enum WindowEvent {
     FocusOut,
     /// Emitted when a character is typed.
     CharInput(char),
-    /// Emitted when a keyboard key is pressed.
-    KeyDown(Code, Option<Key>),
+    /// Emitted when the composition text of an input method editor changes, with the byte range of the cursor within
+    /// the composition text if the cursor should be shown. An empty string clears the composition.
+    ImePreedit(String, Option<(usize, usize)>),
+    /// Emitted when an input method editor commits text.
+    ImeCommit(String),
+    /// Sets the area of the window, in physical pixels, which the input method editor candidate window should avoid
+    /// covering, such as the area around the text caret.
+    SetImeCursorArea(BoundingBox),
+    /// Emitted when a keyboard key is pressed, with whether the event is an automatic repeat generated by the
+    /// operating system while the key is held down.
+    KeyDown(Code, Option<Key>, bool),
     /// Emitted when a keyboard key is released.
     KeyUp(Code, Option<Key>),
     /// Emited when the system window theme has changed.
     ThemeChanged(ThemeMode),
+    /// Emitted when the system preference for reduced motion has changed.
+    ReducedMotionChanged(bool),
+    /// Emitted to a view when an animation played on it with `play_animation` has finished, either by running to
+    /// completion or by being replaced with another animation.
+    AnimationFinished(Animation),
+    /// Emitted when the system preference for high contrast has changed.
+    HighContrastChanged(bool),
+    /// Emitted when the window has been moved, with the new logical position of the top left corner of the window.
+    WindowMoved(i32, i32),
+    /// Emitted when the scale factor of the window has changed, such as when the window is moved to a monitor with a
+    /// different DPI.
+    ScaleFactorChanged(f64),
     /// Sets the mouse cursor icon.
     SetCursor(CursorIcon),
+    /// Sets whether the mouse cursor is visible.
+    SetCursorVisible(bool),
     /// Grabs the mouse cursor, preventing it from leaving the window.
     GrabCursor(bool),
+    /// Sets how the mouse cursor is grabbed by the window.
+    SetCursorGrab(CursorGrabMode),
+    /// Emitted with the raw, unaccelerated relative motion of the mouse while the cursor is grabbed by the window.
+    RawMouseMotion(f32, f32),
     /// Sets the (x,y) position of the mouse cursor in window coordinates.
     SetCursorPosition(u32, u32),
     /// Sets the title of the window.
@@ -83,6 +175,8 @@ pub enum WindowEvent {
     SetSize(WindowSize),
     /// Sets the position of the window.
     SetPosition(WindowPosition),
+    /// Moves the window to the center of the monitor it is on.
+    CenterOnMonitor,
     /// Sets the maximum size of the window.
     SetMaxSize(Option<WindowSize>),
     /// Sets the minimum size of the window.
@@ -97,8 +191,39 @@ pub enum WindowEvent {
     SetVisible(bool),
     /// Sets whether the window has decorations.
     SetDecorations(bool),
+    /// Sets the icon of the window from its width, height and RGBA pixel data.
+    SetIcon(u32, u32, Vec<u8>),
+    /// Sets whether the window is hidden from the taskbar.
+    SetSkipTaskbar(bool),
+    /// Sets whether the background behind a transparent window is blurred.
+    SetBlur(bool),
     /// Sets whether the window remains on top of other windows.
     SetAlwaysOnTop(bool),
+    /// Sets the stacking level of the window relative to other windows.
+    SetWindowLevel(WindowLevel),
+    /// Sets whether the window is shown in a window or fullscreen.
+    SetFullscreen(FullscreenMode),
+    /// Sets how rendered frames are presented to the display.
+    SetPresentMode(PresentMode),
+    /// Requests the attention of the user, or cancels a previous request if `None`.
+    RequestUserAttention(Option<UrgencyLevel>),
+    /// Emitted when the window enters or leaves fullscreen, and with the initial state once the window is created.
+    FullscreenChanged(bool),
+    /// Emitted when the window gains or loses keyboard focus. The event is sent to the focused view if it is within
+    /// the window, and propagates up to the window.
+    WindowFocused(bool),
+    /// Requests that the window is brought to the front and given keyboard focus.
+    FocusWindow,
+    /// Emitted when the window becomes fully hidden from view, such as by being covered by other windows, or
+    /// becomes visible again. The window is not redrawn while it is occluded.
+    Occluded(bool),
+    /// Emitted when the window is minimized or restored from being minimized.
+    Minimized(bool),
+    /// Emitted when the window is maximized or restored from being maximized.
+    Maximized(bool),
+    /// Emitted when a frame requested with [`EventContext::capture_frame`](crate::context::EventContext::capture_frame)
+    /// has been captured.
+    FrameCaptured(CapturedFrame),
     /// Emitted when mouse events have been captured.
     MouseCaptureEvent,
     /// Emitted when mouse events have been released.
@@ -123,8 +248,10 @@ pub enum WindowEvent {
     ReloadStyles,
 
     SetEnabled(bool),
-
+    /// Begins moving the window with the mouse, as if the title bar of the window were dragged.
     DragWindow,
+    /// Begins resizing the window with the mouse from the given edge or corner.
+    DragResizeWindow(ResizeDirection),
 
     // ToDo: Move this
     Destroyed,
@@ -0,0 +1,12 @@
+/// The phase of a touch point.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum TouchPhase {
+    /// A finger touched the screen.
+    Started,
+    /// A finger moved on the screen.
+    Moved,
+    /// A finger was lifted from the screen.
+    Ended,
+    /// The touch was cancelled by the system, such as when the window loses focus.
+    Cancelled,
+}
@@ -1,13 +1,13 @@
 use crate::{
     define_property, Angle, BackgroundImage, BackgroundSize, BlendMode, Border, BorderStyle,
     BorderWidth, ClipPath, Color, CornerRadius, CornerShape, CursorIcon, CustomParseError,
-    CustomProperty, Display, Filter, FontFamily, FontSize, FontSlant, FontVariation, FontWeight,
-    FontWidth, LayoutType, LengthOrPercentage, LineClamp, Opacity, Outline, Overflow, Parse,
-    PointerEvents, Position, PositionType, Rect, Scale, Shadow, TextAlign, TextDecoration,
-    TextDecorationLine, TextDecorationStyle, TextOverflow, Transform, Transition, Translate, Units,
-    UnparsedProperty, Visibility,
+    CustomProperty, Direction, Display, Filter, FontFamily, FontSize, FontSlant, FontVariation,
+    FontWeight, FontWidth, LayoutType, Length, LengthOrPercentage, LineClamp, LineHeight, Opacity,
+    Outline, Overflow, Parse, PointerEvents, Position, PositionType, Rect, Scale, Shadow,
+    TextAlign, TextDecoration, TextDecorationLine, TextDecorationStyle, TextOverflow, Transform,
+    Transition, Translate, Units, UnparsedProperty, Visibility,
 };
-use cssparser::Parser;
+use cssparser::{CowRcStr, Parser, ParserInput};
 
 define_property! {
     pub enum Property<'i> {
@@ -25,6 +25,7 @@ define_property! {
         // Positioning
         "layout-type": LayoutType(LayoutType),
         "position-type": PositionType(PositionType),
+        "direction": Direction(Direction),
 
         // Position and Size
         "space": Space(Units),
@@ -158,6 +159,8 @@ define_property! {
         "text-align": TextAlign(TextAlign),
         "text-overflow": TextOverflow(TextOverflow),
         "line-clamp": LineClamp(LineClamp),
+        "line-height": LineHeight(LineHeight),
+        "letter-spacing": LetterSpacing(Length),
         "text-decoration": TextDecoration(TextDecoration),
         "text-decoration-line": TextDecorationLine(TextDecorationLine),
         "underline-style": UnderlineStyle(TextDecorationStyle),
@@ -192,6 +195,20 @@ define_property! {
     }
 }
 
+impl<'i> Property<'i> {
+    /// Parses the value of the property with the given name, such as `background-color`, from a CSS string.
+    ///
+    /// Returns `None` if the name is not a known property or the value is invalid for the property.
+    pub fn parse_str(name: &'i str, value: &'i str) -> Option<Self> {
+        let mut input = ParserInput::new(value);
+        let mut parser = Parser::new(&mut input);
+        match Property::parse_value(CowRcStr::from(name), &mut parser) {
+            Ok(Property::Unparsed(_)) | Ok(Property::Custom(_)) | Err(_) => None,
+            Ok(property) => parser.is_exhausted().then_some(property),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use cssparser::{CowRcStr, ParserInput};
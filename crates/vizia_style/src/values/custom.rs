@@ -69,6 +69,29 @@ impl<'i> TokenOrValue<'i> {
 }
 
 impl<'i> TokenList<'i> {
+    /// Serializes the tokens to a CSS string, replacing each variable reference with the value returned by
+    /// `resolve`, or with its fallback if the variable is not defined.
+    ///
+    /// Returns `None` if a variable is not defined and has no fallback.
+    pub fn to_css_string(&self, resolve: &dyn Fn(&str) -> Option<String>) -> Option<String> {
+        let mut css = String::new();
+        for token in self.0.iter() {
+            match token {
+                TokenOrValue::Token(token) => token.to_css(&mut css).ok()?,
+                TokenOrValue::Color(color) => color.to_css(&mut css).ok()?,
+                TokenOrValue::Var(variable) => {
+                    if let Some(value) = resolve(variable.name.0.as_ref()) {
+                        css.push_str(&value);
+                    } else {
+                        css.push_str(&variable.fallback.as_ref()?.to_css_string(resolve)?);
+                    }
+                }
+            }
+        }
+
+        Some(css)
+    }
+
     fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
         input.parse_until_before(Delimiter::Bang | Delimiter::Semicolon, |input| {
             let mut tokens = vec![];
@@ -239,7 +262,7 @@ impl<'i> Variable<'i> {
 mod tests {
     use cssparser::CowRcStr;
 
-    use crate::CustomProperty;
+    use crate::{CustomProperty, UnparsedProperty};
 
     #[test]
     fn parse_custom_ident() {
@@ -268,6 +291,16 @@ mod tests {
         // assert_eq!(result, Ok($value));
     }
 
+    #[test]
+    fn substitute_variables() {
+        let success_string = "var(--accent) var(--missing, 2px)";
+        let mut parser_input = cssparser::ParserInput::new(success_string);
+        let mut parser = cssparser::Parser::new(&mut parser_input);
+        let result = UnparsedProperty::parse(CowRcStr::from("border"), &mut parser).unwrap();
+        let resolve = |name: &str| (name == "--accent").then(|| String::from("red"));
+        assert_eq!(result.value.to_css_string(&resolve), Some(String::from("red 2px")));
+    }
+
     #[test]
     fn parse_custom_complex() {
         let success_string = "left 3px rgb(100, 200, 50)";
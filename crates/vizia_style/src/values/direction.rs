@@ -1,12 +1,13 @@
 use crate::{macros::define_enum, Parse};
 
 define_enum! {
+    /// Determines the direction in which the content of an entity flows horizontally.
     #[derive(Default)]
     pub enum Direction {
-        /// The entity will be rendered and acted on by the layout system.
+        /// Content flows from left to right.
         #[default]
         "ltr": Ltr,
-        /// The entity will not be rendered and acted on by the layout system.
+        /// Content flows from right to left. Row layouts, horizontal spacing, and the default text alignment are mirrored.
         "rtl": Rtl,
     }
 }
@@ -20,3 +21,18 @@ impl From<bool> for Direction {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        Direction, assert_direction,
+
+        ident {
+            "ltr" => Direction::Ltr,
+            "rtl" => Direction::Rtl,
+        }
+    }
+}
@@ -1,5 +1,7 @@
 pub mod auto;
 pub mod inset;
+pub mod normal;
 
 pub use auto::*;
 pub use inset::*;
+pub use normal::*;
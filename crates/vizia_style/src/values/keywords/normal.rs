@@ -0,0 +1,31 @@
+use crate::{impl_parse, Parse};
+
+/// The 'normal' keyword.
+///
+/// It is used to parse the [`LineHeight::Normal`](crate::LineHeight::Normal) variant.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct NormalKeyword;
+
+impl_parse! {
+    NormalKeyword,
+
+    tokens {
+        ident {
+            "normal" => NormalKeyword,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        NormalKeyword, assert_normal_keyword,
+
+        ident {
+            "normal" => NormalKeyword,
+        }
+    }
+}
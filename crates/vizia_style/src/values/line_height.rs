@@ -0,0 +1,91 @@
+use cssparser::*;
+
+use crate::{macros::impl_parse, Length, NormalKeyword, Parse};
+
+/// The height of a line of text.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub enum LineHeight {
+    /// The line height is determined by the ascent, descent and line gap of the font.
+    #[default]
+    Normal,
+    /// The line height is a multiple of the font size.
+    Number(f32),
+    /// The line height is a fixed length.
+    Length(Length),
+}
+
+impl LineHeight {
+    /// Returns the line height as a multiple of the given font size, or `None` for [`LineHeight::Normal`].
+    pub fn to_multiplier(&self, font_size: f32) -> Option<f32> {
+        match self {
+            LineHeight::Normal => None,
+            LineHeight::Number(number) => Some(*number),
+            LineHeight::Length(length) => {
+                length.to_px().filter(|_| font_size > 0.0).map(|px| px / font_size)
+            }
+        }
+    }
+}
+
+impl_parse! {
+    LineHeight,
+
+    try_parse {
+        NormalKeyword,
+        f32,
+        Length,
+    }
+}
+
+impl From<NormalKeyword> for LineHeight {
+    fn from(_: NormalKeyword) -> Self {
+        LineHeight::Normal
+    }
+}
+
+impl From<f32> for LineHeight {
+    fn from(number: f32) -> Self {
+        LineHeight::Number(number)
+    }
+}
+
+impl From<f64> for LineHeight {
+    fn from(number: f64) -> Self {
+        LineHeight::Number(number as f32)
+    }
+}
+
+impl From<Length> for LineHeight {
+    fn from(length: Length) -> Self {
+        LineHeight::Length(length)
+    }
+}
+
+impl From<&str> for LineHeight {
+    fn from(s: &str) -> Self {
+        let mut input = ParserInput::new(s);
+        let mut parser = Parser::new(&mut input);
+        LineHeight::parse(&mut parser).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        LineHeight, line_height,
+
+        success {
+            "normal" => LineHeight::Normal,
+            "1.5" => LineHeight::Number(1.5),
+            "20px" => LineHeight::Length(Length::px(20.0)),
+        }
+
+        failure {
+            "test",
+            "auto",
+        }
+    }
+}
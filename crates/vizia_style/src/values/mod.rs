@@ -31,6 +31,7 @@ pub mod layout_type;
 pub mod length;
 pub mod length_or_percentage;
 pub mod length_percentage_auto;
+pub mod line_height;
 pub mod matrix;
 pub mod number_or_percentage;
 pub mod opacity;
@@ -88,6 +89,7 @@ pub use layout_type::*;
 pub use length::*;
 pub use length_or_percentage::*;
 pub use length_percentage_auto::*;
+pub use line_height::*;
 pub use matrix::*;
 pub use number_or_percentage::*;
 pub use opacity::*;
@@ -0,0 +1,29 @@
+//! # Vizia Testing
+//!
+//! Utilities for writing visual regression tests for vizia views.
+//!
+//! A snapshot test builds a view headlessly at a fixed size and scale factor, renders a single frame,
+//! and compares it against a PNG stored alongside the crate under test:
+//!
+//! ```no_run
+//! use vizia_core::prelude::*;
+//! use vizia_testing::assert_snapshot;
+//!
+//! #[test]
+//! fn button() {
+//!     assert_snapshot("button", |cx| {
+//!         Button::new(cx, |cx| Label::new(cx, "Press Me"));
+//!     });
+//! }
+//! ```
+//!
+//! The first time a test runs, or whenever the `VIZIA_UPDATE_SNAPSHOTS` environment variable is set,
+//! the rendered frame is written as the stored snapshot. On a mismatch the rendered frame and an image
+//! highlighting the differing pixels are written next to the stored snapshot, and the test panics.
+//!
+//! Text is rendered with a bundled copy of DejaVu Sans rather than fonts installed on the system, so that
+//! snapshots match across machines.
+
+mod snapshot;
+
+pub use snapshot::*;
@@ -0,0 +1,396 @@
+use std::path::{Path, PathBuf};
+
+use skia_safe::{
+    images, AlphaType, CachingHint, ColorType, Data, EncodedImageFormat, Image, ImageInfo,
+};
+use vizia_core::backend::{BackendContext, WindowDescription};
+use vizia_core::events::EventManager;
+use vizia_core::prelude::*;
+
+/// The environment variable which, when set, causes stored snapshots to be overwritten.
+pub const UPDATE_SNAPSHOTS_VAR: &str = "VIZIA_UPDATE_SNAPSHOTS";
+
+/// The font bundled with the crate, which is used to render text in snapshots by default.
+pub const DEFAULT_FONT: &[u8] = include_bytes!("../resources/fonts/DejaVuSans.ttf");
+
+/// The family name of [`DEFAULT_FONT`].
+pub const DEFAULT_FONT_FAMILY: &str = "DejaVu Sans";
+
+/// Options used to render and compare a snapshot.
+#[derive(Debug, Clone)]
+pub struct SnapshotOptions {
+    /// The logical size of the headless window.
+    pub size: WindowSize,
+    /// The scale factor used to convert the logical size to physical pixels.
+    pub scale_factor: f64,
+    /// The maximum difference allowed in any color channel before a pixel is considered changed.
+    pub tolerance: u8,
+    /// The theme used to render the snapshot.
+    pub theme: ThemeMode,
+    /// The locale used to render the snapshot.
+    pub locale: LanguageIdentifier,
+    /// The directory in which snapshots are stored.
+    pub directory: PathBuf,
+    /// Whether fonts installed on the system are used to render text.
+    pub system_fonts: bool,
+    fonts: Vec<Vec<u8>>,
+    default_font: Vec<String>,
+}
+
+impl Default for SnapshotOptions {
+    fn default() -> Self {
+        // Cargo sets the manifest directory of the crate under test when running tests.
+        let manifest_dir = std::env::var_os("CARGO_MANIFEST_DIR").map(PathBuf::from);
+
+        Self {
+            size: WindowSize::new(400, 300),
+            scale_factor: 1.0,
+            tolerance: 2,
+            theme: ThemeMode::LightMode,
+            locale: langid!("en-US"),
+            directory: manifest_dir.unwrap_or_default().join("tests").join("snapshots"),
+            system_fonts: false,
+            fonts: Vec::new(),
+            default_font: vec![DEFAULT_FONT_FAMILY.to_string()],
+        }
+    }
+}
+
+impl SnapshotOptions {
+    pub fn new() -> Self {
+        SnapshotOptions::default()
+    }
+
+    pub fn with_size(mut self, width: u32, height: u32) -> Self {
+        self.size = WindowSize::new(width, height);
+
+        self
+    }
+
+    pub fn with_scale_factor(mut self, factor: f64) -> Self {
+        self.scale_factor = factor;
+
+        self
+    }
+
+    pub fn with_tolerance(mut self, tolerance: u8) -> Self {
+        self.tolerance = tolerance;
+
+        self
+    }
+
+    pub fn with_theme(mut self, theme: ThemeMode) -> Self {
+        self.theme = theme;
+
+        self
+    }
+
+    pub fn with_locale(mut self, locale: LanguageIdentifier) -> Self {
+        self.locale = locale;
+
+        self
+    }
+
+    pub fn with_directory(mut self, directory: impl Into<PathBuf>) -> Self {
+        self.directory = directory.into();
+
+        self
+    }
+
+    /// Adds a font used to render text in the snapshot, alongside the bundled [`DEFAULT_FONT`].
+    pub fn with_font(mut self, data: impl Into<Vec<u8>>) -> Self {
+        self.fonts.push(data.into());
+
+        self
+    }
+
+    /// Sets whether fonts installed on the system are used to render text.
+    ///
+    /// System fonts are ignored by default, so that text renders identically across machines.
+    pub fn with_system_fonts(mut self, flag: bool) -> Self {
+        self.system_fonts = flag;
+
+        self
+    }
+
+    /// Sets the font families used for text which doesn't specify a font. Defaults to [`DEFAULT_FONT_FAMILY`].
+    pub fn with_default_font(mut self, names: &[&str]) -> Self {
+        self.default_font = names.iter().map(|name| name.to_string()).collect();
+
+        self
+    }
+
+    // Returns the size of the rendered frame in physical pixels.
+    fn physical_size(&self) -> (i32, i32) {
+        (
+            (self.size.width as f64 * self.scale_factor).round() as i32,
+            (self.size.height as f64 * self.scale_factor).round() as i32,
+        )
+    }
+}
+
+// The root view of the headless window.
+struct HeadlessWindow;
+
+impl View for HeadlessWindow {}
+
+/// Builds the content headlessly and renders a single frame.
+pub fn render(options: &SnapshotOptions, content: impl FnOnce(&mut Context)) -> Image {
+    let mut cx = BackendContext::new(Context::new());
+    let mut event_manager = EventManager::new();
+
+    if !options.system_fonts {
+        cx.disable_system_fonts(DEFAULT_FONT_FAMILY);
+    }
+
+    cx.context().add_font_mem(DEFAULT_FONT);
+    for font in options.fonts.iter() {
+        cx.context().add_font_mem(font);
+    }
+
+    if !options.default_font.is_empty() {
+        let names = options.default_font.iter().map(String::as_str).collect::<Vec<_>>();
+        cx.context().set_default_font(&names);
+    }
+
+    // Fix the theme and locale so that the frame doesn't depend on the settings of the system.
+    cx.emit_origin(EnvironmentEvent::SetThemeMode(AppTheme::BuiltIn(options.theme)));
+    cx.emit_origin(EnvironmentEvent::SetLocale(options.locale.clone()));
+    while event_manager.flush_events(cx.context()) {}
+
+    let window_description = WindowDescription::new()
+        .with_inner_size(options.size.width, options.size.height)
+        .with_scale_factor(options.scale_factor);
+
+    cx.add_main_window(Entity::root(), &window_description, options.scale_factor as f32);
+    cx.add_window(HeadlessWindow);
+    cx.0.windows.insert(Entity::root(), WindowState { window_description, ..Default::default() });
+
+    cx.context().remove_user_themes();
+    (content)(cx.context());
+
+    while event_manager.flush_events(cx.context()) {}
+    cx.process_style_updates();
+    cx.process_animations(Instant::now());
+    cx.process_visual_updates();
+
+    let mut surface = skia_safe::surfaces::raster_n32_premul(options.physical_size())
+        .expect("Failed to create snapshot surface");
+    let mut dirty_surface = surface
+        .new_surface_with_dimensions(options.physical_size())
+        .expect("Failed to create snapshot surface");
+
+    cx.draw(Entity::root(), &mut surface, &mut dirty_surface);
+
+    surface.image_snapshot()
+}
+
+/// Renders the content with the default [`SnapshotOptions`] and compares it against the stored snapshot
+/// with the given name.
+///
+/// # Panics
+///
+/// Panics if the rendered frame differs from the stored snapshot.
+pub fn assert_snapshot(name: &str, content: impl FnOnce(&mut Context)) {
+    assert_snapshot_with(name, &SnapshotOptions::default(), content);
+}
+
+/// Renders the content with the given options and compares it against the stored snapshot with the given
+/// name.
+///
+/// # Panics
+///
+/// Panics if the rendered frame differs from the stored snapshot.
+pub fn assert_snapshot_with(
+    name: &str,
+    options: &SnapshotOptions,
+    content: impl FnOnce(&mut Context),
+) {
+    let actual = render(options, content);
+
+    let snapshot_path = options.directory.join(format!("{name}.png"));
+    let actual_path = options.directory.join(format!("{name}.actual.png"));
+    let diff_path = options.directory.join(format!("{name}.diff.png"));
+
+    if std::env::var_os(UPDATE_SNAPSHOTS_VAR).is_some() || !snapshot_path.exists() {
+        write_png(&snapshot_path, &actual);
+        let _ = std::fs::remove_file(&actual_path);
+        let _ = std::fs::remove_file(&diff_path);
+        return;
+    }
+
+    let expected = read_png(&snapshot_path);
+
+    match compare(&expected, &actual, options.tolerance) {
+        Comparison::Match => {
+            let _ = std::fs::remove_file(&actual_path);
+            let _ = std::fs::remove_file(&diff_path);
+        }
+
+        Comparison::SizeMismatch => {
+            write_png(&actual_path, &actual);
+            panic!(
+                "Snapshot `{name}` is {}x{} but the rendered frame is {}x{}. The rendered frame was written to {}.",
+                expected.width(),
+                expected.height(),
+                actual.width(),
+                actual.height(),
+                actual_path.display(),
+            );
+        }
+
+        Comparison::Mismatch { changed_pixels, diff } => {
+            write_png(&actual_path, &actual);
+            write_png(&diff_path, &diff);
+            panic!(
+                "Snapshot `{name}` differs in {changed_pixels} pixels. The rendered frame was written to {} and the difference to {}.",
+                actual_path.display(),
+                diff_path.display(),
+            );
+        }
+    }
+}
+
+/// The result of comparing a rendered frame against a stored snapshot.
+pub enum Comparison {
+    /// No pixel differs by more than the tolerance.
+    Match,
+    /// The frame and the snapshot have different dimensions.
+    SizeMismatch,
+    /// Some pixels differ by more than the tolerance.
+    Mismatch {
+        /// The number of pixels which differ by more than the tolerance.
+        changed_pixels: usize,
+        /// An image of the snapshot with the changed pixels highlighted in red.
+        diff: Image,
+    },
+}
+
+/// Compares two images pixel by pixel, treating channel differences up to the tolerance as equal.
+pub fn compare(expected: &Image, actual: &Image, tolerance: u8) -> Comparison {
+    if expected.dimensions() != actual.dimensions() {
+        return Comparison::SizeMismatch;
+    }
+
+    let info =
+        ImageInfo::new(expected.dimensions(), ColorType::RGBA8888, AlphaType::Unpremul, None);
+    let expected_pixels = read_pixels(expected, &info);
+    let actual_pixels = read_pixels(actual, &info);
+
+    let mut changed_pixels = 0;
+    let mut diff_pixels = Vec::with_capacity(expected_pixels.len());
+
+    for (expected, actual) in expected_pixels.chunks_exact(4).zip(actual_pixels.chunks_exact(4)) {
+        let changed = expected.iter().zip(actual).any(|(e, a)| e.abs_diff(*a) > tolerance);
+
+        if changed {
+            changed_pixels += 1;
+            diff_pixels.extend_from_slice(&[255, 0, 0, 255]);
+        } else {
+            // Fade unchanged pixels so that the changed pixels stand out.
+            let luma = (expected[0] as u32 * 3 + expected[1] as u32 * 6 + expected[2] as u32) / 10;
+            let faded = (128 + luma / 2) as u8;
+            diff_pixels.extend_from_slice(&[faded, faded, faded, 255]);
+        }
+    }
+
+    if changed_pixels == 0 {
+        return Comparison::Match;
+    }
+
+    let diff = images::raster_from_data(&info, Data::new_copy(&diff_pixels), info.min_row_bytes())
+        .expect("Failed to create diff image");
+
+    Comparison::Mismatch { changed_pixels, diff }
+}
+
+fn read_pixels(image: &Image, info: &ImageInfo) -> Vec<u8> {
+    let mut pixels = vec![0u8; info.compute_min_byte_size()];
+    if !image.read_pixels(info, &mut pixels, info.min_row_bytes(), (0, 0), CachingHint::Disallow) {
+        panic!("Failed to read snapshot pixels");
+    }
+
+    pixels
+}
+
+fn read_png(path: &Path) -> Image {
+    let data = std::fs::read(path)
+        .unwrap_or_else(|err| panic!("Failed to read snapshot {}: {err}", path.display()));
+
+    Image::from_encoded(Data::new_copy(&data))
+        .unwrap_or_else(|| panic!("Failed to decode snapshot {}", path.display()))
+}
+
+fn write_png(path: &Path, image: &Image) {
+    let data = image
+        .encode(None, EncodedImageFormat::PNG, None)
+        .unwrap_or_else(|| panic!("Failed to encode snapshot {}", path.display()));
+
+    if let Some(directory) = path.parent() {
+        std::fs::create_dir_all(directory).unwrap_or_else(|err| {
+            panic!("Failed to create snapshot directory {}: {err}", directory.display())
+        });
+    }
+
+    std::fs::write(path, data.as_bytes())
+        .unwrap_or_else(|err| panic!("Failed to write snapshot {}: {err}", path.display()));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Creates a 2x1 image from the RGBA values of its two pixels.
+    fn image(pixels: [[u8; 4]; 2]) -> Image {
+        let info = ImageInfo::new((2, 1), ColorType::RGBA8888, AlphaType::Unpremul, None);
+        images::raster_from_data(&info, Data::new_copy(&pixels.concat()), 8).unwrap()
+    }
+
+    #[test]
+    fn differences_within_tolerance_match() {
+        let expected = image([[10, 20, 30, 255], [40, 50, 60, 255]]);
+        let actual = image([[12, 20, 28, 255], [40, 51, 60, 255]]);
+
+        assert!(matches!(compare(&expected, &actual, 2), Comparison::Match));
+    }
+
+    #[test]
+    fn differences_beyond_tolerance_are_counted() {
+        let expected = image([[10, 20, 30, 255], [40, 50, 60, 255]]);
+        let actual = image([[13, 20, 30, 255], [40, 50, 60, 255]]);
+
+        let Comparison::Mismatch { changed_pixels, diff } = compare(&expected, &actual, 2) else {
+            panic!("Expected a mismatch");
+        };
+
+        assert_eq!(changed_pixels, 1);
+        assert_eq!(diff.dimensions(), expected.dimensions());
+    }
+
+    #[test]
+    fn label_renders_identically_with_bundled_font() {
+        let options = SnapshotOptions::new().with_size(120, 40);
+        let content = |cx: &mut Context| {
+            Label::new(cx, "Snapshot");
+        };
+
+        let first = render(&options, content);
+        let second = render(&options, content);
+        assert!(matches!(compare(&first, &second, 0), Comparison::Match));
+
+        // System fonts are disabled, so any dark pixels must have been drawn with the bundled font.
+        let info =
+            ImageInfo::new(first.dimensions(), ColorType::RGBA8888, AlphaType::Unpremul, None);
+        let pixels = read_pixels(&first, &info);
+        assert!(pixels.chunks_exact(4).any(|pixel| pixel[0] < 128));
+    }
+
+    #[test]
+    fn different_dimensions_mismatch() {
+        let expected = image([[0, 0, 0, 255], [0, 0, 0, 255]]);
+        let info = ImageInfo::new((1, 1), ColorType::RGBA8888, AlphaType::Unpremul, None);
+        let actual = images::raster_from_data(&info, Data::new_copy(&[0, 0, 0, 255]), 4).unwrap();
+
+        assert!(matches!(compare(&expected, &actual, 0), Comparison::SizeMismatch));
+    }
+}
@@ -1,3 +1,5 @@
+mod monitor;
 mod window_description;
 
+pub use monitor::*;
 pub use window_description::*;
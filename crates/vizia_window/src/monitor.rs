@@ -0,0 +1,35 @@
+use crate::WindowSize;
+
+/// Information about a monitor connected to the system.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonitorInfo {
+    /// The human-readable name of the monitor, if available.
+    pub name: Option<String>,
+    /// The x position of the top left corner of the monitor on the desktop, in physical pixels.
+    pub x: i32,
+    /// The y position of the top left corner of the monitor on the desktop, in physical pixels.
+    pub y: i32,
+    /// The resolution of the monitor in physical pixels.
+    pub size: WindowSize,
+    /// The area of the monitor which isn't covered by the taskbar, dock or other system bars, if available.
+    ///
+    /// This is currently only reported on Windows.
+    pub work_area: Option<WorkArea>,
+    /// The refresh rate of the monitor in millihertz, if available.
+    pub refresh_rate_millihertz: Option<u32>,
+    /// The scale factor used to convert between logical and physical pixels on the monitor.
+    pub scale_factor: f64,
+    /// Whether the monitor is the primary monitor of the system.
+    pub is_primary: bool,
+}
+
+/// An area of the desktop, in physical pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WorkArea {
+    /// The x position of the top left corner of the area on the desktop.
+    pub x: i32,
+    /// The y position of the top left corner of the area on the desktop.
+    pub y: i32,
+    /// The size of the area.
+    pub size: WindowSize,
+}
@@ -63,6 +63,108 @@ bitflags::bitflags! {
     }
 }
 
+/// How the alpha channel of a transparent window is presented to the OS compositor.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlphaMode {
+    /// Color channels are multiplied by alpha. This is what most compositors expect.
+    #[default]
+    Premultiplied,
+    /// Color channels are independent of alpha, for compositors which expect straight alpha.
+    Unpremultiplied,
+}
+
+/// The stacking level of a window relative to other windows.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowLevel {
+    /// The window is kept below all other windows.
+    AlwaysOnBottom,
+    /// The window is stacked normally.
+    #[default]
+    Normal,
+    /// The window is kept above all other windows.
+    AlwaysOnTop,
+}
+
+/// How urgently a window requests the attention of the user.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UrgencyLevel {
+    /// Requests attention until the window is focused, such as by continuously flashing the taskbar entry of
+    /// the window on Windows or bouncing the dock icon on macOS.
+    Critical,
+    /// Requests attention briefly, such as by flashing the taskbar entry of the window once on Windows or
+    /// bouncing the dock icon once on macOS.
+    Informational,
+}
+
+/// The edge or corner of a window from which it is resized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResizeDirection {
+    East,
+    North,
+    NorthEast,
+    NorthWest,
+    South,
+    SouthEast,
+    SouthWest,
+    West,
+}
+
+/// How the mouse cursor is grabbed by a window.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CursorGrabMode {
+    /// The cursor can move freely.
+    #[default]
+    None,
+    /// The cursor is confined to the window area.
+    Confined,
+    /// The cursor is locked in place. Relative mouse movement is reported with
+    /// `WindowEvent::RawMouseMotion` while the cursor is grabbed.
+    Locked,
+}
+
+/// How rendered frames are presented to the display.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PresentMode {
+    /// Frames are presented in sync with the refresh rate of the display. This is the same as enabling vsync.
+    #[default]
+    Fifo,
+    /// Frames are presented as soon as they are rendered, which has the lowest latency but may cause tearing.
+    Immediate,
+    /// Frames are presented in sync with the display, replacing any frame still waiting to be presented.
+    /// Backends which don't support this mode fall back to [`PresentMode::Immediate`].
+    Mailbox,
+}
+
+/// A display mode used by a window in exclusive fullscreen.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VideoMode {
+    /// The resolution of the display mode in physical pixels.
+    pub size: WindowSize,
+    /// The number of bits per pixel.
+    pub bit_depth: u16,
+    /// The refresh rate of the display mode in millihertz.
+    pub refresh_rate_millihertz: u32,
+}
+
+impl VideoMode {
+    pub fn new(width: u32, height: u32, bit_depth: u16, refresh_rate_millihertz: u32) -> Self {
+        Self { size: WindowSize::new(width, height), bit_depth, refresh_rate_millihertz }
+    }
+}
+
+/// Whether a window is shown in a window or fullscreen.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FullscreenMode {
+    /// The window is shown in a window.
+    #[default]
+    Windowed,
+    /// The window covers the monitor it is on without changing the display mode of the monitor.
+    Borderless,
+    /// The window takes exclusive control of the monitor it is on, using the available display mode which most
+    /// closely matches the given video mode.
+    Exclusive(VideoMode),
+}
+
 /// Passed to the window to set initial window properties.
 #[derive(Clone, Debug)]
 pub struct WindowDescription {
@@ -78,10 +180,39 @@ pub struct WindowDescription {
     pub maximized: bool,
     pub visible: bool,
     pub transparent: bool,
+    /// The alpha format of the window contents when the window is transparent.
+    pub alpha_mode: AlphaMode,
+    /// The opacity applied to the window contents when the window is transparent, from 0.0 to 1.0.
+    pub opacity: f32,
+    /// Whether the background behind a transparent window is blurred by the OS compositor.
+    pub blur: bool,
     pub decorations: bool,
+    /// Whether the window is hidden from the taskbar.
+    pub skip_taskbar: bool,
+    /// Whether the window remains on top of other windows. This only takes effect while `window_level` is
+    /// [`WindowLevel::Normal`].
+    #[deprecated(note = "use `window_level` instead")]
     pub always_on_top: bool,
+    /// The stacking level of the window relative to other windows.
+    pub window_level: WindowLevel,
+    /// Whether the window is shown in a window or fullscreen.
+    pub fullscreen: FullscreenMode,
     pub vsync: bool,
+    pub present_mode: PresentMode,
+    /// The number of samples used for multisample anti-aliasing, or `None` to use the driver default.
+    pub msaa_samples: Option<u8>,
+    /// The maximum rate, in frames per second, at which the window redraws while animating or updating,
+    /// or `None` for no limit.
+    pub max_fps: Option<u32>,
+    /// Whether to render a throwaway frame off-screen before the window is first shown, so that GPU
+    /// pipelines are compiled ahead of the first real frame.
+    pub prewarm: bool,
     pub enabled_window_buttons: WindowButtons,
+    /// The application identifier used by the OS for taskbar grouping and desktop-file matching.
+    ///
+    /// This is the `AppUserModelID` on Windows and the `app_id`/`WM_CLASS` on Wayland/X11.
+    /// It has no effect on macOS.
+    pub app_id: Option<String>,
 
     // Change this to resource id when the resource manager is working
     pub icon: Option<Vec<u8>>,
@@ -90,6 +221,7 @@ pub struct WindowDescription {
 }
 
 impl Default for WindowDescription {
+    #[allow(deprecated)]
     fn default() -> Self {
         Self {
             title: "Vizia Application".to_string(),
@@ -99,14 +231,25 @@ impl Default for WindowDescription {
             user_scale_factor: 1.0,
             position: None,
             resizable: true,
-            minimized: true,
+            minimized: false,
             maximized: false,
             visible: true,
             transparent: false,
+            alpha_mode: AlphaMode::Premultiplied,
+            opacity: 1.0,
+            blur: false,
             decorations: true,
+            skip_taskbar: false,
             always_on_top: false,
+            window_level: WindowLevel::Normal,
+            fullscreen: FullscreenMode::Windowed,
             vsync: true,
+            present_mode: PresentMode::Fifo,
+            msaa_samples: None,
+            max_fps: None,
+            prewarm: false,
             enabled_window_buttons: WindowButtons::all(),
+            app_id: None,
 
             icon: None,
             icon_width: 0,
@@ -126,8 +269,58 @@ impl WindowDescription {
         self
     }
 
+    /// Sets whether the window is shown in a window or fullscreen.
+    pub fn with_fullscreen(mut self, fullscreen: FullscreenMode) -> Self {
+        self.fullscreen = fullscreen;
+
+        self
+    }
+
+    /// Sets whether vsync is enabled, which selects the [`PresentMode::Fifo`] present mode when enabled and
+    /// [`PresentMode::Immediate`] when disabled.
     pub fn with_vsync(mut self, vsync: bool) -> Self {
         self.vsync = vsync;
+        self.present_mode = if vsync { PresentMode::Fifo } else { PresentMode::Immediate };
+
+        self
+    }
+
+    /// Sets how rendered frames are presented to the display.
+    pub fn with_present_mode(mut self, present_mode: PresentMode) -> Self {
+        self.vsync = present_mode == PresentMode::Fifo;
+        self.present_mode = present_mode;
+
+        self
+    }
+
+    /// Sets the number of samples used for multisample anti-aliasing, overriding the driver default.
+    ///
+    /// A count of 0 or 1 disables multisampling. Counts which are not a power of two are rounded down to the nearest
+    /// power of two.
+    pub fn with_msaa_samples(mut self, samples: u8) -> Self {
+        self.msaa_samples = Some(match samples {
+            0 | 1 => 1,
+            _ => 1 << (u8::BITS - 1 - samples.leading_zeros()),
+        });
+
+        self
+    }
+
+    /// Sets the maximum rate, in frames per second, at which the window redraws.
+    ///
+    /// Redraws requested before the next frame is due are deferred rather than dropped, so animations keep
+    /// running at the capped rate while an idle window does not redraw at all. Any application-wide frame rate
+    /// limit set by the backend still applies, so the lower of the two rates takes effect.
+    pub fn with_max_fps(mut self, max_fps: Option<u32>) -> Self {
+        self.max_fps = max_fps.filter(|fps| *fps > 0);
+
+        self
+    }
+
+    /// Sets whether to warm up the GPU pipeline by rendering a throwaway frame before the window is
+    /// first shown. This reduces stutter on the first frames after a cold start.
+    pub fn with_prewarm(mut self, prewarm: bool) -> Self {
+        self.prewarm = prewarm;
 
         self
     }
@@ -138,6 +331,13 @@ impl WindowDescription {
         self
     }
 
+    /// Sets the initial logical position of the top left corner of the window on the desktop.
+    pub fn with_position(mut self, x: u32, y: u32) -> Self {
+        self.position = Some(WindowPosition::new(x, y));
+
+        self
+    }
+
     pub fn with_min_inner_size(mut self, width: u32, height: u32) -> Self {
         self.min_inner_size = Some(WindowSize::new(width, height));
 
@@ -158,8 +358,91 @@ impl WindowDescription {
         self
     }
 
+    /// Sets whether the window starts maximized. Ignored for windows parented to a host window.
+    pub fn with_maximized(mut self, flag: bool) -> Self {
+        self.maximized = flag;
+
+        self
+    }
+
+    /// Sets whether the window starts minimized. Ignored for windows parented to a host window.
+    pub fn with_minimized(mut self, flag: bool) -> Self {
+        self.minimized = flag;
+
+        self
+    }
+
+    #[allow(deprecated)]
     pub fn with_always_on_top(mut self, flag: bool) -> Self {
         self.always_on_top = flag;
+        self.window_level = if flag { WindowLevel::AlwaysOnTop } else { WindowLevel::Normal };
+
+        self
+    }
+
+    /// Sets the stacking level of the window relative to other windows.
+    #[allow(deprecated)]
+    pub fn with_window_level(mut self, window_level: WindowLevel) -> Self {
+        self.always_on_top = window_level == WindowLevel::AlwaysOnTop;
+        self.window_level = window_level;
+
+        self
+    }
+
+    /// Returns the stacking level the window is created with, taking the deprecated `always_on_top` field into
+    /// account.
+    #[allow(deprecated)]
+    pub fn resolved_window_level(&self) -> WindowLevel {
+        if self.always_on_top && self.window_level == WindowLevel::Normal {
+            WindowLevel::AlwaysOnTop
+        } else {
+            self.window_level
+        }
+    }
+
+    /// Sets whether the window is transparent, allowing content behind the window to show through
+    /// any parts of the window which are not fully opaque.
+    pub fn with_transparent(mut self, flag: bool) -> Self {
+        self.transparent = flag;
+
+        self
+    }
+
+    /// Sets the opacity applied to the window contents, from 0.0 to 1.0. Only has an effect when the
+    /// window is transparent.
+    pub fn with_opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity.clamp(0.0, 1.0);
+
+        self
+    }
+
+    /// Sets whether the background behind the window is blurred. Only has an effect when the window is
+    /// transparent, and is only supported on some platforms.
+    pub fn with_blur(mut self, flag: bool) -> Self {
+        self.blur = flag;
+
+        self
+    }
+
+    /// Sets the alpha format of the window contents presented to the OS compositor when the
+    /// window is transparent.
+    pub fn with_alpha_mode(mut self, alpha_mode: AlphaMode) -> Self {
+        self.alpha_mode = alpha_mode;
+
+        self
+    }
+
+    /// Sets whether the window has decorations, such as the title bar and borders provided by the OS.
+    pub fn with_decorations(mut self, flag: bool) -> Self {
+        self.decorations = flag;
+
+        self
+    }
+
+    /// Sets whether the window is hidden from the taskbar, such as for a floating tool palette. Only
+    /// supported on Windows and X11.
+    pub fn with_skip_taskbar(mut self, flag: bool) -> Self {
+        self.skip_taskbar = flag;
 
         self
     }
@@ -170,6 +453,14 @@ impl WindowDescription {
         self
     }
 
+    /// Sets the application identifier used by the OS for taskbar grouping, icon association and
+    /// desktop-file matching. Has no effect on macOS.
+    pub fn with_app_id(mut self, app_id: impl Into<String>) -> Self {
+        self.app_id = Some(app_id.into());
+
+        self
+    }
+
     pub fn with_icon(mut self, icon: Vec<u8>, width: u32, height: u32) -> Self {
         self.icon = Some(icon);
         self.icon_width = width;
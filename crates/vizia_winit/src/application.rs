@@ -1,10 +1,14 @@
 use crate::{
-    convert::{winit_key_code_to_code, winit_key_to_key},
-    window::{WinState, Window},
+    convert::{
+        fullscreen_mode_to_fullscreen, icon_to_icon, monitor_handle_to_monitor_info,
+        window_level_to_window_level, winit_key_code_to_code, winit_key_to_key,
+        winit_theme_to_theme_mode,
+    },
+    window::{ResizableSurface, WinState, Window},
     window_modifiers::WindowModifiers,
 };
 use hashbrown::HashMap;
-use std::{error::Error, fmt::Display, sync::Arc};
+use std::{any::Any, error::Error, fmt::Display, sync::Arc, thread::JoinHandle};
 
 // #[cfg(feature = "accesskit")]
 // use accesskit::{Action, NodeBuilder, NodeId, TreeUpdate};
@@ -16,19 +20,17 @@ use vizia_core::prelude::*;
 use vizia_core::{backend::*, events::EventManager};
 use winit::{
     application::ApplicationHandler,
-    dpi::{LogicalPosition, LogicalSize},
+    dpi::{LogicalPosition, LogicalSize, PhysicalSize},
     error::EventLoopError,
-    event::ElementState,
+    event::{DeviceEvent, DeviceId, ElementState},
     event_loop::{ActiveEventLoop, ControlFlow, EventLoop, EventLoopProxy},
     keyboard::{NativeKeyCode, PhysicalKey},
-    window::{CursorIcon, CustomCursor, WindowAttributes, WindowId, WindowLevel},
+    raw_window_handle::{HasDisplayHandle, HasWindowHandle},
+    window::{CursorIcon, CustomCursor, WindowAttributes, WindowId},
 };
 
 #[cfg(target_os = "windows")]
-use winit::{
-    platform::windows::WindowAttributesExtWindows,
-    raw_window_handle::{HasWindowHandle, RawWindowHandle},
-};
+use winit::{platform::windows::WindowAttributesExtWindows, raw_window_handle::RawWindowHandle};
 // #[cfg(all(
 //     feature = "clipboard",
 //     feature = "wayland",
@@ -46,6 +48,11 @@ use vizia_window::WindowPosition;
 #[derive(Debug)]
 pub enum UserEvent {
     Event(Event),
+    /// The accessibility preferences of the system, queried on a background thread.
+    AccessibilityPreferences {
+        reduced_motion: Option<bool>,
+        high_contrast: Option<bool>,
+    },
     #[cfg(feature = "accesskit")]
     AccessKitActionRequest(accesskit_winit::ActionRequestEvent),
 }
@@ -65,6 +72,23 @@ impl From<vizia_core::events::Event> for UserEvent {
 
 type IdleCallback = Option<Box<dyn Fn(&mut Context)>>;
 
+type FrameCallback = Option<Box<dyn Fn(&mut Context, Duration)>>;
+
+type PlatformEventCallback =
+    Option<Box<dyn Fn(&mut Context, Entity, &winit::event::WindowEvent) -> bool>>;
+
+/// Limits how often the application redraws, independently of vsync.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum FrameRateLimit {
+    /// Redraw as often as needed.
+    #[default]
+    Unlimited,
+    /// Redraw at most once per refresh of the monitor the window is on.
+    RefreshRate,
+    /// Redraw at most the given number of times per second.
+    Fps(u32),
+}
+
 #[derive(Debug)]
 pub enum ApplicationError {
     EventLoopError(EventLoopError),
@@ -99,8 +123,15 @@ pub struct Application {
     event_manager: EventManager,
     pub(crate) event_loop: Option<EventLoop<UserEvent>>,
     on_idle: IdleCallback,
+    on_frame: FrameCallback,
+    on_platform_event: PlatformEventCallback,
     window_description: WindowDescription,
     control_flow: ControlFlow,
+    frame_rate_limit: FrameRateLimit,
+    /// Whether the accessibility preferences of the system are being queried.
+    accessibility_query_pending: bool,
+    /// The time at which the frame callback was last called.
+    last_frame_callback_time: Option<Instant>,
     event_loop_proxy: EventLoopProxy<UserEvent>,
     windows: HashMap<WindowId, WinState>,
     window_ids: HashMap<Entity, WindowId>,
@@ -123,11 +154,18 @@ impl Application {
     where
         F: 'static + FnOnce(&mut Context),
     {
-        let context = Context::new();
-
         let event_loop =
             EventLoop::<UserEvent>::with_user_event().build().expect("Failed to create event loop");
 
+        Self::with_event_loop(event_loop, content)
+    }
+
+    fn with_event_loop<F>(event_loop: EventLoop<UserEvent>, content: F) -> Self
+    where
+        F: 'static + FnOnce(&mut Context),
+    {
+        let context = Context::new();
+
         let mut cx = BackendContext::new(context);
         let event_proxy_obj = event_loop.create_proxy();
         cx.set_event_proxy(Box::new(WinitEventProxy(event_proxy_obj)));
@@ -143,8 +181,13 @@ impl Application {
             event_manager: EventManager::new(),
             event_loop: Some(event_loop),
             on_idle: None,
+            on_frame: None,
+            on_platform_event: None,
             window_description: WindowDescription::new(),
             control_flow: ControlFlow::Wait,
+            frame_rate_limit: FrameRateLimit::Unlimited,
+            accessibility_query_pending: false,
+            last_frame_callback_time: None,
             event_loop_proxy: proxy,
             windows: HashMap::new(),
             window_ids: HashMap::new(),
@@ -190,12 +233,56 @@ impl Application {
 
             #[cfg(not(target_os = "windows"))]
             {
-                event_loop.create_window(window_attributes)?
+                // A prewarmed window is hidden until the throwaway frame has been rendered.
+                let visible = window_attributes.visible && !window_description.prewarm;
+                event_loop.create_window(window_attributes.with_visible(visible))?
             }
         };
 
+        if window_description.minimized {
+            window.set_minimized(true);
+        }
+
+        self.cx.set_raw_window_handle(
+            window_entity,
+            window.window_handle().ok().map(|handle| handle.as_raw()),
+        );
+        self.cx.set_raw_display_handle(
+            window_entity,
+            window.display_handle().ok().map(|handle| handle.as_raw()),
+        );
+
+        // The monitor used for fullscreen is only known once the window has been created.
+        if window_description.fullscreen != FullscreenMode::Windowed {
+            window.set_fullscreen(fullscreen_mode_to_fullscreen(
+                &window,
+                window_description.fullscreen,
+            ));
+        }
+
         let window = Arc::new(window);
-        let window_state = WinState::new(event_loop, window.clone(), window_entity)?;
+        let mut window_state =
+            WinState::new(event_loop, window.clone(), window_entity, window_description)?;
+
+        // A maximized window may already differ in size from the inner size of the description,
+        // so the surface is resized before the first frame rather than waiting for a resize event.
+        if window_description.maximized {
+            window_state.resize(window.inner_size());
+        }
+
+        if window_description.prewarm {
+            window_state.prewarm();
+
+            // On Windows the window is instead cloaked until its first frame has been drawn.
+            #[cfg(not(target_os = "windows"))]
+            window.set_visible(window_description.visible);
+        }
+
+        // Views are told the initial fullscreen state, as later changes are only reported when they happen.
+        self.cx.emit_window_event(
+            window_entity,
+            WindowEvent::FullscreenChanged(window_state.is_fullscreen()),
+        );
 
         let window_id = window_state.window.id();
         self.windows.insert(window_id, window_state);
@@ -203,6 +290,21 @@ impl Application {
         Ok(window)
     }
 
+    // Updates the monitors connected to the system and the monitor each window is on.
+    fn update_monitors(&mut self, event_loop: &ActiveEventLoop) {
+        let primary_monitor = event_loop.primary_monitor();
+
+        self.cx.set_monitors(available_monitors(event_loop));
+
+        for window in self.windows.values() {
+            let monitor = window
+                .window()
+                .current_monitor()
+                .map(|monitor| monitor_handle_to_monitor_info(&monitor, primary_monitor.as_ref()));
+            self.cx.set_window_monitor(window.entity, monitor);
+        }
+    }
+
     /// Sets the default built-in theming to be ignored.
     pub fn ignore_default_theme(mut self) -> Self {
         self.cx.context().ignore_default_theme = true;
@@ -215,6 +317,46 @@ impl Application {
         self
     }
 
+    /// Caps how often the application redraws, which avoids rendering far more frames than can be
+    /// displayed when vsync is disabled.
+    ///
+    /// When a redraw is requested before the frame budget has elapsed, the redraw is deferred until the
+    /// budget has elapsed, while the event loop keeps handling input. The limit only applies while actively
+    /// redrawing.
+    ///
+    /// This limit applies to every window, in addition to the per-window limit set with
+    /// [`max_fps`](crate::window_modifiers::WindowModifiers::max_fps), so each window redraws at the lower of
+    /// the two rates.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_winit::application::{Application, FrameRateLimit};
+    /// Application::new(|cx|{
+    ///     // Content here
+    /// })
+    /// .vsync(false)
+    /// .frame_rate_limit(FrameRateLimit::RefreshRate)
+    /// .run();
+    /// ```
+    pub fn frame_rate_limit(mut self, limit: FrameRateLimit) -> Self {
+        self.frame_rate_limit = limit;
+
+        self
+    }
+
+    // Calls the frame callback with the time elapsed since the previous frame was drawn.
+    fn frame_callback(&mut self) {
+        let now = Instant::now();
+        let dt = self.last_frame_callback_time.map_or(Duration::ZERO, |time| now - time);
+        self.last_frame_callback_time = Some(now);
+
+        if let Some(frame_callback) = &self.on_frame {
+            self.cx.set_current(Entity::root());
+            (frame_callback)(self.cx.context(), dt);
+        }
+    }
+
     /// Takes a closure which will be called at the end of every loop of the application.
     ///
     /// The callback provides a place to run 'idle' processing and happens at the end of each loop but before drawing.
@@ -241,14 +383,215 @@ impl Application {
         self
     }
 
+    /// Takes a closure which will be called once for every frame which is drawn, with the time elapsed since the previous frame.
+    ///
+    /// Unlike [`on_idle()`](Self::on_idle), the callback only runs when a frame has actually been produced, so it
+    /// ticks at the rate the application redraws. This makes it a place to advance custom animations or simulations.
+    /// Changes made in the callback which cause a redraw will produce another frame, and so another call to the callback.
+    /// The elapsed time is zero for the first frame.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_winit::application::Application;
+    /// #
+    /// Application::new(|cx| {
+    ///     // Build application here
+    /// })
+    /// .on_frame(|cx, dt| {
+    ///     // Code here runs after every frame, with the time since the previous frame
+    /// })
+    /// .run();
+    /// ```
+    pub fn on_frame<F: 'static + Fn(&mut Context, Duration)>(mut self, callback: F) -> Self {
+        self.on_frame = Some(Box::new(callback));
+
+        self
+    }
+
+    /// Takes a closure which will be called with every raw window event received from winit, before it is translated by vizia.
+    ///
+    /// The callback receives the entity of the window the event is for and the untranslated [`winit::event::WindowEvent`],
+    /// and returns whether vizia should also process the event. Returning `false` stops vizia from handling the event.
+    /// This is an escape hatch for platform features which vizia does not yet model.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_winit::application::Application;
+    /// #
+    /// Application::new(|cx| {
+    ///     // Build application here
+    /// })
+    /// .on_platform_event(|_cx, _window, event| {
+    ///     if let winit::event::WindowEvent::DoubleTapGesture { .. } = event {
+    ///         // Handle the gesture here
+    ///         return false;
+    ///     }
+    ///
+    ///     true
+    /// })
+    /// .run();
+    /// ```
+    pub fn on_platform_event<F>(mut self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut Context, Entity, &winit::event::WindowEvent) -> bool,
+    {
+        self.on_platform_event = Some(Box::new(callback));
+
+        self
+    }
+
     /// Returns a `ContextProxy` which can be used to send events from another thread.
     pub fn get_proxy(&self) -> ContextProxy {
         self.cx.0.get_proxy()
     }
 
     pub fn run(mut self) -> Result<(), ApplicationError> {
+        // The AppUserModelID applies to the whole process, so it is set once from the main window before any
+        // windows are created. The app identity is taken from the application bundle on macOS.
+        #[cfg(target_os = "windows")]
+        if let Some(app_id) = &self.window_description.app_id {
+            if let Err(result) = set_app_user_model_id(app_id) {
+                log::warn!(
+                    "Failed to set the AppUserModelID to {app_id:?} (HRESULT {result:#010x})"
+                );
+            }
+        }
+
         self.event_loop.take().unwrap().run_app(&mut self).map_err(ApplicationError::EventLoopError)
     }
+
+    /// Runs the application on a dedicated thread without blocking the current thread.
+    ///
+    /// The `content` closure builds the views of the application, as with [`Application::new()`], and the
+    /// `configure` closure can be used to apply window modifiers before the event loop starts. Returns an
+    /// [`ApplicationHandle`] which can be used to send events to the application and to request shutdown.
+    ///
+    /// # Platform-specific
+    ///
+    /// - **macOS / iOS:** Not available, the event loop must be run on the main thread.
+    /// - **Android / Web:** Not available.
+    /// - **Windows / X11 / Wayland:** The event loop is created with `with_any_thread(true)`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_winit::application::Application;
+    /// # use vizia_winit::window_modifiers::WindowModifiers;
+    /// let handle = Application::open(
+    ///     |cx| {
+    ///         Label::new(cx, "Hello Vizia");
+    ///     },
+    ///     |app| app.title("Background Application"),
+    /// )
+    /// .expect("Failed to open application");
+    ///
+    /// // Do some other work here...
+    ///
+    /// handle.close().ok();
+    /// handle.join().ok();
+    /// ```
+    #[cfg(any(
+        target_os = "windows",
+        all(unix, not(any(target_os = "macos", target_os = "ios", target_os = "android")))
+    ))]
+    pub fn open<F, C>(content: F, configure: C) -> Result<ApplicationHandle, ApplicationError>
+    where
+        F: 'static + Send + FnOnce(&mut Context),
+        C: 'static + Send + FnOnce(Self) -> Self,
+    {
+        let (sender, receiver) = std::sync::mpsc::channel();
+
+        let thread = std::thread::spawn(move || {
+            let event_loop = match build_any_thread_event_loop() {
+                Ok(event_loop) => event_loop,
+                Err(err) => {
+                    let _ = sender.send(Err(err));
+                    return Ok(());
+                }
+            };
+
+            let _ = sender.send(Ok(event_loop.create_proxy()));
+
+            (configure)(Application::with_event_loop(event_loop, content)).run()
+        });
+
+        match receiver.recv() {
+            Ok(Ok(proxy)) => Ok(ApplicationHandle { proxy, thread }),
+            Ok(Err(err)) => Err(ApplicationError::EventLoopError(err)),
+            // The sender is only dropped without sending if the thread panicked.
+            Err(_) => std::panic::resume_unwind(thread.join().unwrap_err()),
+        }
+    }
+}
+
+#[cfg(any(
+    target_os = "windows",
+    all(unix, not(any(target_os = "macos", target_os = "ios", target_os = "android")))
+))]
+fn build_any_thread_event_loop() -> Result<EventLoop<UserEvent>, EventLoopError> {
+    let mut builder = EventLoop::<UserEvent>::with_user_event();
+
+    #[cfg(target_os = "windows")]
+    winit::platform::windows::EventLoopBuilderExtWindows::with_any_thread(&mut builder, true);
+
+    #[cfg(all(feature = "x11", unix, not(target_os = "windows")))]
+    winit::platform::x11::EventLoopBuilderExtX11::with_any_thread(&mut builder, true);
+
+    #[cfg(all(feature = "wayland", unix, not(target_os = "windows")))]
+    winit::platform::wayland::EventLoopBuilderExtWayland::with_any_thread(&mut builder, true);
+
+    builder.build()
+}
+
+/// A handle to an application running on a dedicated thread, returned by [`Application::open()`].
+pub struct ApplicationHandle {
+    proxy: EventLoopProxy<UserEvent>,
+    thread: JoinHandle<Result<(), ApplicationError>>,
+}
+
+impl ApplicationHandle {
+    /// Sends an event containing the provided message to the root window of the application.
+    pub fn emit<M: Any + Send>(&self, message: M) -> Result<(), ProxyEmitError> {
+        self.emit_custom(
+            Event::new(message)
+                .target(Entity::root())
+                .origin(Entity::root())
+                .propagate(Propagation::Up),
+        )
+    }
+
+    /// Sends a custom event with custom origin and propagation information to the application.
+    pub fn emit_custom(&self, event: Event) -> Result<(), ProxyEmitError> {
+        self.proxy.send_event(UserEvent::Event(event)).map_err(|_| ProxyEmitError::EventLoopClosed)
+    }
+
+    /// Returns a [`ContextProxy`] which can be moved between threads and used to send events to the application.
+    pub fn get_proxy(&self) -> ContextProxy {
+        ContextProxy {
+            current: Entity::root(),
+            event_proxy: Some(Box::new(WinitEventProxy(self.proxy.clone()))),
+        }
+    }
+
+    /// Requests that the application closes its main window and exits the event loop.
+    pub fn close(&self) -> Result<(), ProxyEmitError> {
+        self.emit(WindowEvent::WindowClose)
+    }
+
+    /// Returns true if the event loop of the application has exited.
+    pub fn is_finished(&self) -> bool {
+        self.thread.is_finished()
+    }
+
+    /// Blocks the current thread until the event loop of the application has exited.
+    pub fn join(self) -> Result<(), ApplicationError> {
+        self.thread.join().unwrap_or_else(|err| std::panic::resume_unwind(err))
+    }
 }
 
 impl ApplicationHandler<UserEvent> for Application {
@@ -258,6 +601,18 @@ impl ApplicationHandler<UserEvent> for Application {
                 self.cx.send_event(event);
             }
 
+            UserEvent::AccessibilityPreferences { reduced_motion, high_contrast } => {
+                self.accessibility_query_pending = false;
+
+                if let Some(flag) = reduced_motion {
+                    self.cx.set_reduced_motion(flag);
+                }
+
+                if let Some(flag) = high_contrast {
+                    self.cx.set_high_contrast(flag);
+                }
+            }
+
             #[cfg(feature = "accesskit")]
             UserEvent::AccessKitActionRequest(action_request_event) => {
                 let node_id = action_request_event.request.target;
@@ -287,12 +642,30 @@ impl ApplicationHandler<UserEvent> for Application {
             .expect("failed to create initial window");
         let custom_cursors = Arc::new(load_default_cursors(event_loop));
         self.cx.add_main_window(Entity::root(), &self.window_description, 1.0);
+
+        // Query the system theme before the first frame so an application following the system theme
+        // doesn't briefly draw with the wrong one.
+        if let Some(theme) = main_window.theme() {
+            self.cx.set_system_theme(winit_theme_to_theme_mode(theme));
+        }
+
+        // Winit doesn't report accessibility preferences, so they are queried from the platform directly.
+        query_accessibility_preferences(
+            &self.event_loop_proxy,
+            &mut self.accessibility_query_pending,
+        );
+
+        if self.window_description.maximized {
+            let size = main_window.inner_size();
+            self.cx.set_window_size(Entity::root(), size.width as f32, size.height as f32);
+        }
         self.cx.add_window(Window {
             window: Some(main_window.clone()),
             on_close: None,
             on_create: None,
             should_close: false,
             custom_cursors: custom_cursors.clone(),
+            cursor_icon_visible: true,
         });
 
         self.cx.0.windows.insert(
@@ -302,6 +675,14 @@ impl ApplicationHandler<UserEvent> for Application {
                 ..Default::default()
             },
         );
+        self.cx.set_raw_window_handle(
+            Entity::root(),
+            main_window.window_handle().ok().map(|handle| handle.as_raw()),
+        );
+        self.cx.set_raw_display_handle(
+            Entity::root(),
+            main_window.display_handle().ok().map(|handle| handle.as_raw()),
+        );
 
         self.cx.0.remove_user_themes();
 
@@ -319,6 +700,10 @@ impl ApplicationHandler<UserEvent> for Application {
                 .create_window(event_loop, window_entity, &window_state.window_description, owner)
                 .expect("Failed to create window");
             self.cx.add_main_window(window_entity, &window_state.window_description, 1.0);
+            if window_state.window_description.maximized {
+                let size = window.inner_size();
+                self.cx.set_window_size(window_entity, size.width as f32, size.height as f32);
+            }
             self.cx.mutate_window(window_entity, |cx, win: &mut Window| {
                 win.window = Some(window.clone());
                 win.custom_cursors = custom_cursors.clone();
@@ -328,14 +713,49 @@ impl ApplicationHandler<UserEvent> for Application {
             });
             self.cx.needs_refresh(window_entity);
         }
+
+        self.update_monitors(event_loop);
     }
 
-    fn window_event(
+    fn device_event(
         &mut self,
         _event_loop: &ActiveEventLoop,
+        _device_id: DeviceId,
+        event: DeviceEvent,
+    ) {
+        if let DeviceEvent::MouseMotion { delta: (dx, dy) } = event {
+            // Raw mouse motion is only reported to focused windows which have grabbed the cursor.
+            for window in self.windows.values() {
+                let grabbed =
+                    self.cx.0.windows.get(&window.entity).is_some_and(|window_state| {
+                        window_state.cursor_grab != CursorGrabMode::None
+                    });
+
+                if grabbed && window.window().has_focus() {
+                    self.cx.emit_window_event(
+                        window.entity,
+                        WindowEvent::RawMouseMotion(dx as f32, dy as f32),
+                    );
+                    window.window().request_redraw();
+                }
+            }
+        }
+    }
+
+    fn window_event(
+        &mut self,
+        event_loop: &ActiveEventLoop,
         window_id: WindowId,
         event: winit::event::WindowEvent,
     ) {
+        if let Some(callback) = &self.on_platform_event {
+            if let Some(window_entity) = self.windows.get(&window_id).map(|window| window.entity) {
+                if !(callback)(self.cx.context(), window_entity, &event) {
+                    return;
+                }
+            }
+        }
+
         let window = match self.windows.get_mut(&window_id) {
             Some(window) => window,
             None => return,
@@ -343,9 +763,29 @@ impl ApplicationHandler<UserEvent> for Application {
 
         match event {
             winit::event::WindowEvent::Resized(size) => {
-                window.resize(size);
-                self.cx.set_window_size(window.entity, size.width as f32, size.height as f32);
-                self.cx.needs_refresh(window.entity);
+                update_minimized_and_maximized(&mut self.cx, window, size);
+
+                if let Some(fullscreen) = window.update_fullscreen() {
+                    self.cx.emit_window_event(
+                        window.entity,
+                        WindowEvent::FullscreenChanged(fullscreen),
+                    );
+                }
+
+                // Not every platform enforces the size limits of a window, so a window resized outside of them is
+                // resized back. The surface keeps the actual size of the window meanwhile, so it covers the window.
+                if let Some(window_state) = self.cx.0.windows.get(&window.entity) {
+                    let clamped_size = clamp_inner_size(
+                        size,
+                        &window_state.window_description,
+                        window.window().scale_factor(),
+                    );
+                    if clamped_size != size {
+                        let _ = window.window().request_inner_size(clamped_size);
+                    }
+                }
+
+                apply_resize(&mut self.cx, window.entity, window, size);
                 window.window().request_redraw();
 
                 #[cfg(target_os = "windows")]
@@ -354,7 +794,7 @@ impl ApplicationHandler<UserEvent> for Application {
 
                     self.cx.process_style_updates();
 
-                    if self.cx.process_animations() {
+                    if self.cx.process_animations(Instant::now()) {
                         window.window().request_redraw();
                     }
 
@@ -373,20 +813,62 @@ impl ApplicationHandler<UserEvent> for Application {
 
             winit::event::WindowEvent::Moved(position) => {
                 self.cx.set_window_position(window.entity, position.x as f32, position.y as f32);
+
+                // The window may have moved to a monitor with a different refresh rate.
+                let primary_monitor = event_loop.primary_monitor();
+                let current_monitor = window.window().current_monitor();
+                window.set_refresh_rate(
+                    current_monitor.as_ref().and_then(|monitor| monitor.refresh_rate_millihertz()),
+                );
+                let monitor = current_monitor.map(|monitor| {
+                    monitor_handle_to_monitor_info(&monitor, primary_monitor.as_ref())
+                });
+                self.cx.set_window_monitor(window.entity, monitor);
+
+                let position = position.to_logical::<i32>(window.window().scale_factor());
+                self.cx.emit_window_event(
+                    window.entity,
+                    WindowEvent::WindowMoved(position.x, position.y),
+                );
             }
 
-            winit::event::WindowEvent::CloseRequested | winit::event::WindowEvent::Destroyed => {
+            winit::event::WindowEvent::CloseRequested => {
+                self.cx.request_window_close(window.entity);
+            }
+            winit::event::WindowEvent::Destroyed => {
                 let window_entity = window.entity;
                 self.cx.emit_window_event(window_entity, WindowEvent::WindowClose);
             }
             winit::event::WindowEvent::DroppedFile(path) => {
+                // Winit doesn't report the drop position, or move the cursor during the drag, so this is only the
+                // last known cursor position.
+                let (x, y) = (self.cx.0.mouse.cursor_x, self.cx.0.mouse.cursor_y);
+                self.cx.emit_window_event(
+                    window.entity,
+                    WindowEvent::FileDrop { path: path.clone(), x, y },
+                );
                 self.cx.emit_window_event(window.entity, WindowEvent::Drop(DropData::File(path)));
             }
 
-            winit::event::WindowEvent::HoveredFile(_) => {}
-            winit::event::WindowEvent::HoveredFileCancelled => {}
+            winit::event::WindowEvent::HoveredFile(path) => {
+                self.cx.emit_window_event(window.entity, WindowEvent::FileHover(path));
+            }
+            winit::event::WindowEvent::HoveredFileCancelled => {
+                self.cx.emit_window_event(window.entity, WindowEvent::FileHoverCancelled);
+            }
             winit::event::WindowEvent::Focused(is_focused) => {
-                self.cx.0.window_has_focus = is_focused;
+                self.cx.set_window_focused(window.entity, is_focused);
+                update_minimized_and_maximized(&mut self.cx, window, window.window().inner_size());
+                // The preferences may have been changed in the system settings while the application was in the
+                // background.
+                if is_focused {
+                    // Monitors may also have been connected or disconnected in the meantime.
+                    self.cx.set_monitors(available_monitors(event_loop));
+                    query_accessibility_preferences(
+                        &self.event_loop_proxy,
+                        &mut self.accessibility_query_pending,
+                    );
+                }
                 // #[cfg(feature = "accesskit")]
                 // accesskit.update_if_active(|| TreeUpdate {
                 //     nodes: vec![],
@@ -418,7 +900,9 @@ impl ApplicationHandler<UserEvent> for Application {
                 }
 
                 let event = match event.state {
-                    winit::event::ElementState::Pressed => WindowEvent::KeyDown(code, key),
+                    winit::event::ElementState::Pressed => {
+                        WindowEvent::KeyDown(code, key, event.repeat)
+                    }
                     winit::event::ElementState::Released => WindowEvent::KeyUp(code, key),
                 };
 
@@ -436,7 +920,17 @@ impl ApplicationHandler<UserEvent> for Application {
 
                 window.window().request_redraw();
             }
-            winit::event::WindowEvent::Ime(_) => {}
+            winit::event::WindowEvent::Ime(ime) => match ime {
+                winit::event::Ime::Preedit(text, cursor) => {
+                    self.cx.emit_window_event(window.entity, WindowEvent::ImePreedit(text, cursor));
+                    window.window().request_redraw();
+                }
+                winit::event::Ime::Commit(text) => {
+                    self.cx.emit_window_event(window.entity, WindowEvent::ImeCommit(text));
+                    window.window().request_redraw();
+                }
+                winit::event::Ime::Enabled | winit::event::Ime::Disabled => {}
+            },
             winit::event::WindowEvent::CursorMoved { device_id: _, position } => {
                 self.cx.emit_window_event(
                     window.entity,
@@ -458,16 +952,40 @@ impl ApplicationHandler<UserEvent> for Application {
                         WindowEvent::MouseScroll(x, y)
                     }
                     winit::event::MouseScrollDelta::PixelDelta(pos) => {
-                        WindowEvent::MouseScroll(
-                            pos.x as f32 / 20.0,
-                            pos.y as f32 / 20.0, // this number calibrated for wayland
-                        )
+                        WindowEvent::MouseScrollPixels(pos.x as f32, pos.y as f32)
                     }
                 };
 
                 self.cx.emit_window_event(window.entity, out_event);
                 window.window().request_redraw();
             }
+            winit::event::WindowEvent::PinchGesture { device_id: _, delta, phase: _ } => {
+                self.cx.emit_window_event(window.entity, WindowEvent::Zoom(delta as f32));
+                window.window().request_redraw();
+            }
+            winit::event::WindowEvent::RotationGesture { device_id: _, delta, phase: _ } => {
+                self.cx.emit_window_event(window.entity, WindowEvent::Rotate(delta));
+                window.window().request_redraw();
+            }
+            winit::event::WindowEvent::Touch(touch) => {
+                let phase = match touch.phase {
+                    winit::event::TouchPhase::Started => TouchPhase::Started,
+                    winit::event::TouchPhase::Moved => TouchPhase::Moved,
+                    winit::event::TouchPhase::Ended => TouchPhase::Ended,
+                    winit::event::TouchPhase::Cancelled => TouchPhase::Cancelled,
+                };
+
+                self.cx.emit_window_event(
+                    window.entity,
+                    WindowEvent::Touch {
+                        id: touch.id,
+                        phase,
+                        x: touch.location.x as f32,
+                        y: touch.location.y as f32,
+                    },
+                );
+                window.window().request_redraw();
+            }
             winit::event::WindowEvent::MouseInput { device_id: _, state, button } => {
                 let button = match button {
                     winit::event::MouseButton::Left => MouseButton::Left,
@@ -491,23 +1009,76 @@ impl ApplicationHandler<UserEvent> for Application {
                 scale_factor,
                 inner_size_writer: _,
             } => {
-                self.cx.set_scale_factor(scale_factor);
-                self.cx.needs_refresh(window.entity);
+                // The surface is recreated by the resize event which follows a scale factor change,
+                // once the window has its final physical size.
+                apply_scale_factor_change(
+                    &mut self.cx,
+                    window.entity,
+                    scale_factor,
+                    window.window().inner_size(),
+                );
+
+                // Winit doesn't report monitors being connected or disconnected, but the scale factor of a window
+                // changes when it is moved to another monitor, or a monitor is reconfigured.
+                self.cx.set_monitors(available_monitors(event_loop));
+                let primary_monitor = event_loop.primary_monitor();
+                let monitor = window.window().current_monitor().map(|monitor| {
+                    monitor_handle_to_monitor_info(&monitor, primary_monitor.as_ref())
+                });
+                self.cx.set_window_monitor(window.entity, monitor);
+
+                self.cx.emit_window_event(
+                    window.entity,
+                    WindowEvent::ScaleFactorChanged(scale_factor),
+                );
+                window.window().request_redraw();
             }
             winit::event::WindowEvent::ThemeChanged(theme) => {
-                let theme = match theme {
-                    winit::window::Theme::Light => ThemeMode::LightMode,
-                    winit::window::Theme::Dark => ThemeMode::DarkMode,
-                };
+                let theme = winit_theme_to_theme_mode(theme);
                 self.cx.emit_window_event(window.entity, WindowEvent::ThemeChanged(theme));
             }
-            winit::event::WindowEvent::Occluded(_) => {}
+            winit::event::WindowEvent::Occluded(occluded) => {
+                window.set_occluded(occluded);
+                self.cx.emit_window_event(window.entity, WindowEvent::Occluded(occluded));
+
+                // Some platforms report minimizing and restoring a window only as a change in its visibility.
+                update_minimized_and_maximized(&mut self.cx, window, window.window().inner_size());
+
+                if let Some(fullscreen) = window.update_fullscreen() {
+                    self.cx.emit_window_event(
+                        window.entity,
+                        WindowEvent::FullscreenChanged(fullscreen),
+                    );
+                }
+
+                // Catch up on any redraws skipped while the window was hidden.
+                if !occluded {
+                    self.cx.needs_refresh(window.entity);
+                    window.window().request_redraw();
+                }
+            }
             winit::event::WindowEvent::RedrawRequested => {
+                let mut frame_drawn = false;
                 for window in self.windows.values_mut() {
+                    // Drawing is skipped while the window is hidden, leaving its redraws pending until it's visible.
+                    if window.is_occluded() {
+                        continue;
+                    }
+
                     window.make_current();
                     //self.cx.needs_refresh(window.entity);
-                    if self.cx.draw(window.entity, &mut window.surface, &mut window.dirty_surface) {
-                        window.swap_buffers();
+                    if let Some(damage) =
+                        self.cx.draw(window.entity, &mut window.surface, &mut window.dirty_surface)
+                    {
+                        if self.cx.has_frame_capture_requests(window.entity) {
+                            if let Some(frame) = window.capture_frame() {
+                                self.cx.send_captured_frame(window.entity, frame);
+                            }
+                        }
+
+                        window.swap_buffers(damage);
+                        window.frame_drawn();
+                        frame_drawn = true;
                     }
 
                     // Un-cloak
@@ -517,6 +1088,10 @@ impl ApplicationHandler<UserEvent> for Application {
                         set_cloak(window.window(), false);
                     }
                 }
+
+                if frame_drawn {
+                    self.frame_callback();
+                }
             }
 
             _ => {}
@@ -533,12 +1108,27 @@ impl ApplicationHandler<UserEvent> for Application {
 
         while self.event_manager.flush_events(self.cx.context()) {}
 
+        for window in self.windows.values_mut() {
+            if let Some(window_state) = self.cx.0.windows.get(&window.entity) {
+                window.set_present_mode(window_state.window_description.present_mode);
+            }
+        }
+
         self.cx.process_style_updates();
 
-        if self.cx.process_animations() {
-            for window in self.windows.values() {
-                window.window().request_redraw();
+        // The earliest time at which a redraw deferred by a frame rate cap is due.
+        let mut next_frame_time: Option<Instant> = None;
+        let frame_rate_limit = self.frame_rate_limit;
+        let mut request_redraws = |windows: &mut HashMap<WindowId, WinState>| {
+            for window in windows.values_mut() {
+                if let Some(time) = window.request_throttled_redraw(frame_rate_limit) {
+                    next_frame_time = Some(next_frame_time.map_or(time, |t| t.min(time)));
+                }
             }
+        };
+
+        if self.cx.process_animations(Instant::now()) {
+            request_redraws(&mut self.windows);
         }
 
         self.cx.process_visual_updates();
@@ -561,15 +1151,20 @@ impl ApplicationHandler<UserEvent> for Application {
                 .expect("Failed to send event");
         }
 
-        if self.cx.0.windows.iter().any(|(_, window_state)| !window_state.redraw_list.is_empty()) {
-            for window in self.windows.values() {
-                window.window().request_redraw();
-            }
+        if self.cx.0.windows.iter().any(|(_, window_state)| !window_state.redraw_list.is_empty())
+            || self.windows.values().any(|window| window.has_pending_redraw())
+        {
+            request_redraws(&mut self.windows);
         }
 
         if self.control_flow != ControlFlow::Poll {
-            if let Some(timer_time) = self.cx.get_next_timer_time() {
-                event_loop.set_control_flow(ControlFlow::WaitUntil(timer_time));
+            let wake_time = match (self.cx.get_next_timer_time(), next_frame_time) {
+                (Some(t1), Some(t2)) => Some(t1.min(t2)),
+                (t1, t2) => t1.or(t2),
+            };
+
+            if let Some(wake_time) = wake_time {
+                event_loop.set_control_flow(ControlFlow::WaitUntil(wake_time));
             } else {
                 event_loop.set_control_flow(ControlFlow::Wait);
             }
@@ -611,6 +1206,15 @@ impl ApplicationHandler<UserEvent> for Application {
                         )
                         .expect("Failed to create window");
 
+                    if window_state.window_description.maximized {
+                        let size = window.inner_size();
+                        self.cx.set_window_size(
+                            *window_entity,
+                            size.width as f32,
+                            size.height as f32,
+                        );
+                    }
+
                     self.cx.mutate_window(*window_entity, |cx, win: &mut Window| {
                         win.window = Some(window.clone());
                         if let Some(callback) = &win.on_create {
@@ -622,6 +1226,8 @@ impl ApplicationHandler<UserEvent> for Application {
                     });
                 }
             }
+
+            self.update_monitors(event_loop);
         }
 
         if self.windows.is_empty() {
@@ -728,19 +1334,93 @@ impl WindowModifiers for Application {
         self
     }
 
+    fn alpha_mode(mut self, alpha_mode: AlphaMode) -> Self {
+        self.window_description.alpha_mode = alpha_mode;
+
+        self
+    }
+
+    fn opacity(mut self, opacity: f32) -> Self {
+        self.window_description.opacity = opacity.clamp(0.0, 1.0);
+
+        self
+    }
+
+    fn blur(mut self, flag: bool) -> Self {
+        self.window_description.blur = flag;
+
+        self
+    }
+
     fn decorations(mut self, flag: bool) -> Self {
         self.window_description.decorations = flag;
 
         self
     }
 
-    fn always_on_top(mut self, flag: bool) -> Self {
-        self.window_description.always_on_top = flag;
+    fn always_on_top(mut self, flag: impl Res<bool>) -> Self {
+        self.window_description = self.window_description.with_always_on_top(flag.get(&self.cx.0));
+
+        flag.set_or_bind(&mut self.cx.0, Entity::root(), |cx, flag| {
+            cx.emit(WindowEvent::SetAlwaysOnTop(flag.get(cx)));
+        });
+
+        self
+    }
+
+    fn skip_taskbar(mut self, flag: impl Res<bool>) -> Self {
+        self.window_description.skip_taskbar = flag.get(&self.cx.0);
+
+        flag.set_or_bind(&mut self.cx.0, Entity::root(), |cx, flag| {
+            cx.emit(WindowEvent::SetSkipTaskbar(flag.get(cx)));
+        });
+
+        self
+    }
+
+    fn window_level(mut self, window_level: impl Res<WindowLevel>) -> Self {
+        self.window_description =
+            self.window_description.with_window_level(window_level.get(&self.cx.0));
+
+        window_level.set_or_bind(&mut self.cx.0, Entity::root(), |cx, window_level| {
+            cx.emit(WindowEvent::SetWindowLevel(window_level.get(cx)));
+        });
+
+        self
+    }
+
+    fn fullscreen(mut self, mode: FullscreenMode) -> Self {
+        self.window_description = self.window_description.with_fullscreen(mode);
+
         self
     }
 
     fn vsync(mut self, flag: bool) -> Self {
-        self.window_description.vsync = flag;
+        self.window_description = self.window_description.with_vsync(flag);
+
+        self
+    }
+
+    fn msaa_samples(mut self, samples: u8) -> Self {
+        self.window_description = self.window_description.with_msaa_samples(samples);
+
+        self
+    }
+
+    fn max_fps(mut self, max_fps: Option<u32>) -> Self {
+        self.window_description = self.window_description.with_max_fps(max_fps);
+
+        self
+    }
+
+    fn present_mode(mut self, present_mode: PresentMode) -> Self {
+        self.window_description = self.window_description.with_present_mode(present_mode);
+
+        self
+    }
+
+    fn prewarm(mut self, flag: bool) -> Self {
+        self.window_description.prewarm = flag;
 
         self
     }
@@ -766,6 +1446,89 @@ impl WindowModifiers for Application {
 
         self
     }
+
+    fn app_id(mut self, app_id: impl Into<String>) -> Self {
+        self.window_description.app_id = Some(app_id.into());
+
+        self
+    }
+}
+
+// Updates whether a window is minimized or maximized. Not every platform reports whether a window is minimized, but
+// those which don't resize minimized windows to zero.
+fn update_minimized_and_maximized(
+    cx: &mut BackendContext,
+    window: &WinState,
+    size: PhysicalSize<u32>,
+) {
+    let minimized = window.window().is_minimized().unwrap_or(size.width == 0 || size.height == 0);
+    cx.set_window_minimized(window.entity, minimized);
+    cx.set_window_maximized(window.entity, window.window().is_maximized());
+}
+
+// Returns the monitors connected to the system.
+fn available_monitors(event_loop: &ActiveEventLoop) -> Vec<MonitorInfo> {
+    let primary_monitor = event_loop.primary_monitor();
+    event_loop
+        .available_monitors()
+        .map(|monitor| monitor_handle_to_monitor_info(&monitor, primary_monitor.as_ref()))
+        .collect()
+}
+
+// Sets the scale factor of the application after the scale factor of a window changes, recomputing the logical size
+// of the window from its current physical size in case no resize follows.
+fn apply_scale_factor_change(
+    cx: &mut BackendContext,
+    window_entity: Entity,
+    scale_factor: f64,
+    size: PhysicalSize<u32>,
+) {
+    cx.set_scale_factor(scale_factor);
+    cx.set_window_size(window_entity, size.width as f32, size.height as f32);
+    cx.needs_refresh(window_entity);
+}
+
+// Sets the size of a window and resizes its surface after the window is resized.
+fn apply_resize(
+    cx: &mut BackendContext,
+    window_entity: Entity,
+    surface: &mut impl ResizableSurface,
+    size: PhysicalSize<u32>,
+) {
+    cx.set_window_size(window_entity, size.width as f32, size.height as f32);
+    cx.needs_refresh(window_entity);
+    surface.resize(size);
+}
+
+// Clamps the physical inner size of a window to the minimum and maximum logical inner sizes of its description.
+// A zero size, such as that of a minimized window, is left as is.
+fn clamp_inner_size(
+    size: PhysicalSize<u32>,
+    description: &WindowDescription,
+    scale_factor: f64,
+) -> PhysicalSize<u32> {
+    if size.width == 0 || size.height == 0 {
+        return size;
+    }
+
+    let to_physical = |size: WindowSize| {
+        LogicalSize::new(size.width, size.height).to_physical::<u32>(scale_factor)
+    };
+
+    let mut width = size.width;
+    let mut height = size.height;
+
+    if let Some(min_size) = description.min_inner_size.map(to_physical) {
+        width = width.max(min_size.width);
+        height = height.max(min_size.height);
+    }
+
+    if let Some(max_size) = description.max_inner_size.map(to_physical) {
+        width = width.min(max_size.width);
+        height = height.min(max_size.height);
+    }
+
+    PhysicalSize::new(width, height)
 }
 
 fn apply_window_description(description: &WindowDescription) -> WindowAttributes {
@@ -790,26 +1553,68 @@ fn apply_window_description(description: &WindowDescription) -> WindowAttributes
             window_attributes.with_position(LogicalPosition::new(position.x, position.y));
     }
 
+    #[cfg(target_os = "windows")]
+    {
+        window_attributes = winit::platform::windows::WindowAttributesExtWindows::with_skip_taskbar(
+            window_attributes,
+            description.skip_taskbar,
+        );
+    }
+
+    // X11 has no taskbar hint, but taskbars conventionally omit utility windows.
+    #[cfg(all(
+        feature = "x11",
+        unix,
+        not(any(target_os = "macos", target_os = "ios", target_os = "android"))
+    ))]
+    if description.skip_taskbar {
+        window_attributes = winit::platform::x11::WindowAttributesExtX11::with_x11_window_type(
+            window_attributes,
+            vec![winit::platform::x11::WindowType::Utility],
+        );
+    }
+
+    #[cfg(all(
+        feature = "x11",
+        unix,
+        not(any(target_os = "macos", target_os = "ios", target_os = "android"))
+    ))]
+    if let Some(app_id) = &description.app_id {
+        window_attributes = winit::platform::x11::WindowAttributesExtX11::with_name(
+            window_attributes,
+            app_id,
+            app_id,
+        );
+    }
+
+    #[cfg(all(
+        feature = "wayland",
+        unix,
+        not(any(target_os = "macos", target_os = "ios", target_os = "android"))
+    ))]
+    if let Some(app_id) = &description.app_id {
+        window_attributes = winit::platform::wayland::WindowAttributesExtWayland::with_name(
+            window_attributes,
+            app_id,
+            app_id,
+        );
+    }
+
     window_attributes
         .with_resizable(description.resizable)
         .with_maximized(description.maximized)
+        .with_visible(description.visible)
         // Accesskit requires that the window start invisible until accesskit is initialized.
         //.with_visible(false)
-        .with_window_level(if description.always_on_top {
-            WindowLevel::AlwaysOnTop
-        } else {
-            WindowLevel::Normal
-        })
+        .with_window_level(window_level_to_window_level(description.resolved_window_level()))
         .with_transparent(description.transparent)
+        .with_blur(description.blur)
         .with_decorations(description.decorations)
-        .with_window_icon(description.icon.as_ref().map(|icon| {
-            winit::window::Icon::from_rgba(
-                icon.clone(),
-                description.icon_width,
-                description.icon_height,
-            )
-            .unwrap()
-        }))
+        .with_window_icon(
+            description.icon.as_ref().and_then(|icon| {
+                icon_to_icon(description.icon_width, description.icon_height, icon)
+            }),
+        )
         .with_enabled_buttons(
             winit::window::WindowButtons::from_bits(description.enabled_window_buttons.bits())
                 .unwrap(),
@@ -845,6 +1650,26 @@ fn set_cloak(window: &winit::window::Window, state: bool) -> bool {
     result == 0 // success
 }
 
+/// Sets the explicit AppUserModelID of the process, used by the taskbar for grouping windows. Returns the failing
+/// HRESULT on error.
+///
+/// <https://learn.microsoft.com/en-us/windows/win32/shell/appids>
+///
+#[cfg(target_os = "windows")]
+fn set_app_user_model_id(app_id: &str) -> Result<(), windows_sys::core::HRESULT> {
+    use windows_sys::Win32::UI::Shell::SetCurrentProcessExplicitAppUserModelID;
+
+    let app_id = app_id.encode_utf16().chain(std::iter::once(0)).collect::<Vec<u16>>();
+
+    let result = unsafe { SetCurrentProcessExplicitAppUserModelID(app_id.as_ptr()) };
+
+    if result == 0 {
+        Ok(())
+    } else {
+        Err(result)
+    }
+}
+
 #[allow(unused_variables)]
 pub fn load_default_cursors(event_loop: &ActiveEventLoop) -> HashMap<CursorIcon, CustomCursor> {
     #[allow(unused_mut)]
@@ -926,3 +1751,258 @@ pub fn load_default_cursors(event_loop: &ActiveEventLoop) -> HashMap<CursorIcon,
 
     custom_cursors
 }
+
+/// Updates the reduced motion and high contrast preferences of the application from the system settings, where
+/// they can be queried.
+// Queries the accessibility preferences of the system on a background thread, as reading them can involve running
+// a command, and sends them back to the event loop. Nothing is queried while a previous query hasn't finished.
+fn query_accessibility_preferences(proxy: &EventLoopProxy<UserEvent>, pending: &mut bool) {
+    if *pending {
+        return;
+    }
+
+    *pending = true;
+    let proxy = proxy.clone();
+    std::thread::spawn(move || {
+        let _ = proxy.send_event(UserEvent::AccessibilityPreferences {
+            reduced_motion: system_reduced_motion(),
+            high_contrast: system_high_contrast(),
+        });
+    });
+}
+
+/// Returns whether client area animations are disabled in the system settings.
+///
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-systemparametersinfow>
+///
+#[cfg(target_os = "windows")]
+fn system_reduced_motion() -> Option<bool> {
+    use windows_sys::Win32::{
+        Foundation::BOOL,
+        UI::WindowsAndMessaging::{SystemParametersInfoW, SPI_GETCLIENTAREAANIMATION},
+    };
+
+    let mut enabled: BOOL = 1;
+
+    let result = unsafe {
+        SystemParametersInfoW(
+            SPI_GETCLIENTAREAANIMATION,
+            0,
+            std::ptr::from_mut(&mut enabled).cast(),
+            0,
+        )
+    };
+
+    (result != 0).then_some(enabled == 0)
+}
+
+/// Returns whether a high contrast theme is enabled in the system settings.
+///
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-highcontrastw>
+///
+#[cfg(target_os = "windows")]
+fn system_high_contrast() -> Option<bool> {
+    use windows_sys::Win32::UI::{
+        Accessibility::{HCF_HIGHCONTRASTON, HIGHCONTRASTW},
+        WindowsAndMessaging::{SystemParametersInfoW, SPI_GETHIGHCONTRAST},
+    };
+
+    let mut high_contrast = HIGHCONTRASTW {
+        cbSize: std::mem::size_of::<HIGHCONTRASTW>() as u32,
+        dwFlags: 0,
+        lpszDefaultScheme: std::ptr::null_mut(),
+    };
+
+    let result = unsafe {
+        SystemParametersInfoW(
+            SPI_GETHIGHCONTRAST,
+            high_contrast.cbSize,
+            std::ptr::from_mut(&mut high_contrast).cast(),
+            0,
+        )
+    };
+
+    (result != 0).then_some(high_contrast.dwFlags & HCF_HIGHCONTRASTON != 0)
+}
+
+/// Returns whether reduced motion is enabled in the accessibility settings.
+#[cfg(target_os = "macos")]
+fn system_reduced_motion() -> Option<bool> {
+    read_defaults_flag("com.apple.universalaccess", "reduceMotion")
+}
+
+/// Returns whether increased contrast is enabled in the accessibility settings.
+#[cfg(target_os = "macos")]
+fn system_high_contrast() -> Option<bool> {
+    read_defaults_flag("com.apple.universalaccess", "increaseContrast")
+}
+
+/// Reads a boolean from the user defaults system.
+#[cfg(target_os = "macos")]
+fn read_defaults_flag(domain: &str, key: &str) -> Option<bool> {
+    let output =
+        std::process::Command::new("defaults").args(["read", domain, key]).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    match String::from_utf8_lossy(&output.stdout).trim() {
+        "1" => Some(true),
+        "0" => Some(false),
+        _ => None,
+    }
+}
+
+/// Returns whether animations are disabled in the GNOME desktop settings.
+#[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
+fn system_reduced_motion() -> Option<bool> {
+    read_gsettings_flag("org.gnome.desktop.interface", "enable-animations").map(|enabled| !enabled)
+}
+
+/// Returns whether the high contrast theme is enabled in the GNOME desktop settings.
+#[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
+fn system_high_contrast() -> Option<bool> {
+    read_gsettings_flag("org.gnome.desktop.a11y.interface", "high-contrast")
+}
+
+/// Reads a boolean from GSettings, returning `None` if the key or the `gsettings` tool isn't available.
+#[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
+fn read_gsettings_flag(schema: &str, key: &str) -> Option<bool> {
+    let output =
+        std::process::Command::new("gsettings").args(["get", schema, key]).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    match String::from_utf8_lossy(&output.stdout).trim() {
+        "true" => Some(true),
+        "false" => Some(false),
+        _ => None,
+    }
+}
+
+#[cfg(not(any(
+    target_os = "windows",
+    target_os = "macos",
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+)))]
+fn system_reduced_motion() -> Option<bool> {
+    None
+}
+
+#[cfg(not(any(
+    target_os = "windows",
+    target_os = "macos",
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+)))]
+fn system_high_contrast() -> Option<bool> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A surface which counts how often it is recreated, standing in for the GL surface of a window.
+    struct TestSurface {
+        size: (i32, i32),
+        recreated: usize,
+    }
+
+    impl ResizableSurface for TestSurface {
+        fn surface_size(&self) -> (i32, i32) {
+            self.size
+        }
+
+        fn recreate_surface(&mut self, size: PhysicalSize<u32>) {
+            self.size = (size.width as i32, size.height as i32);
+            self.recreated += 1;
+        }
+    }
+
+    fn test_window(surface_size: (i32, i32)) -> (BackendContext, TestSurface) {
+        let window_description = WindowDescription::new().with_inner_size(200, 100);
+
+        let mut cx = BackendContext::new(Context::default());
+        cx.add_main_window(Entity::root(), &window_description, 1.0);
+        cx.0.windows
+            .insert(Entity::root(), WindowState { window_description, ..Default::default() });
+
+        (cx, TestSurface { size: surface_size, recreated: 0 })
+    }
+
+    #[test]
+    fn scale_factor_change_recreates_surface_once_at_final_size() {
+        let (mut cx, mut surface) = test_window((200, 100));
+        let child = Element::new(cx.context()).size(Stretch(1.0)).entity();
+
+        // Moving to a 2x monitor first reports the new scale factor while the window still has its old physical
+        // size, which halves its logical size without touching the surface.
+        apply_scale_factor_change(&mut cx, Entity::root(), 2.0, PhysicalSize::new(200, 100));
+        assert_eq!(cx.style().width.get(Entity::root()), Some(&Pixels(100.0)));
+        assert_eq!(cx.style().height.get(Entity::root()), Some(&Pixels(50.0)));
+        assert_eq!(surface.recreated, 0);
+
+        // The resize which follows brings the window to its final physical size. Some platforms report the same
+        // size again, which keeps the surface.
+        apply_resize(&mut cx, Entity::root(), &mut surface, PhysicalSize::new(400, 200));
+        apply_resize(&mut cx, Entity::root(), &mut surface, PhysicalSize::new(400, 200));
+        assert_eq!(surface.recreated, 1);
+        assert_eq!(surface.size, (400, 200));
+
+        cx.process_style_updates();
+        cx.process_visual_updates();
+        assert_eq!(cx.style().width.get(Entity::root()), Some(&Pixels(200.0)));
+        assert_eq!(cx.style().height.get(Entity::root()), Some(&Pixels(100.0)));
+        assert_eq!(cx.cache().get_bounds(child), BoundingBox::from_min_max(0.0, 0.0, 400.0, 200.0));
+    }
+
+    #[test]
+    fn minimized_window_keeps_surface() {
+        let (mut cx, mut surface) = test_window((200, 100));
+
+        apply_resize(&mut cx, Entity::root(), &mut surface, PhysicalSize::new(0, 0));
+        assert_eq!(surface.recreated, 0);
+        assert_eq!(surface.size, (200, 100));
+    }
+
+    #[test]
+    fn inner_size_is_clamped_to_limits() {
+        let window_description =
+            WindowDescription::new().with_min_inner_size(100, 50).with_max_inner_size(400, 300);
+
+        let clamp = |width, height| {
+            clamp_inner_size(PhysicalSize::new(width, height), &window_description, 2.0)
+        };
+        assert_eq!(clamp(100, 50), PhysicalSize::new(200, 100));
+        assert_eq!(clamp(1000, 1000), PhysicalSize::new(800, 600));
+        assert_eq!(clamp(300, 300), PhysicalSize::new(300, 300));
+        assert_eq!(clamp(0, 0), PhysicalSize::new(0, 0));
+    }
+}
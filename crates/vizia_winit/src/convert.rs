@@ -1,9 +1,22 @@
+use skia_safe::{AlphaType, CachingHint, ColorType, Data, Image, ImageInfo};
+use vizia_core::prelude::CursorGrabMode as ViziaCursorGrabMode;
 use vizia_core::prelude::CursorIcon as ViziaCursorIcon;
+use vizia_core::prelude::ResizeDirection as ViziaResizeDirection;
+use vizia_core::prelude::UrgencyLevel as ViziaUrgencyLevel;
+use vizia_core::prelude::WindowLevel as ViziaWindowLevel;
+use vizia_core::prelude::{FullscreenMode, MonitorInfo, ThemeMode, WindowSize, WorkArea};
 use vizia_input::Code as ViziaCode;
 use vizia_input::Key as ViziaKey;
 use winit::keyboard::KeyCode;
 use winit::keyboard::NamedKey;
+use winit::monitor::MonitorHandle;
+use winit::window::CursorGrabMode as WinitCursorGrabMode;
 use winit::window::CursorIcon as WinitCursorIcon;
+use winit::window::ResizeDirection as WinitResizeDirection;
+use winit::window::Theme as WinitTheme;
+use winit::window::UserAttentionType as WinitUserAttentionType;
+use winit::window::WindowLevel as WinitWindowLevel;
+use winit::window::{Fullscreen, Window};
 
 pub fn cursor_icon_to_cursor_icon(cursor_icon: ViziaCursorIcon) -> Option<WinitCursorIcon> {
     match cursor_icon {
@@ -46,6 +59,118 @@ pub fn cursor_icon_to_cursor_icon(cursor_icon: ViziaCursorIcon) -> Option<WinitC
     }
 }
 
+pub fn cursor_grab_mode_to_cursor_grab_mode(
+    cursor_grab_mode: ViziaCursorGrabMode,
+) -> WinitCursorGrabMode {
+    match cursor_grab_mode {
+        ViziaCursorGrabMode::None => WinitCursorGrabMode::None,
+        ViziaCursorGrabMode::Confined => WinitCursorGrabMode::Confined,
+        ViziaCursorGrabMode::Locked => WinitCursorGrabMode::Locked,
+    }
+}
+
+pub fn window_level_to_window_level(window_level: ViziaWindowLevel) -> WinitWindowLevel {
+    match window_level {
+        ViziaWindowLevel::AlwaysOnBottom => WinitWindowLevel::AlwaysOnBottom,
+        ViziaWindowLevel::Normal => WinitWindowLevel::Normal,
+        ViziaWindowLevel::AlwaysOnTop => WinitWindowLevel::AlwaysOnTop,
+    }
+}
+
+/// Converts a fullscreen mode to the winit fullscreen of the monitor the window is on.
+///
+/// Exclusive fullscreen uses the video mode of the monitor which most closely matches the requested one, preferring
+/// a matching resolution, then refresh rate, then bit depth.
+pub fn fullscreen_mode_to_fullscreen(window: &Window, mode: FullscreenMode) -> Option<Fullscreen> {
+    match mode {
+        FullscreenMode::Windowed => None,
+        FullscreenMode::Borderless => Some(Fullscreen::Borderless(window.current_monitor())),
+        FullscreenMode::Exclusive(video_mode) => window
+            .current_monitor()?
+            .video_modes()
+            .min_by_key(|mode| {
+                let size = mode.size();
+                (
+                    size.width.abs_diff(video_mode.size.width)
+                        + size.height.abs_diff(video_mode.size.height),
+                    mode.refresh_rate_millihertz().abs_diff(video_mode.refresh_rate_millihertz),
+                    mode.bit_depth().abs_diff(video_mode.bit_depth),
+                )
+            })
+            .map(Fullscreen::Exclusive),
+    }
+}
+
+pub fn resize_direction_to_resize_direction(
+    resize_direction: ViziaResizeDirection,
+) -> WinitResizeDirection {
+    match resize_direction {
+        ViziaResizeDirection::East => WinitResizeDirection::East,
+        ViziaResizeDirection::North => WinitResizeDirection::North,
+        ViziaResizeDirection::NorthEast => WinitResizeDirection::NorthEast,
+        ViziaResizeDirection::NorthWest => WinitResizeDirection::NorthWest,
+        ViziaResizeDirection::South => WinitResizeDirection::South,
+        ViziaResizeDirection::SouthEast => WinitResizeDirection::SouthEast,
+        ViziaResizeDirection::SouthWest => WinitResizeDirection::SouthWest,
+        ViziaResizeDirection::West => WinitResizeDirection::West,
+    }
+}
+
+pub fn urgency_level_to_user_attention_type(
+    urgency_level: ViziaUrgencyLevel,
+) -> WinitUserAttentionType {
+    match urgency_level {
+        ViziaUrgencyLevel::Critical => WinitUserAttentionType::Critical,
+        ViziaUrgencyLevel::Informational => WinitUserAttentionType::Informational,
+    }
+}
+
+/// Converts a winit monitor handle to the monitor information exposed to views.
+pub fn monitor_handle_to_monitor_info(
+    monitor: &MonitorHandle,
+    primary_monitor: Option<&MonitorHandle>,
+) -> MonitorInfo {
+    let position = monitor.position();
+    let size = monitor.size();
+
+    MonitorInfo {
+        name: monitor.name(),
+        x: position.x,
+        y: position.y,
+        size: WindowSize::new(size.width, size.height),
+        work_area: monitor_work_area(monitor),
+        refresh_rate_millihertz: monitor.refresh_rate_millihertz(),
+        scale_factor: monitor.scale_factor(),
+        is_primary: primary_monitor == Some(monitor),
+    }
+}
+
+// Returns the area of a monitor which isn't covered by the taskbar.
+#[cfg(target_os = "windows")]
+fn monitor_work_area(monitor: &MonitorHandle) -> Option<WorkArea> {
+    use windows_sys::Win32::Graphics::Gdi::{GetMonitorInfoW, MONITORINFO};
+    use winit::platform::windows::MonitorHandleExtWindows;
+
+    let mut info: MONITORINFO = unsafe { std::mem::zeroed() };
+    info.cbSize = std::mem::size_of::<MONITORINFO>() as u32;
+    if unsafe { GetMonitorInfoW(monitor.hmonitor() as _, &mut info) } == 0 {
+        return None;
+    }
+
+    let rect = info.rcWork;
+    Some(WorkArea {
+        x: rect.left,
+        y: rect.top,
+        size: WindowSize::new((rect.right - rect.left) as u32, (rect.bottom - rect.top) as u32),
+    })
+}
+
+// Winit doesn't report the work area of a monitor on other platforms.
+#[cfg(not(target_os = "windows"))]
+fn monitor_work_area(_monitor: &MonitorHandle) -> Option<WorkArea> {
+    None
+}
+
 pub fn winit_key_code_to_code(virtual_key_code: KeyCode) -> ViziaCode {
     use winit::keyboard::KeyCode::*;
     match virtual_key_code {
@@ -600,3 +725,24 @@ pub fn winit_key_to_key(virtual_key_code: NamedKey) -> Option<ViziaKey> {
         _ => None,
     }
 }
+
+pub fn winit_theme_to_theme_mode(theme: WinitTheme) -> ThemeMode {
+    match theme {
+        WinitTheme::Light => ThemeMode::LightMode,
+        WinitTheme::Dark => ThemeMode::DarkMode,
+    }
+}
+
+pub fn icon_to_icon(width: u32, height: u32, image: &[u8]) -> Option<winit::window::Icon> {
+    winit::window::Icon::from_rgba(image.to_vec(), width, height).ok()
+}
+
+/// Decodes an encoded image, such as a PNG file, into the width, height and RGBA pixels of a window icon.
+pub fn decode_icon(data: &[u8]) -> Option<(u32, u32, Vec<u8>)> {
+    let image = Image::from_encoded(Data::new_copy(data))?;
+    let info = ImageInfo::new(image.dimensions(), ColorType::RGBA8888, AlphaType::Unpremul, None);
+    let mut pixels = vec![0u8; info.compute_min_byte_size()];
+    image
+        .read_pixels(&info, &mut pixels, info.min_row_bytes(), (0, 0), CachingHint::Disallow)
+        .then(|| (image.width() as u32, image.height() as u32, pixels))
+}
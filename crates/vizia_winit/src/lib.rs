@@ -3,16 +3,40 @@ mod convert;
 pub mod window;
 pub mod window_modifiers;
 
+/// Re-export of winit, for matching on the raw events passed to [`Application::on_platform_event`](application::Application::on_platform_event).
+pub use winit;
+
 pub trait ModifyWindow {
     fn modify_window<T>(&mut self, f: impl FnOnce(&winit::window::Window) -> T) -> Option<T>;
 }
 
+/// Extension trait for opening additional windows from within a running application.
+pub trait SpawnWindow {
+    /// Opens a new OS window with the properties of the given window description, containing the views built by
+    /// `content`. See [`Window::with_description`] for details.
+    fn spawn_window(
+        &mut self,
+        window_description: WindowDescription,
+        content: impl Fn(&mut Context),
+    ) -> Handle<Window>;
+}
+
 use vizia_core::{
     context::TreeProps,
-    prelude::{Entity, EventContext, GenerationalId},
+    prelude::{Context, Entity, EventContext, GenerationalId, Handle, WindowDescription},
 };
 use window::Window;
 
+impl SpawnWindow for Context {
+    fn spawn_window(
+        &mut self,
+        window_description: WindowDescription,
+        content: impl Fn(&mut Context),
+    ) -> Handle<Window> {
+        Window::with_description(self, window_description, content)
+    }
+}
+
 impl<'a> ModifyWindow for EventContext<'a> {
     fn modify_window<T>(&mut self, f: impl FnOnce(&winit::window::Window) -> T) -> Option<T> {
         self.with_current(self.parent_window().unwrap_or(Entity::root()), move |cx| {
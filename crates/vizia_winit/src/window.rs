@@ -1,3 +1,4 @@
+use crate::application::FrameRateLimit;
 use crate::window_modifiers::WindowModifiers;
 use glutin::context::GlProfile;
 use vizia_core::context::TreeProps;
@@ -10,7 +11,11 @@ use std::num::NonZeroU32;
 use std::{ffi::CString, sync::Arc};
 use winit::raw_window_handle::HasWindowHandle;
 
-use crate::convert::cursor_icon_to_cursor_icon;
+use crate::convert::{
+    cursor_grab_mode_to_cursor_grab_mode, cursor_icon_to_cursor_icon,
+    fullscreen_mode_to_fullscreen, icon_to_icon, resize_direction_to_resize_direction,
+    urgency_level_to_user_attention_type, window_level_to_window_level,
+};
 
 use gl_rs as gl;
 use glutin::config::Config;
@@ -23,7 +28,7 @@ use glutin::{
     context::{ContextApi, ContextAttributesBuilder},
     display::GetGlDisplay,
     prelude::*,
-    surface::{SurfaceAttributesBuilder, WindowSurface},
+    surface::{SurfaceAttributesBuilder, SwapInterval, WindowSurface},
 };
 
 use skia_safe::{
@@ -31,12 +36,14 @@ use skia_safe::{
         self, backend_render_targets, context_options, gl::FramebufferInfo, ContextOptions,
         SurfaceOrigin,
     },
-    ColorSpace, ColorType, PixelGeometry, Surface, SurfaceProps, SurfacePropsFlags,
+    AlphaType, ColorSpace, ColorType, ImageInfo, PixelGeometry, Surface, SurfaceProps,
+    SurfacePropsFlags,
 };
 
+use log::warn;
 use vizia_core::prelude::*;
 use winit::event_loop::ActiveEventLoop;
-use winit::window::{CursorGrabMode, CursorIcon, CustomCursor, WindowLevel};
+use winit::window::{CursorIcon, CustomCursor};
 use winit::{dpi::*, window::WindowId};
 
 pub struct WinState {
@@ -52,6 +59,15 @@ pub struct WinState {
     pub should_close: bool,
     #[cfg(target_os = "windows")]
     pub is_initially_cloaked: bool,
+    supports_swap_damage: bool,
+    present_mode: PresentMode,
+    max_fps: Option<u32>,
+    // The refresh rate of the monitor the window is on, in millihertz, cached as querying the monitor can be slow.
+    refresh_rate: Option<u32>,
+    last_frame_time: Option<Instant>,
+    redraw_pending: bool,
+    occluded: bool,
+    fullscreen: bool,
 }
 
 impl Drop for WinState {
@@ -65,27 +81,37 @@ impl WinState {
         event_loop: &ActiveEventLoop,
         window: Arc<winit::window::Window>,
         entity: Entity,
+        window_description: &WindowDescription,
     ) -> Result<Self, Box<dyn Error>> {
         window.set_ime_allowed(true);
-        window.set_visible(true);
 
-        let template = ConfigTemplateBuilder::new().with_alpha_size(8).with_transparency(true);
+        let transparent = window_description.transparent;
+        let msaa_samples = window_description.msaa_samples;
+
+        let mut template =
+            ConfigTemplateBuilder::new().with_alpha_size(8).with_transparency(transparent);
+        if let Some(samples) = msaa_samples.filter(|samples| *samples > 1) {
+            template = template.with_multisampling(samples);
+        }
         let display_builder = DisplayBuilder::new();
 
         let (_, gl_config) = display_builder
             .build(event_loop, template, |configs| {
-                // Find the config with the maximum number of samples, so our triangle will
-                // be smooth.
+                // Prefer a config which supports transparency if it was requested. Then, if a number of samples
+                // was requested, prefer exactly that many, then the most samples below it, then the fewest above
+                // it. Otherwise prefer the fewest samples.
                 configs
-                    .reduce(|accum, config| {
-                        let transparency_check = config.supports_transparency().unwrap_or(false)
-                            & !accum.supports_transparency().unwrap_or(false);
-
-                        if transparency_check || config.num_samples() < accum.num_samples() {
-                            config
-                        } else {
-                            accum
-                        }
+                    .max_by_key(|config| {
+                        let supports_transparency =
+                            !transparent || config.supports_transparency().unwrap_or(false);
+
+                        let samples = i32::from(config.num_samples());
+                        let samples_score = match msaa_samples {
+                            Some(requested) if samples <= i32::from(requested) => samples,
+                            _ => -samples,
+                        };
+
+                        (supports_transparency, samples_score)
                     })
                     .unwrap()
             })
@@ -126,11 +152,7 @@ impl WinState {
 
         let gl_context = not_current_gl_context.make_current(&gl_surface).unwrap();
 
-        // if window_description.vsync {
-        //     gl_surface
-        //         .set_swap_interval(&gl_context, SwapInterval::Wait(NonZeroU32::new(1).unwrap()))
-        //         .expect("Failed to set vsync");
-        // }
+        set_swap_interval(&gl_surface, &gl_context, window_description.present_mode);
 
         // Build skia renderer
         gl::load_with(|s| {
@@ -166,8 +188,13 @@ impl WinState {
         let num_samples = gl_config.num_samples() as usize;
         let stencil_size = gl_config.stencil_size() as usize;
 
-        let mut surface =
-            create_surface(&window, fb_info, &mut gr_context, num_samples, stencil_size);
+        let mut surface = create_surface(
+            window.inner_size(),
+            fb_info,
+            &mut gr_context,
+            num_samples,
+            stencil_size,
+        );
 
         let inner_size = window.inner_size();
 
@@ -175,6 +202,10 @@ impl WinState {
             .new_surface_with_dimensions((inner_size.width as i32, inner_size.height as i32))
             .unwrap();
 
+        let fullscreen = window.fullscreen().is_some();
+        let refresh_rate =
+            window.current_monitor().and_then(|monitor| monitor.refresh_rate_millihertz());
+
         // Build our window
         Ok(WinState {
             entity,
@@ -189,6 +220,14 @@ impl WinState {
             should_close: false,
             #[cfg(target_os = "windows")]
             is_initially_cloaked: true,
+            supports_swap_damage: true,
+            present_mode: window_description.present_mode,
+            max_fps: window_description.max_fps,
+            refresh_rate,
+            last_frame_time: None,
+            redraw_pending: false,
+            occluded: false,
+            fullscreen,
         })
     }
 
@@ -201,14 +240,266 @@ impl WinState {
         self.gl_context.make_current(&self.gl_surface).unwrap();
     }
 
-    pub fn resize(&mut self, size: PhysicalSize<u32>) {
+    /// Requests a redraw of the window, respecting the maximum frame rate of the window and the frame rate limit
+    /// of the application.
+    ///
+    /// If the next frame is not yet due the redraw is deferred, and the time at which it is due is returned.
+    pub fn request_throttled_redraw(
+        &mut self,
+        frame_rate_limit: FrameRateLimit,
+    ) -> Option<Instant> {
+        // Redraws are resumed once the window is no longer occluded.
+        if self.is_occluded() {
+            return None;
+        }
+
+        if let (Some(frame_budget), Some(last_frame_time)) =
+            (self.frame_budget(frame_rate_limit), self.last_frame_time)
+        {
+            let next_frame_time = last_frame_time + frame_budget;
+            if next_frame_time > Instant::now() {
+                self.redraw_pending = true;
+                return Some(next_frame_time);
+            }
+        }
+
+        self.redraw_pending = false;
+        self.window.request_redraw();
+        None
+    }
+
+    // Returns the minimum time between frames, from the lower of the maximum frame rate of the window and the frame
+    // rate limit of the application.
+    fn frame_budget(&self, frame_rate_limit: FrameRateLimit) -> Option<Duration> {
+        let limit = match frame_rate_limit {
+            FrameRateLimit::Unlimited => None,
+            FrameRateLimit::Fps(fps) => Some(fps as f64),
+            FrameRateLimit::RefreshRate => {
+                Some(self.refresh_rate.map_or(60.0, |millihertz| millihertz as f64 / 1000.0))
+            }
+        };
+
+        let fps = [limit, self.max_fps.map(|fps| fps as f64)]
+            .into_iter()
+            .flatten()
+            .filter(|fps| *fps > 0.0)
+            .min_by(f64::total_cmp)?;
+
+        Some(Duration::from_secs_f64(1.0 / fps))
+    }
+
+    /// Sets the refresh rate of the monitor the window is on, in millihertz, used by
+    /// [`FrameRateLimit::RefreshRate`].
+    pub fn set_refresh_rate(&mut self, refresh_rate: Option<u32>) {
+        self.refresh_rate = refresh_rate;
+    }
+
+    /// Returns true if a redraw was deferred by [`WinState::request_throttled_redraw`] and has not yet been requested.
+    pub fn has_pending_redraw(&self) -> bool {
+        self.redraw_pending
+    }
+
+    /// Sets whether the window is fully hidden from view, such as by being minimized or covered by other windows.
+    pub fn set_occluded(&mut self, flag: bool) {
+        self.occluded = flag;
+    }
+
+    /// Returns true if the window is fully hidden from view, in which case it is not redrawn.
+    pub fn is_occluded(&self) -> bool {
+        // A window cloaked during creation may be reported as occluded, but must still draw its first frame
+        // before it can be revealed.
+        #[cfg(target_os = "windows")]
+        if self.is_initially_cloaked {
+            return false;
+        }
+
+        self.occluded
+    }
+
+    /// Returns true if the window was fullscreen when last checked by [`WinState::update_fullscreen`].
+    pub fn is_fullscreen(&self) -> bool {
+        self.fullscreen
+    }
+
+    /// Checks whether the window has entered or left fullscreen, returning the new state if it has changed.
+    ///
+    /// Some platforms, such as macOS, change the fullscreen state asynchronously, so this is checked whenever
+    /// the window is resized or its visibility changes rather than immediately after requesting the change.
+    pub fn update_fullscreen(&mut self) -> Option<bool> {
+        let fullscreen = self.window.fullscreen().is_some();
+        if fullscreen == self.fullscreen {
+            return None;
+        }
+
+        self.fullscreen = fullscreen;
+        Some(fullscreen)
+    }
+
+    /// Records that a frame was drawn, which starts the frame budget for the next throttled redraw.
+    pub fn frame_drawn(&mut self) {
+        self.last_frame_time = Some(Instant::now());
+        self.redraw_pending = false;
+    }
+
+    /// Changes how rendered frames are presented to the display, without recreating the surface.
+    pub fn set_present_mode(&mut self, present_mode: PresentMode) {
+        if self.present_mode == present_mode {
+            return;
+        }
+
+        self.present_mode = present_mode;
         self.gl_context.make_current(&self.gl_surface).unwrap();
-        let (width, height): (u32, u32) = size.into();
+        set_swap_interval(&self.gl_surface, &self.gl_context, present_mode);
+    }
 
-        if width == 0 || height == 0 {
+    /// Renders a throwaway frame containing common draw primitives into an off-screen surface, forcing
+    /// skia to compile the corresponding GPU pipelines before the first real frame.
+    pub fn prewarm(&mut self) {
+        self.gl_context.make_current(&self.gl_surface).unwrap();
+
+        let Some(mut surface) = self.surface.new_surface_with_dimensions((256, 256)) else {
             return;
+        };
+
+        let canvas = surface.canvas();
+        canvas.clear(skia_safe::Color::TRANSPARENT);
+
+        let mut paint = skia_safe::Paint::default();
+        paint.set_anti_alias(true);
+        paint.set_color(skia_safe::Color::from_argb(200, 100, 150, 200));
+
+        // Rects, rounded rects and borders.
+        let rect = skia_safe::Rect::from_xywh(8.0, 8.0, 96.0, 48.0);
+        canvas.draw_rect(rect, &paint);
+        canvas.draw_rrect(
+            skia_safe::RRect::new_rect_xy(rect.with_offset((0.0, 56.0)), 8.0, 8.0),
+            &paint,
+        );
+        paint.set_style(skia_safe::PaintStyle::Stroke);
+        paint.set_stroke_width(2.0);
+        canvas.draw_rrect(
+            skia_safe::RRect::new_rect_xy(rect.with_offset((0.0, 112.0)), 8.0, 8.0),
+            &paint,
+        );
+        canvas.draw_circle((160.0, 32.0), 24.0, &paint);
+        paint.set_style(skia_safe::PaintStyle::Fill);
+
+        // Shadows.
+        paint.set_mask_filter(skia_safe::MaskFilter::blur(
+            skia_safe::BlurStyle::Normal,
+            4.0,
+            false,
+        ));
+        canvas.draw_rrect(
+            skia_safe::RRect::new_rect_xy(rect.with_offset((112.0, 56.0)), 8.0, 8.0),
+            &paint,
+        );
+        paint.set_mask_filter(None);
+
+        // Gradients.
+        paint.set_shader(skia_safe::Shader::linear_gradient(
+            ((0.0, 0.0), (256.0, 0.0)),
+            skia_safe::gradient_shader::GradientShaderColors::Colors(&[
+                skia_safe::Color::RED,
+                skia_safe::Color::BLUE,
+            ]),
+            None,
+            skia_safe::TileMode::Clamp,
+            None,
+            None,
+        ));
+        canvas.draw_rect(rect.with_offset((112.0, 112.0)), &paint);
+        paint.set_shader(None);
+
+        // Text.
+        if let Some(typeface) =
+            skia_safe::FontMgr::new().legacy_make_typeface(None, skia_safe::FontStyle::default())
+        {
+            let font = skia_safe::Font::from_typeface(typeface, 14.0);
+            canvas.draw_str("Vizia", (8.0, 200.0), &font, &paint);
+        }
+
+        // Images.
+        let pixels = [255u8; 16 * 16 * 4];
+        if let Some(image) = skia_safe::images::raster_from_data(
+            &skia_safe::ImageInfo::new_n32_premul((16, 16), None),
+            skia_safe::Data::new_copy(&pixels),
+            16 * 4,
+        ) {
+            canvas.draw_image_rect(
+                image,
+                None,
+                skia_safe::Rect::from_xywh(160.0, 160.0, 64.0, 64.0),
+                &paint,
+            );
+        }
+
+        self.gr_context.flush_and_submit();
+    }
+
+    /// Reads back the pixels of the frame which was just drawn, before it is presented.
+    pub fn capture_frame(&mut self) -> Option<CapturedFrame> {
+        self.gl_context.make_current(&self.gl_surface).unwrap();
+
+        // Make sure all drawing commands have completed before reading the surface.
+        self.gr_context.flush_and_submit();
+
+        let info = ImageInfo::new(
+            self.surface.image_info().dimensions(),
+            ColorType::RGBA8888,
+            AlphaType::Unpremul,
+            None,
+        );
+        let mut pixels = vec![0; info.compute_min_byte_size()];
+
+        self.surface.read_pixels(&info, &mut pixels, info.min_row_bytes(), (0, 0)).then(|| {
+            CapturedFrame { width: info.width() as u32, height: info.height() as u32, pixels }
+        })
+    }
+
+    /// Presents the rendered frame, passing the region which changed since the previous frame to the
+    /// compositor when the platform supports it.
+    ///
+    /// The damage region only limits what the compositor updates when presenting. The back buffer is still
+    /// composited in full every frame, since its contents are undefined after a swap without buffer age tracking.
+    pub fn swap_buffers(&mut self, damage: BoundingBox) {
+        self.gr_context.flush_and_submit();
+
+        if self.supports_swap_damage {
+            // The surface has a bottom-left origin, so the damage region is flipped vertically.
+            let left = damage.left().floor() as i32;
+            let right = damage.right().ceil() as i32;
+            let top = damage.top().floor() as i32;
+            let bottom = damage.bottom().ceil() as i32;
+            let rect = glutin::surface::Rect::new(
+                left,
+                self.surface.height() - bottom,
+                right - left,
+                bottom - top,
+            );
+
+            if self.gl_surface.swap_buffers_with_damage(&self.gl_context, &[rect]).is_ok() {
+                return;
+            }
+
+            // Swapping with damage is unsupported, so fall back to presenting the whole surface from now on.
+            self.supports_swap_damage = false;
         }
 
+        self.gl_surface.swap_buffers(&self.gl_context).expect("Failed to swap buffers");
+    }
+}
+
+impl ResizableSurface for WinState {
+    fn surface_size(&self) -> (i32, i32) {
+        (self.surface.width(), self.surface.height())
+    }
+
+    fn recreate_surface(&mut self, size: PhysicalSize<u32>) {
+        self.gl_context.make_current(&self.gl_surface).unwrap();
+
+        let (width, height): (u32, u32) = size.into();
+
         let fb_info = {
             let mut fboid: GLint = 0;
             unsafe { gl::GetIntegerv(gl::FRAMEBUFFER_BINDING, &mut fboid) };
@@ -221,7 +512,7 @@ impl WinState {
         };
 
         self.surface = create_surface(
-            &self.window,
+            size,
             fb_info,
             &mut self.gr_context,
             self.gl_config.num_samples() as usize,
@@ -239,21 +530,57 @@ impl WinState {
             NonZeroU32::new(height.max(1)).unwrap(),
         );
     }
+}
 
-    pub fn swap_buffers(&mut self) {
-        self.gr_context.flush_and_submit();
-        self.gl_surface.swap_buffers(&self.gl_context).expect("Failed to swap buffers");
+/// The render surface of a window, separate from [`WinState`] so that resizing can be tested without a GL context.
+pub(crate) trait ResizableSurface {
+    /// Returns the physical size of the surface.
+    fn surface_size(&self) -> (i32, i32);
+
+    /// Recreates the surface with the given physical size.
+    fn recreate_surface(&mut self, size: PhysicalSize<u32>);
+
+    /// Resizes the surface to the given physical size of the window, recreating it only if its size changes.
+    fn resize(&mut self, size: PhysicalSize<u32>) {
+        if needs_new_surface(self.surface_size(), size) {
+            self.recreate_surface(size);
+        }
     }
 }
 
+// Returns whether a surface of the given size must be recreated for a window with the given physical size.
+// A scale factor change may be followed by a resize to the size the surface already has, in which case the
+// surface is kept rather than recreated.
+pub(crate) fn needs_new_surface(surface_size: (i32, i32), size: PhysicalSize<u32>) -> bool {
+    if size.width == 0 || size.height == 0 {
+        return false;
+    }
+
+    surface_size != (size.width as i32, size.height as i32)
+}
+
+fn set_swap_interval(
+    gl_surface: &glutin::surface::Surface<WindowSurface>,
+    gl_context: &glutin::context::PossiblyCurrentContext,
+    present_mode: PresentMode,
+) {
+    let interval = match present_mode {
+        PresentMode::Fifo => SwapInterval::Wait(NonZeroU32::new(1).unwrap()),
+        // OpenGL has no mailbox mode, so frames are presented immediately instead.
+        PresentMode::Immediate | PresentMode::Mailbox => SwapInterval::DontWait,
+    };
+
+    // Not every platform allows the swap interval to be changed, in which case the driver default is kept.
+    let _ = gl_surface.set_swap_interval(gl_context, interval);
+}
+
 pub fn create_surface(
-    window: &winit::window::Window,
+    size: PhysicalSize<u32>,
     fb_info: FramebufferInfo,
     gr_context: &mut skia_safe::gpu::DirectContext,
     num_samples: usize,
     stencil_size: usize,
 ) -> Surface {
-    let size = window.inner_size();
     let size = (
         size.width.try_into().expect("Could not convert width"),
         size.height.try_into().expect("Could not convert height"),
@@ -289,6 +616,8 @@ pub struct Window {
     pub on_create: WindowCallback,
     pub should_close: bool,
     pub(crate) custom_cursors: Arc<HashMap<CursorIcon, CustomCursor>>,
+    /// Whether the last cursor icon set on the window is visible, as opposed to `CursorIcon::None`.
+    pub(crate) cursor_icon_visible: bool,
 }
 
 impl Window {
@@ -296,16 +625,61 @@ impl Window {
         self.window.as_ref().unwrap()
     }
 
+    fn set_cursor_grab(&self, cx: &mut EventContext, grab_mode: CursorGrabMode) {
+        let window = self.window();
+
+        // Not every platform supports both grab modes, so fall back to the other mode if necessary.
+        let fallback = match grab_mode {
+            CursorGrabMode::None => CursorGrabMode::None,
+            CursorGrabMode::Confined => CursorGrabMode::Locked,
+            CursorGrabMode::Locked => CursorGrabMode::Confined,
+        };
+
+        let grab_mode = [grab_mode, fallback]
+            .into_iter()
+            .find(|mode| {
+                window.set_cursor_grab(cursor_grab_mode_to_cursor_grab_mode(*mode)).is_ok()
+            })
+            .unwrap_or_default();
+
+        let current = cx.current();
+        let Some(window_state) = cx.windows.get_mut(&current) else {
+            return;
+        };
+
+        window_state.cursor_grab = grab_mode;
+
+        // Releasing the grab restores the cursor, unless it is hidden due to inactivity.
+        if grab_mode == CursorGrabMode::None {
+            window.set_cursor_visible(self.cursor_icon_visible && !window_state.cursor_hidden);
+        }
+    }
+
     pub fn new(cx: &mut Context, content: impl Fn(&mut Context)) -> Handle<Self> {
+        Self::with_description(cx, WindowDescription::default(), content)
+    }
+
+    /// Creates a new OS window with the properties of the given window description.
+    ///
+    /// The window is opened once the current event loop iteration finishes, and closes when the returned view is
+    /// removed, such as when the binding containing it rebuilds. Closing a secondary window does not exit the
+    /// application, which only exits once all of its windows have been closed.
+    pub fn with_description(
+        cx: &mut Context,
+        window_description: WindowDescription,
+        content: impl Fn(&mut Context),
+    ) -> Handle<Self> {
         Self {
             window: None,
             on_close: None,
             on_create: None,
             should_close: false,
             custom_cursors: Default::default(),
+            cursor_icon_visible: true,
         }
         .build(cx, |cx| {
-            cx.windows.insert(cx.current(), WindowState::default());
+            cx.windows
+                .insert(cx.current(), WindowState { window_description, ..Default::default() });
             cx.tree.set_window(cx.current(), true);
             (content)(cx);
         })
@@ -318,6 +692,7 @@ impl Window {
             on_create: None,
             should_close: false,
             custom_cursors: Default::default(),
+            cursor_icon_visible: true,
         }
         .build(cx, |cx| {
             let parent_window = cx.parent_window();
@@ -350,7 +725,11 @@ impl View for Window {
 
             WindowEvent::GrabCursor(flag) => {
                 let grab_mode = if *flag { CursorGrabMode::Locked } else { CursorGrabMode::None };
-                self.window().set_cursor_grab(grab_mode).expect("Failed to set cursor grab");
+                self.set_cursor_grab(cx, grab_mode);
+            }
+
+            WindowEvent::SetCursorGrab(grab_mode) => {
+                self.set_cursor_grab(cx, *grab_mode);
             }
 
             WindowEvent::SetCursorPosition(x, y) => {
@@ -363,6 +742,7 @@ impl View for Window {
 
             WindowEvent::SetCursor(cursor) => {
                 let Some(icon) = cursor_icon_to_cursor_icon(*cursor) else {
+                    self.cursor_icon_visible = false;
                     self.window().set_cursor_visible(false);
                     return;
                 };
@@ -373,7 +753,19 @@ impl View for Window {
                     self.window().set_cursor(icon);
                 }
 
-                self.window().set_cursor_visible(true);
+                self.cursor_icon_visible = true;
+
+                // Don't show a cursor which has been hidden due to inactivity.
+                let cursor_hidden = cx
+                    .windows
+                    .get(&cx.current())
+                    .is_some_and(|window_state| window_state.cursor_hidden);
+                self.window().set_cursor_visible(!cursor_hidden);
+            }
+
+            WindowEvent::SetCursorVisible(flag) => {
+                // Keep the cursor hidden if the current cursor icon is `CursorIcon::None`.
+                self.window().set_cursor_visible(*flag && self.cursor_icon_visible);
             }
 
             WindowEvent::SetTitle(title) => {
@@ -394,6 +786,28 @@ impl View for Window {
                     .set_max_inner_size(size.map(|size| LogicalSize::new(size.width, size.height)));
             }
 
+            WindowEvent::SetImeCursorArea(area) => {
+                self.window().set_ime_cursor_area(
+                    PhysicalPosition::new(area.x, area.y),
+                    PhysicalSize::new(area.w, area.h),
+                );
+            }
+
+            WindowEvent::CenterOnMonitor => {
+                if let Some(monitor) = self.window().current_monitor() {
+                    let monitor_position = monitor.position();
+                    let monitor_size = monitor.size();
+                    let window_size = self.window().outer_size();
+
+                    self.window().set_outer_position(PhysicalPosition::new(
+                        monitor_position.x
+                            + (monitor_size.width as i32 - window_size.width as i32) / 2,
+                        monitor_position.y
+                            + (monitor_size.height as i32 - window_size.height as i32) / 2,
+                    ));
+                }
+            }
+
             WindowEvent::SetPosition(pos) => {
                 let parent_window_position = if cx.current() == Entity::root() {
                     WindowPosition::new(0, 0)
@@ -433,6 +847,11 @@ impl View for Window {
                 cx.reload_styles().unwrap();
             }
 
+            // Close the window once the request has propagated to every handler, unless a handler prevented it.
+            WindowEvent::WindowCloseRequested => {
+                cx.emit(CloseRequestEvent::Confirm);
+            }
+
             WindowEvent::WindowClose => {
                 self.should_close = true;
 
@@ -465,24 +884,116 @@ impl View for Window {
                 self.window().focus_window();
             }
 
+            // Dragging is not supported on every platform, in which case the request is ignored.
             WindowEvent::DragWindow => {
-                self.window().drag_window().expect("Failed to init drag window");
+                let _ = self.window().drag_window();
+                meta.consume();
+            }
+
+            WindowEvent::DragResizeWindow(direction) => {
+                let _ = self
+                    .window()
+                    .drag_resize_window(resize_direction_to_resize_direction(*direction));
                 meta.consume();
             }
 
+            // These may be emitted by a binding before the window has been created, in which case the window is
+            // created with the level from its description instead.
             WindowEvent::SetAlwaysOnTop(flag) => {
-                self.window().set_window_level(if *flag {
-                    WindowLevel::AlwaysOnTop
-                } else {
-                    WindowLevel::Normal
-                });
+                if let Some(window) = &self.window {
+                    window.set_window_level(window_level_to_window_level(if *flag {
+                        WindowLevel::AlwaysOnTop
+                    } else {
+                        WindowLevel::Normal
+                    }));
+                }
+            }
+
+            WindowEvent::SetWindowLevel(window_level) => {
+                if let Some(window) = &self.window {
+                    window.set_window_level(window_level_to_window_level(*window_level));
+                }
+            }
+
+            WindowEvent::SetIcon(width, height, image) => {
+                // An invalid icon is ignored rather than clearing the current icon.
+                match icon_to_icon(*width, *height, image) {
+                    Some(icon) => self.window().set_window_icon(Some(icon)),
+                    None => warn!(
+                        "Ignoring invalid {}x{} window icon with {} bytes of pixel data",
+                        width,
+                        height,
+                        image.len()
+                    ),
+                }
+                meta.consume();
+            }
+
+            WindowEvent::FocusWindow => {
+                self.window().focus_window();
+                meta.consume();
+            }
+
+            // Only Windows supports changing whether a window is shown in the taskbar after it is created.
+            #[allow(unused_variables)]
+            WindowEvent::SetSkipTaskbar(flag) => {
+                #[cfg(target_os = "windows")]
+                if let Some(window) = &self.window {
+                    window.set_skip_taskbar(*flag);
+                }
+                meta.consume();
+            }
+
+            WindowEvent::SetBlur(flag) => {
+                self.window().set_blur(*flag);
+                meta.consume();
+            }
+
+            WindowEvent::RequestUserAttention(urgency) => {
+                self.window()
+                    .request_user_attention(urgency.map(urgency_level_to_user_attention_type));
+                meta.consume();
+            }
+
+            // The resulting `FullscreenChanged` event is emitted by the application once the OS has applied the
+            // change, which happens asynchronously on some platforms.
+            WindowEvent::SetFullscreen(mode) => {
+                self.window().set_fullscreen(fullscreen_mode_to_fullscreen(self.window(), *mode));
+                meta.consume();
+            }
+
+            // The present mode is applied to the surface by the application once the event has been handled.
+            WindowEvent::SetPresentMode(present_mode) => {
+                if let Some(window_state) = cx.windows.get_mut(&cx.current()) {
+                    window_state.window_description =
+                        window_state.window_description.clone().with_present_mode(*present_mode);
+                }
+                meta.consume();
             }
 
             _ => {}
-        })
+        });
+
+        event.map(|close_request_event, _| match close_request_event {
+            CloseRequestEvent::Confirm => {
+                let close_prevented = cx
+                    .windows
+                    .get(&cx.current())
+                    .is_some_and(|window_state| window_state.close_prevented);
+
+                if !close_prevented {
+                    cx.emit(WindowEvent::WindowClose);
+                }
+            }
+        });
     }
 }
 
+/// Events used by a window to close in response to a close request once the request has been handled.
+enum CloseRequestEvent {
+    Confirm,
+}
+
 impl<'a> WindowModifiers for Handle<'a, Window> {
     fn on_close(self, callback: impl Fn(&mut EventContext) + 'static) -> Self {
         self.modify(|window| window.on_close = Some(Box::new(callback)))
@@ -590,6 +1101,33 @@ impl<'a> WindowModifiers for Handle<'a, Window> {
         self
     }
 
+    fn alpha_mode(mut self, alpha_mode: AlphaMode) -> Self {
+        let entity = self.entity();
+        if let Some(win_state) = self.context().windows.get_mut(&entity) {
+            win_state.window_description.alpha_mode = alpha_mode;
+        }
+
+        self
+    }
+
+    fn opacity(mut self, opacity: f32) -> Self {
+        let entity = self.entity();
+        if let Some(win_state) = self.context().windows.get_mut(&entity) {
+            win_state.window_description.opacity = opacity.clamp(0.0, 1.0);
+        }
+
+        self
+    }
+
+    fn blur(mut self, flag: bool) -> Self {
+        let entity = self.entity();
+        if let Some(win_state) = self.context().windows.get_mut(&entity) {
+            win_state.window_description.blur = flag;
+        }
+
+        self
+    }
+
     fn decorations(mut self, flag: bool) -> Self {
         let entity = self.entity();
         if let Some(win_state) = self.context().windows.get_mut(&entity) {
@@ -599,10 +1137,54 @@ impl<'a> WindowModifiers for Handle<'a, Window> {
         self
     }
 
-    fn always_on_top(mut self, flag: bool) -> Self {
+    fn always_on_top(mut self, flag: impl Res<bool>) -> Self {
+        let entity = self.entity();
+        let value = flag.get(&self);
+        if let Some(win_state) = self.context().windows.get_mut(&entity) {
+            win_state.window_description =
+                win_state.window_description.clone().with_always_on_top(value);
+        }
+
+        flag.set_or_bind(self.context(), entity, |cx, flag| {
+            cx.emit(WindowEvent::SetAlwaysOnTop(flag.get(cx)));
+        });
+
+        self
+    }
+
+    fn skip_taskbar(mut self, flag: impl Res<bool>) -> Self {
+        let entity = self.entity();
+        let value = flag.get(&self);
+        if let Some(win_state) = self.context().windows.get_mut(&entity) {
+            win_state.window_description.skip_taskbar = value;
+        }
+
+        flag.set_or_bind(self.context(), entity, |cx, flag| {
+            cx.emit(WindowEvent::SetSkipTaskbar(flag.get(cx)));
+        });
+
+        self
+    }
+
+    fn window_level(mut self, window_level: impl Res<WindowLevel>) -> Self {
+        let entity = self.entity();
+        let value = window_level.get(&self);
+        if let Some(win_state) = self.context().windows.get_mut(&entity) {
+            win_state.window_description =
+                win_state.window_description.clone().with_window_level(value);
+        }
+
+        window_level.set_or_bind(self.context(), entity, |cx, window_level| {
+            cx.emit(WindowEvent::SetWindowLevel(window_level.get(cx)));
+        });
+
+        self
+    }
+
+    fn fullscreen(mut self, mode: FullscreenMode) -> Self {
         let entity = self.entity();
         if let Some(win_state) = self.context().windows.get_mut(&entity) {
-            win_state.window_description.always_on_top = flag
+            win_state.window_description.fullscreen = mode
         }
 
         self
@@ -611,7 +1193,46 @@ impl<'a> WindowModifiers for Handle<'a, Window> {
     fn vsync(mut self, flag: bool) -> Self {
         let entity = self.entity();
         if let Some(win_state) = self.context().windows.get_mut(&entity) {
-            win_state.window_description.vsync = flag
+            win_state.window_description = win_state.window_description.clone().with_vsync(flag);
+        }
+
+        self
+    }
+
+    fn msaa_samples(mut self, samples: u8) -> Self {
+        let entity = self.entity();
+        if let Some(win_state) = self.context().windows.get_mut(&entity) {
+            win_state.window_description =
+                win_state.window_description.clone().with_msaa_samples(samples);
+        }
+
+        self
+    }
+
+    fn max_fps(mut self, max_fps: Option<u32>) -> Self {
+        let entity = self.entity();
+        if let Some(win_state) = self.context().windows.get_mut(&entity) {
+            win_state.window_description =
+                win_state.window_description.clone().with_max_fps(max_fps);
+        }
+
+        self
+    }
+
+    fn present_mode(mut self, present_mode: PresentMode) -> Self {
+        let entity = self.entity();
+        if let Some(win_state) = self.context().windows.get_mut(&entity) {
+            win_state.window_description =
+                win_state.window_description.clone().with_present_mode(present_mode);
+        }
+
+        self
+    }
+
+    fn prewarm(mut self, flag: bool) -> Self {
+        let entity = self.entity();
+        if let Some(win_state) = self.context().windows.get_mut(&entity) {
+            win_state.window_description.prewarm = flag
         }
 
         self
@@ -636,4 +1257,13 @@ impl<'a> WindowModifiers for Handle<'a, Window> {
 
         self
     }
+
+    fn app_id(mut self, app_id: impl Into<String>) -> Self {
+        let entity = self.entity();
+        if let Some(win_state) = self.context().windows.get_mut(&entity) {
+            win_state.window_description.app_id = Some(app_id.into());
+        }
+
+        self
+    }
 }
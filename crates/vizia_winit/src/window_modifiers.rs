@@ -1,5 +1,8 @@
+use crate::convert::decode_icon;
 use vizia_core::{binding::Res, context::EventContext};
-use vizia_window::{WindowButtons, WindowPosition, WindowSize};
+use vizia_window::{
+    AlphaMode, FullscreenMode, PresentMode, WindowButtons, WindowLevel, WindowPosition, WindowSize,
+};
 
 /// Modifiers for setting the properties of a window.
 pub trait WindowModifiers {
@@ -141,6 +144,51 @@ pub trait WindowModifiers {
     /// .run();
     /// ```
     fn transparent(self, flag: bool) -> Self;
+    /// Sets whether the contents of a transparent window are presented with premultiplied or straight alpha.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_winit::application::Application;
+    /// Application::new(|cx|{
+    ///     // Content here
+    /// })
+    /// .transparent(true)
+    /// .alpha_mode(AlphaMode::Premultiplied)
+    /// .run();
+    /// ```
+    fn alpha_mode(self, alpha_mode: AlphaMode) -> Self;
+    /// Sets the opacity of the contents of a transparent window, from 0.0 to 1.0.
+    ///
+    /// The opacity can be changed after the window has been created with
+    /// [`EventContext::set_window_opacity`](vizia_core::context::EventContext::set_window_opacity).
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_winit::application::Application;
+    /// Application::new(|cx|{
+    ///     // Content here
+    /// })
+    /// .transparent(true)
+    /// .opacity(0.9)
+    /// .run();
+    /// ```
+    fn opacity(self, opacity: f32) -> Self;
+    /// Sets whether the background behind a transparent window is blurred. Only supported on some platforms.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_winit::application::Application;
+    /// Application::new(|cx|{
+    ///     // Content here
+    /// })
+    /// .transparent(true)
+    /// .blur(true)
+    /// .run();
+    /// ```
+    fn blur(self, flag: bool) -> Self;
     /// Sets whether the window has decorations. Accepts a boolean value, or lens to a boolean value.
     ///
     /// # Example
@@ -166,7 +214,50 @@ pub trait WindowModifiers {
     /// .always_on_top(true)
     /// .run();
     /// ```
-    fn always_on_top(self, flag: bool) -> Self;
+    fn always_on_top(self, flag: impl Res<bool>) -> Self;
+    /// Sets whether the window is hidden from the taskbar, such as for a floating tool palette. Accepts a
+    /// boolean value, or lens to a boolean value.
+    ///
+    /// On Windows this can be changed while the window is open. On X11 the window is marked as a utility window
+    /// when it is created, and changes afterwards are ignored. This has no effect on other platforms.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_winit::application::Application;
+    /// Application::new(|cx|{
+    ///     // Content here
+    /// })
+    /// .skip_taskbar(true)
+    /// .run();
+    /// ```
+    fn skip_taskbar(self, flag: impl Res<bool>) -> Self;
+    /// Sets the stacking level of the window relative to other windows. Accepts a value, or lens to a [`WindowLevel`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_winit::application::Application;
+    /// Application::new(|cx|{
+    ///     // Content here
+    /// })
+    /// .window_level(WindowLevel::AlwaysOnTop)
+    /// .run();
+    /// ```
+    fn window_level(self, window_level: impl Res<WindowLevel>) -> Self;
+    /// Sets whether the window is shown in a window or fullscreen.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_winit::application::Application;
+    /// Application::new(|cx|{
+    ///     // Content here
+    /// })
+    /// .fullscreen(FullscreenMode::Borderless)
+    /// .run();
+    /// ```
+    fn fullscreen(self, mode: FullscreenMode) -> Self;
     /// Sets whether the window has vsync enabled.
     ///
     /// # Example
@@ -180,7 +271,69 @@ pub trait WindowModifiers {
     /// .run();
     /// ```
     fn vsync(self, flag: bool) -> Self;
-    /// Sets the icon used for the window.
+    /// Sets how rendered frames are presented to the display. [`PresentMode::Immediate`] has the lowest latency,
+    /// at the cost of possible tearing.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_winit::application::Application;
+    /// Application::new(|cx|{
+    ///     // Content here
+    /// })
+    /// .present_mode(PresentMode::Immediate)
+    /// .run();
+    /// ```
+    fn present_mode(self, present_mode: PresentMode) -> Self;
+    /// Sets the number of samples used for multisample anti-aliasing. A count of 0 or 1 disables multisampling, and
+    /// counts which are not a power of two are rounded down to the nearest power of two.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_winit::application::Application;
+    /// Application::new(|cx|{
+    ///     // Content here
+    /// })
+    /// .msaa_samples(4)
+    /// .run();
+    /// ```
+    fn msaa_samples(self, samples: u8) -> Self;
+    /// Sets the maximum rate, in frames per second, at which the window redraws while animating or updating.
+    ///
+    /// This is applied in addition to the application-wide
+    /// [`frame_rate_limit`](crate::application::Application::frame_rate_limit), so the lower of the two rates
+    /// takes effect.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_winit::application::Application;
+    /// Application::new(|cx|{
+    ///     // Content here
+    /// })
+    /// .max_fps(Some(60))
+    /// .run();
+    /// ```
+    fn max_fps(self, max_fps: Option<u32>) -> Self;
+    /// Sets whether the GPU pipeline is warmed up by rendering a throwaway frame before the window is first shown.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_winit::application::Application;
+    /// Application::new(|cx|{
+    ///     // Content here
+    /// })
+    /// .prewarm(true)
+    /// .run();
+    /// ```
+    fn prewarm(self, flag: bool) -> Self;
+    /// Sets the icon used for the window from RGBA pixel data, shown in the title bar and taskbar.
+    ///
+    /// The icon can be changed after the window has been created with
+    /// [`EventContext::set_window_icon`](vizia_core::context::EventContext::set_window_icon). Window icons
+    /// are not supported on macOS, where the application bundle icon is used instead.
     ///
     /// # Example
     /// ```no_run, ignore
@@ -197,6 +350,45 @@ pub trait WindowModifiers {
     /// .run();
     /// ```
     fn icon(self, width: u32, height: u32, image: Vec<u8>) -> Self;
+    /// Sets the icon used for the window from an encoded image, such as a PNG file. The icon is left
+    /// unchanged if the image cannot be decoded.
+    ///
+    /// # Example
+    /// ```no_run, ignore
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_winit::application::Application;
+    /// Application::new(|cx|{
+    ///     // Content here
+    /// })
+    /// .encoded_icon(include_bytes!("../icon.png"))
+    /// .run();
+    /// ```
+    fn encoded_icon(self, data: &[u8]) -> Self
+    where
+        Self: Sized,
+    {
+        match decode_icon(data) {
+            Some((width, height, image)) => self.icon(width, height, image),
+            None => self,
+        }
+    }
 
     fn enabled_window_buttons(self, window_buttons: WindowButtons) -> Self;
+
+    /// Sets the application identifier used for taskbar grouping, icon association and desktop-file matching.
+    ///
+    /// This sets the `AppUserModelID` on Windows and the `app_id`/`WM_CLASS` on Wayland/X11. Has no effect on macOS.
+    /// The `AppUserModelID` applies to the whole process, so on Windows only the app id of the main window is used.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_winit::application::Application;
+    /// Application::new(|cx|{
+    ///     // Content here
+    /// })
+    /// .app_id("com.example.MyApp")
+    /// .run();
+    /// ```
+    fn app_id(self, app_id: impl Into<String>) -> Self;
 }
@@ -0,0 +1,47 @@
+use vizia::prelude::*;
+
+#[cfg(feature = "baseview")]
+fn main() {
+    panic!("This example is not supported on baseview");
+}
+
+const STYLE: &str = r#"
+    :root {
+        background-color: transparent;
+        child-space: 1s;
+    }
+
+    .panel {
+        size: 240px;
+        corner-radius: 24px;
+        background-color: rgba(40, 120, 220, 0.6);
+        border: 2px white;
+        shadow: 0px 8px 24px rgba(0, 0, 0, 0.5);
+        child-space: 1s;
+    }
+
+    .panel label {
+        color: white;
+        font-size: 20px;
+    }
+"#;
+
+#[cfg(not(feature = "baseview"))]
+fn main() -> Result<(), ApplicationError> {
+    Application::new(|cx| {
+        cx.add_stylesheet(STYLE).expect("Failed to add stylesheet");
+
+        // The anti-aliased rounded corners, border and shadow should blend cleanly with whatever
+        // is behind the window, without dark fringes along the edges.
+        VStack::new(cx, |cx| {
+            Label::new(cx, "Transparent Window");
+        })
+        .class("panel");
+    })
+    .title("Transparent Window")
+    .inner_size((400, 400))
+    .transparent(true)
+    .decorations(false)
+    .alpha_mode(AlphaMode::Premultiplied)
+    .run()
+}
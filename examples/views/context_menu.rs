@@ -0,0 +1,51 @@
+mod helpers;
+use helpers::*;
+use log::debug;
+use vizia::prelude::*;
+
+fn main() -> Result<(), ApplicationError> {
+    Application::new(|cx: &mut Context| {
+        ExamplePage::new(cx, |cx| {
+            ContextMenu::new(
+                cx,
+                |cx| {
+                    Label::new(cx, "Right-click here")
+                        .size(Pixels(200.0))
+                        .child_space(Stretch(1.0))
+                        .border_width(Pixels(1.0))
+                        .border_color(Color::gray())
+                },
+                |cx| {
+                    MenuButton::new(cx, |_| debug!("Cut"), |cx| Label::new(cx, "Cut"));
+                    MenuButton::new(cx, |_| debug!("Copy"), |cx| Label::new(cx, "Copy"));
+                    MenuButton::new(cx, |_| debug!("Paste"), |cx| Label::new(cx, "Paste"));
+                    MenuDivider::new(cx);
+                    Submenu::new(
+                        cx,
+                        |cx| Label::new(cx, "Transform"),
+                        |cx| {
+                            MenuButton::new(
+                                cx,
+                                |_| debug!("Uppercase"),
+                                |cx| Label::new(cx, "Uppercase"),
+                            );
+                            MenuButton::new(
+                                cx,
+                                |_| debug!("Lowercase"),
+                                |cx| Label::new(cx, "Lowercase"),
+                            );
+                        },
+                    );
+                    MenuDivider::new(cx);
+                    MenuButton::new(
+                        cx,
+                        |_| debug!("Select All"),
+                        |cx| Label::new(cx, "Select All"),
+                    );
+                },
+            );
+        });
+    })
+    .title("Context Menu")
+    .run()
+}
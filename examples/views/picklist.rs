@@ -6,10 +6,12 @@ use vizia::prelude::*;
 struct AppState {
     options: Vec<&'static str>,
     selected_option: usize,
+    chosen_option: Option<usize>,
 }
 
 pub enum AppEvent {
     SetOption(usize),
+    ChooseOption(usize),
 }
 
 impl Model for AppState {
@@ -18,6 +20,10 @@ impl Model for AppState {
             AppEvent::SetOption(index) => {
                 self.selected_option = *index;
             }
+
+            AppEvent::ChooseOption(index) => {
+                self.chosen_option = Some(*index);
+            }
         });
     }
 }
@@ -40,6 +46,7 @@ fn main() -> Result<(), ApplicationError> {
                 "Twelve",
             ],
             selected_option: 0,
+            chosen_option: None,
         }
         .build(cx);
 
@@ -47,6 +54,26 @@ fn main() -> Result<(), ApplicationError> {
             PickList::new(cx, AppState::options, AppState::selected_option, true)
                 .on_select(|cx, index| cx.emit(AppEvent::SetOption(index)))
                 .width(Pixels(100.0));
+
+            PickList::new(cx, AppState::options, AppState::selected_option, true)
+                .searchable(true)
+                .on_select(|cx, index| cx.emit(AppEvent::SetOption(index)))
+                .width(Pixels(100.0));
+
+            PickList::new(cx, AppState::options, AppState::selected_option, true)
+                .item_content(|cx, item, index| {
+                    HStack::new(cx, move |cx| {
+                        Label::new(cx, index + 1).width(Pixels(24.0));
+                        Label::new(cx, item);
+                    });
+                })
+                .on_select(|cx, index| cx.emit(AppEvent::SetOption(index)))
+                .width(Pixels(140.0));
+
+            PickList::new(cx, AppState::options, AppState::chosen_option, true)
+                .placeholder("Choose an option...")
+                .on_select(|cx, index| cx.emit(AppEvent::ChooseOption(index)))
+                .width(Pixels(180.0));
         });
     })
     .title("Picklist")
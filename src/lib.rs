@@ -3,27 +3,34 @@
 extern crate self as vizia;
 
 #[cfg(all(not(feature = "baseview"), feature = "winit"))]
-pub use vizia_winit::application::{Application, ApplicationError};
+pub use vizia_winit::application::{
+    Application, ApplicationError, ApplicationHandle, FrameRateLimit,
+};
 
 #[cfg(all(not(feature = "winit"), feature = "baseview"))]
 pub use vizia_baseview::{
-    Application, ApplicationError, ParentWindow, WindowHandle, WindowScalePolicy,
+    Application, ApplicationError, IdleContext, ParentWindow, WindowHandle, WindowScalePolicy,
 };
 
 pub use vizia_core::*;
 
+#[cfg(feature = "testing")]
+pub use vizia_testing as testing;
+
 #[doc(hidden)]
 pub mod prelude {
     pub use vizia_core::prelude::*;
 
     #[cfg(all(not(feature = "baseview"), feature = "winit"))]
     pub use vizia_winit::{
-        application::{Application, ApplicationError},
+        application::{Application, ApplicationError, ApplicationHandle, FrameRateLimit},
         window::Window,
         window_modifiers::WindowModifiers,
-        ModifyWindow,
+        ModifyWindow, SpawnWindow,
     };
 
     #[cfg(all(not(feature = "winit"), feature = "baseview"))]
-    pub use vizia_baseview::{Application, ApplicationError, WindowHandle, WindowScalePolicy};
+    pub use vizia_baseview::{
+        Application, ApplicationError, IdleContext, WindowHandle, WindowScalePolicy,
+    };
 }